@@ -14,3 +14,63 @@ normmonth = Normmonat
 normmonths = Normmonate
 normyear = Normjahr
 normyears = Normjahre
+
+era-norm = NÄ
+era-before-norm = vNÄ
+
+# The date part of a `NormTime`. Arguments: $year, $month, $day, $era.
+date-pattern = { $day }.{ $month }.{ $year } { $era }
+
+# The names of the normmonths 0 to 9.
+normmonth-name-0 = Primus
+normmonth-name-1 = Secundus
+normmonth-name-2 = Tertius
+normmonth-name-3 = Quartus
+normmonth-name-4 = Quintus
+normmonth-name-5 = Sextus
+normmonth-name-6 = Septimus
+normmonth-name-7 = Octavus
+normmonth-name-8 = Nonus
+normmonth-name-9 = Decimus
+
+# The names of the normdays 0 to 9 of the normweek.
+weekday-name-0 = Primidi
+weekday-name-1 = Duodi
+weekday-name-2 = Tridi
+weekday-name-3 = Quartidi
+weekday-name-4 = Quintidi
+weekday-name-5 = Sextidi
+weekday-name-6 = Septidi
+weekday-name-7 = Octidi
+weekday-name-8 = Nonidi
+weekday-name-9 = Decadi
+
+# The names of the 5 seasons of the default division of the normyear.
+season-name-0 = Tauzeit
+season-name-1 = Blüte
+season-name-2 = Zenit
+season-name-3 = Ernte
+season-name-4 = Frost
+
+# Counts from 1 to 12 spelled out as words. Arguments: $count, $unit (e.g. `normday`).
+number-word = { $count ->
+    [1] { $unit ->
+        [normweek] eine
+        [hour] eine
+        [minute] eine
+        [second] eine
+       *[other] ein
+    }
+    [2] zwei
+    [3] drei
+    [4] vier
+    [5] fünf
+    [6] sechs
+    [7] sieben
+    [8] acht
+    [9] neun
+    [10] zehn
+    [11] elf
+    [12] zwölf
+   *[other] { $count }
+}
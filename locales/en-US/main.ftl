@@ -14,3 +14,57 @@ normmonth = normmonth
 normmonths = normmonths
 normyear = normyear
 normyears = normyears
+
+era-norm = NE
+era-before-norm = BNE
+
+# The date part of a `NormTime`. Arguments: $year, $month, $day, $era.
+date-pattern = { $year }-{ $month }-{ $day } { $era }
+
+# The names of the normmonths 0 to 9.
+normmonth-name-0 = Primus
+normmonth-name-1 = Secundus
+normmonth-name-2 = Tertius
+normmonth-name-3 = Quartus
+normmonth-name-4 = Quintus
+normmonth-name-5 = Sextus
+normmonth-name-6 = Septimus
+normmonth-name-7 = Octavus
+normmonth-name-8 = Nonus
+normmonth-name-9 = Decimus
+
+# The names of the normdays 0 to 9 of the normweek.
+weekday-name-0 = Primidi
+weekday-name-1 = Duodi
+weekday-name-2 = Tridi
+weekday-name-3 = Quartidi
+weekday-name-4 = Quintidi
+weekday-name-5 = Sextidi
+weekday-name-6 = Septidi
+weekday-name-7 = Octidi
+weekday-name-8 = Nonidi
+weekday-name-9 = Decadi
+
+# The names of the 5 seasons of the default division of the normyear.
+season-name-0 = Thaw
+season-name-1 = Bloom
+season-name-2 = Zenith
+season-name-3 = Harvest
+season-name-4 = Frost
+
+# Counts from 1 to 12 spelled out as words. Arguments: $count, $unit (e.g. `normday`).
+number-word = { $count ->
+    [1] one
+    [2] two
+    [3] three
+    [4] four
+    [5] five
+    [6] six
+    [7] seven
+    [8] eight
+    [9] nine
+    [10] ten
+    [11] eleven
+    [12] twelve
+   *[other] { $count }
+}
@@ -0,0 +1,103 @@
+//! Conversion of Normtime data into and from Apache Arrow arrays.
+//!
+//! This module is only available, if the **`arrow`** feature has been enabled.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use arrow_array::{Int64Array, TimestampSecondArray};
+
+use crate::{NormTime, NormTimeDelta};
+
+
+
+
+//=============================================================================
+// Functions
+
+
+/// Returns a `TimestampSecondArray` holding the Unix timestamps of `items`.
+///
+/// # Example
+///
+/// ```
+/// use arrow_array::Array;
+/// use normtime::NormTime;
+/// use normtime::arrow::{normtimes_to_array, array_to_normtimes};
+///
+/// let times = [ NormTime::from_ymd_opt( 1, 2, 3 ).unwrap(), NormTime::from_ymd_opt( -1, 0, 0 ).unwrap() ];
+/// let array = normtimes_to_array( &times );
+///
+/// assert_eq!( array.len(), 2 );
+/// assert_eq!( array.value( 0 ), times[0].timestamp() );
+/// assert_eq!( array_to_normtimes( &array ), vec![ Some( times[0] ), Some( times[1] ) ] );
+/// ```
+pub fn normtimes_to_array( items: &[NormTime] ) -> TimestampSecondArray {
+	items.iter()
+		.map( |x| x.timestamp() )
+		.collect::<Vec<i64>>()
+		.into()
+}
+
+/// Returns the `NormTime`s stored as Unix timestamps in `array`. Null entries and the earliest timestamps, which overflow when shifted to the zero time of Normtime (see [`NormTime::from_timestamp`]), are returned as `None`.
+pub fn array_to_normtimes( array: &TimestampSecondArray ) -> Vec<Option<NormTime>> {
+	array.iter()
+		.map( |x| x.and_then( NormTime::from_timestamp ) )
+		.collect()
+}
+
+/// Returns an `Int64Array` holding the durations of `items` in seconds.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormTimeDelta;
+/// use normtime::arrow::{deltas_to_array, array_to_deltas};
+///
+/// let deltas = [ NormTimeDelta::new_days( 1 ), NormTimeDelta::new_seconds( -5 ) ];
+/// let array = deltas_to_array( &deltas );
+///
+/// assert_eq!( array.value( 0 ), 100_000 );
+/// assert_eq!( array_to_deltas( &array ), vec![ Some( deltas[0] ), Some( deltas[1] ) ] );
+/// ```
+pub fn deltas_to_array( items: &[NormTimeDelta] ) -> Int64Array {
+	items.iter()
+		.map( |x| x.seconds() )
+		.collect::<Vec<i64>>()
+		.into()
+}
+
+/// Returns the `NormTimeDelta`s stored as seconds in `array`. Null entries and values out of the range of `NormTimeDelta` are returned as `None`.
+pub fn array_to_deltas( array: &Int64Array ) -> Vec<Option<NormTimeDelta>> {
+	array.iter()
+		.map( |x| x.and_then( |secs| NormTimeDelta::new( secs, 0 ) ) )
+		.collect()
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	use arrow_array::Array;
+
+	#[test]
+	fn arrays_with_nulls() {
+		let array = TimestampSecondArray::from( vec![ Some( 0 ), None ] );
+		assert_eq!( array_to_normtimes( &array ), vec![ Some( NormTime::from_timestamp( 0 ).unwrap() ), None ] );
+		assert_eq!( array.null_count(), 1 );
+
+		let array = Int64Array::from( vec![ None, Some( i64::MAX ), Some( 1 ) ] );
+		assert_eq!( array_to_deltas( &array ), vec![ None, None, Some( NormTimeDelta::new_seconds( 1 ) ) ] );
+	}
+}
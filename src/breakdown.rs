@@ -0,0 +1,151 @@
+//! The difference between two points in time, broken down into calendar components.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use std::fmt;
+
+use crate::{DUR_HOUR, DUR_MINUTE, DUR_NORMDAY, DUR_NORMMONTH, DUR_NORMYEAR};
+use crate::Unit;
+
+
+
+
+//=============================================================================
+// Breakdown
+
+
+/// The difference between two `NormTime`s in normyears, normmonths, normdays, hours, minutes and seconds as returned by [`NormTime::since`](crate::NormTime::since).
+///
+/// All components share the sign of the difference and, apart from the normyears, never exceed the next greater unit, e.g. there are at most 9 normmonths and 29 normdays. Since all units of Normtime have a fixed length, the breakdown is exact.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{NormBreakdown, NormTime};
+///
+/// let a = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+/// let b = NormTime::from_ymd_opt( 47, 6, 17 ).unwrap().and_hms( 8, 0, 0 );
+///
+/// let diff = b.since( a );
+/// assert_eq!( diff, NormBreakdown { years: 2, months: 3, days: 5, hours: 8, minutes: 0, seconds: 0 } );
+/// assert_eq!( diff.to_string(), "2 normyears, 3 normmonths, 5 normdays, 8 hours" );
+/// assert_eq!( a.since( b ).to_string(), "-2 normyears, -3 normmonths, -5 normdays, -8 hours" );
+/// ```
+#[derive( Clone, Copy, PartialEq, Eq, Hash, Default, Debug )]
+pub struct NormBreakdown {
+	/// The full normyears.
+	pub years: i64,
+
+	/// The normmonths within the normyear.
+	pub months: i64,
+
+	/// The normdays within the normmonth.
+	pub days: i64,
+
+	/// The hours within the normday.
+	pub hours: i64,
+
+	/// The minutes within the hour.
+	pub minutes: i64,
+
+	/// The seconds within the minute.
+	pub seconds: i64,
+}
+
+impl NormBreakdown {
+	/// Returns the breakdown of the difference `secs`. The normyears of the largest possible difference between two `NormTime`s still fit into an `i64`.
+	pub(crate) const fn from_seconds( secs: i128 ) -> Self {
+		let sign = if secs < 0 { -1 } else { 1 };
+		let abs = secs.unsigned_abs();
+		let subyear = ( abs % DUR_NORMYEAR as u128 ) as i64;
+		let subday = subyear % DUR_NORMDAY;
+
+		Self {
+			years: sign * ( abs / DUR_NORMYEAR as u128 ) as i64,
+			months: sign * ( subyear / DUR_NORMMONTH ),
+			days: sign * ( subyear % DUR_NORMMONTH / DUR_NORMDAY ),
+			hours: sign * ( subday / DUR_HOUR ),
+			minutes: sign * ( subday % DUR_HOUR / DUR_MINUTE ),
+			seconds: sign * ( subday % DUR_MINUTE ),
+		}
+	}
+
+	/// Returns `true` if the difference is negative, i.e. if the first `NormTime` is earlier than the second.
+	pub const fn is_negative( &self ) -> bool {
+		self.years < 0 || self.months < 0 || self.days < 0 || self.hours < 0 || self.minutes < 0 || self.seconds < 0
+	}
+
+	/// Returns the components together with their units, ordered from largest to smallest.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTime, Unit};
+	///
+	/// let diff = NormTime::from_ymd_opt( 1, 0, 2 ).unwrap().since( NormTime::from_ymd_opt( 0, 0, 0 ).unwrap() );
+	/// assert_eq!( diff.components()[0], ( 1, Unit::Year ) );
+	/// assert_eq!( diff.components()[2], ( 2, Unit::Day ) );
+	/// ```
+	pub const fn components( &self ) -> [( i64, Unit ); 6] {
+		[
+			( self.years, Unit::Year ),
+			( self.months, Unit::Month ),
+			( self.days, Unit::Day ),
+			( self.hours, Unit::Hour ),
+			( self.minutes, Unit::Minute ),
+			( self.seconds, Unit::Second ),
+		]
+	}
+}
+
+/// Lists the non-zero components separated by commas. A difference of zero is written as `"0 seconds"`.
+impl fmt::Display for NormBreakdown {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		let parts = self.components().into_iter()
+			.filter( |( x, _ )| *x != 0 )
+			.map( |( x, unit )| {
+				let name = if x.abs() == 1 { unit.as_str_singular() } else { unit.as_str() };
+				format!( "{} {}", x, name )
+			} )
+			.collect::<Vec<_>>();
+
+		if parts.is_empty() {
+			return write!( f, "0 {}", Unit::Second.as_str() );
+		}
+
+		write!( f, "{}", parts.join( ", " ) )
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	use crate::NormTime;
+
+	#[test]
+	fn since() {
+		let a = NormTime( 0 );
+		let b = NormTime( DUR_NORMYEAR + DUR_NORMMONTH + DUR_NORMDAY + DUR_HOUR + DUR_MINUTE + 1 );
+		assert_eq!( b.since( a ), NormBreakdown { years: 1, months: 1, days: 1, hours: 1, minutes: 1, seconds: 1 } );
+		assert_eq!( b.since( a ).to_string(), "1 normyear, 1 normmonth, 1 normday, 1 hour, 1 minute, 1 second" );
+		assert!( a.since( b ).is_negative() );
+		assert!( !b.since( a ).is_negative() );
+
+		let max = NormTime( i64::MAX ).since( NormTime( i64::MIN ) );
+		assert_eq!( max.years, ( u64::MAX / DUR_NORMYEAR as u64 ) as i64 );
+		assert_eq!( NormTime( i64::MIN ).since( NormTime( i64::MAX ) ).years, -max.years );
+	}
+}
@@ -0,0 +1,175 @@
+//! Parsing command line arguments into Normtime types using `clap`.
+//!
+//! `NormTime`, `NormTimeDelta` and `Unit` implement `ValueParserFactory`, so they can be used directly as argument types in derive-based parsers. Invalid values are reported together with an example of a valid value.
+//!
+//! # Example
+//!
+//! ```
+//! use clap::Parser;
+//! use normtime::{NormTime, NormTimeDelta, Unit};
+//!
+//! #[derive( Parser )]
+//! struct Cli {
+//!     #[arg( long )]
+//!     start: NormTime,
+//!
+//!     #[arg( long )]
+//!     duration: NormTimeDelta,
+//!
+//!     #[arg( long, value_delimiter = ',' )]
+//!     units: Vec<Unit>,
+//! }
+//!
+//! let cli = Cli::parse_from( [ "app", "--start", "0045-03-12N08:00:00", "--duration", "3 normdays", "--units", "day,hour" ] );
+//! assert_eq!( cli.start, NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 ) );
+//! assert_eq!( cli.duration, NormTimeDelta::new_days( 3 ) );
+//! assert_eq!( cli.units, [ Unit::Day, Unit::Hour ] );
+//!
+//! assert!( Cli::try_parse_from( [ "app", "--start", "0045-03-12T08:00:00", "--duration", "3" ] ).is_err() );
+//! ```
+//!
+//! This module is only available, if the **`clap`** feature has been enabled.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use std::ffi::OsStr;
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use clap::builder::{PossibleValue, TypedValueParser, ValueParserFactory};
+use clap::error::{Error, ErrorKind};
+use clap::{Arg, Command};
+
+use crate::{NormTime, NormTimeDelta, Unit};
+
+
+
+
+//=============================================================================
+// Parser
+
+
+/// A `clap` value parser for types parsed by `FromStr`, mentioning an example of a valid value on errors.
+#[derive( Debug )]
+pub struct NormValueParser<T> {
+	example: &'static str,
+	possible: &'static [&'static str],
+	_marker: PhantomData<fn() -> T>,
+}
+
+impl<T> NormValueParser<T> {
+	/// Creates a parser, whose errors mention `example` as valid value.
+	pub const fn new( example: &'static str ) -> Self {
+		Self {
+			example,
+			possible: &[],
+			_marker: PhantomData,
+		}
+	}
+}
+
+// Deriving would require `T: Clone`.
+impl<T> Clone for NormValueParser<T> {
+	fn clone( &self ) -> Self {
+		Self {
+			example: self.example,
+			possible: self.possible,
+			_marker: PhantomData,
+		}
+	}
+}
+
+impl<T> TypedValueParser for NormValueParser<T>
+where
+	T: FromStr + Clone + Send + Sync + 'static,
+	T::Err: fmt::Display,
+{
+	type Value = T;
+
+	fn parse_ref( &self, cmd: &Command, arg: Option<&Arg>, value: &OsStr ) -> Result<Self::Value, Error> {
+		let name = arg.map_or( "...".to_string(), |x| x.to_string() );
+
+		let Some( text ) = value.to_str() else {
+			return Err( Error::raw( ErrorKind::InvalidUtf8, format!( "invalid UTF-8 in the value for '{}'\n", name ) ).with_cmd( cmd ) );
+		};
+
+		text.parse().map_err( |err| {
+			let message = format!( "invalid value '{}' for '{}': {}\n\n  tip: a valid value is e.g. '{}'\n", text, name, err, self.example );
+			Error::raw( ErrorKind::ValueValidation, message ).with_cmd( cmd )
+		} )
+	}
+
+	fn possible_values( &self ) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+		if self.possible.is_empty() {
+			return None;
+		}
+
+		Some( Box::new( self.possible.iter().map( |x| PossibleValue::new( *x ) ) ) )
+	}
+}
+
+impl ValueParserFactory for NormTime {
+	type Parser = NormValueParser<NormTime>;
+
+	fn value_parser() -> Self::Parser {
+		NormValueParser::new( "0045-03-12N08:00:00" )
+	}
+}
+
+impl ValueParserFactory for NormTimeDelta {
+	type Parser = NormValueParser<NormTimeDelta>;
+
+	fn value_parser() -> Self::Parser {
+		NormValueParser::new( "3 normdays" )
+	}
+}
+
+impl ValueParserFactory for Unit {
+	type Parser = NormValueParser<Unit>;
+
+	fn value_parser() -> Self::Parser {
+		NormValueParser {
+			possible: &[ "year", "month", "week", "day", "hour", "minute", "second" ],
+			..NormValueParser::new( "day" )
+		}
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	use clap::value_parser;
+
+	#[test]
+	fn errors() {
+		let cmd = Command::new( "app" )
+			.arg( Arg::new( "time" ).long( "time" ).value_parser( value_parser!( NormTime ) ) )
+			.arg( Arg::new( "unit" ).long( "unit" ).value_parser( value_parser!( Unit ) ) );
+
+		let matches = cmd.clone().try_get_matches_from( [ "app", "--time", "0045-03-12", "--unit", "normdays" ] ).unwrap();
+		assert_eq!( matches.get_one::<NormTime>( "time" ), Some( &NormTime::from_ymd_opt( 45, 3, 12 ).unwrap() ) );
+		assert_eq!( matches.get_one::<Unit>( "unit" ), Some( &Unit::Day ) );
+
+		let err = cmd.clone().try_get_matches_from( [ "app", "--time", "yesterday" ] ).unwrap_err();
+		assert_eq!( err.kind(), ErrorKind::ValueValidation );
+		assert!( err.to_string().contains( "'0045-03-12N08:00:00'" ) );
+		assert!( err.to_string().contains( "--time <time>" ) );
+
+		let err = cmd.try_get_matches_from( [ "app", "--unit", "fortnight" ] ).unwrap_err();
+		assert!( err.to_string().contains( "'fortnight'" ) );
+	}
+}
@@ -0,0 +1,275 @@
+//! Normdays without a clock.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg( feature = "chrono" )] use chrono::NaiveDate;
+
+use crate::{NormTime, NormTimeOfDay, TimeError};
+use crate::{DUR_NORMYEAR, DUR_NORMMONTH, DUR_NORMDAY};
+
+
+
+
+//=============================================================================
+// Date
+
+
+/// A normday in the Normtime calendar without a clock, e.g. `0045-03-12`.
+///
+/// Unlike a `NormTime` at the start of a normday, a `NormDate` cannot accidentally be compared with an instant later that normday.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{NormDate, NormTime};
+///
+/// let d = NormDate::from_ymd_opt( 45, 3, 12 ).unwrap();
+/// assert_eq!( d.to_string(), "0045-03-12" );
+/// assert_eq!( "0045-03-12".parse(), Ok( d ) );
+///
+/// let t = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+/// assert_eq!( NormDate::from( t ), d );
+/// assert_eq!( NormTime::from( d ), NormTime::from_ymd_opt( 45, 3, 12 ).unwrap() );
+/// ```
+#[derive( Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Default )]
+pub struct NormDate( pub(super) i64 );
+
+impl NormDate {
+	/// The number of normdays of a normyear.
+	const DAYS_PER_YEAR: i64 = DUR_NORMYEAR / DUR_NORMDAY;
+
+	/// The number of normdays of a normmonth.
+	const DAYS_PER_MONTH: i64 = DUR_NORMMONTH / DUR_NORMDAY;
+
+	/// Create a new `NormDate` from `normyear`, `normmonth` and `normday`. Returns `None` if `normmonth` is greater than 9 or `normday` is greater than 29.
+	pub const fn from_ymd_opt( normyear: i32, normmonth: u32, normday: u32 ) -> Option<Self> {
+		if normday > 29 || normmonth > 9 {
+			return None;
+		}
+
+		Some( Self( Self::DAYS_PER_YEAR * normyear as i64 + Self::DAYS_PER_MONTH * normmonth as i64 + normday as i64 ) )
+	}
+
+	/// Returns the normyear.
+	pub const fn year( self ) -> i64 {
+		self.0.div_euclid( Self::DAYS_PER_YEAR )
+	}
+
+	/// Returns the normmonth within the normyear (0 to 9).
+	pub const fn month( self ) -> u32 {
+		( self.0.rem_euclid( Self::DAYS_PER_YEAR ) / Self::DAYS_PER_MONTH ) as u32
+	}
+
+	/// Returns the normday within the normmonth (0 to 29).
+	pub const fn day( self ) -> u32 {
+		self.0.rem_euclid( Self::DAYS_PER_MONTH ) as u32
+	}
+
+	/// Combines `self` and `time` into a `NormTime`. Instants outside of the range of `NormTime` are saturated.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormDate, NormTime, NormTimeOfDay};
+	///
+	/// let d = NormDate::from_ymd_opt( 45, 3, 12 ).unwrap();
+	/// let t = NormTimeOfDay::from_hms_opt( 8, 0, 0 ).unwrap();
+	/// assert_eq!( d.and_time( t ), NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 ) );
+	///
+	/// // The end of a normday is the start of the next one.
+	/// assert_eq!( d.and_time( NormTimeOfDay::END ), NormTime::from_ymd_opt( 45, 3, 13 ).unwrap() );
+	/// ```
+	pub const fn and_time( self, time: NormTimeOfDay ) -> NormTime {
+		let secs = self.0 as i128 * DUR_NORMDAY as i128 + time.0 as i128;
+
+		if secs < i64::MIN as i128 {
+			NormTime( i64::MIN )
+		} else if secs > i64::MAX as i128 {
+			NormTime( i64::MAX )
+		} else {
+			NormTime( secs as i64 )
+		}
+	}
+}
+
+impl fmt::Debug for NormDate {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		fmt::Display::fmt( self, f )
+	}
+}
+
+/// A `NormDate` is formatted like the date part of a `NormTime`. Width, fill and alignment are respected and the alternate form (`{:#}`) appends the era.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormDate;
+///
+/// let d = NormDate::from_ymd_opt( -5, 3, 12 ).unwrap();
+/// assert_eq!( d.to_string(), "-0005-03-12" );
+/// assert_eq!( format!( "{:#}", d ), "0005-03-12 BNE" );
+/// ```
+impl fmt::Display for NormDate {
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result {
+		let year = self.year();
+		let date = format!( "{:0>4}-{:0>2}-{:0>2}", year.unsigned_abs(), self.month(), self.day() );
+
+		match ( year < 0, f.alternate() ) {
+			( false, false ) => f.pad( &date ),
+			( true, false ) => f.pad( &format!( "-{}", date ) ),
+			( false, true ) => f.pad( &format!( "{} NE", date ) ),
+			( true, true ) => f.pad( &format!( "{} BNE", date ) ),
+		}
+	}
+}
+
+/// Converting `NormTime` to `NormDate`, dropping the clock.
+impl From<NormTime> for NormDate {
+	fn from( item: NormTime ) -> Self {
+		item.date()
+	}
+}
+
+/// Converting `NormDate` to the `NormTime` at the start of the normday. The start of the earliest `NormDate` precedes the range of `NormTime` and is saturated.
+impl From<NormDate> for NormTime {
+	fn from( item: NormDate ) -> Self {
+		item.and_time( NormTimeOfDay::MIDNIGHT )
+	}
+}
+
+/// Converting `chrono::NaiveDate` to the `NormDate` of its start.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormDate;
+/// use chrono::NaiveDate;
+///
+/// assert_eq!( NormDate::from( NaiveDate::from_ymd_opt( 2068, 1, 1 ).unwrap() ), NormDate::from_ymd_opt( 0, 0, 0 ).unwrap() );
+/// ```
+#[cfg( feature = "chrono" )]
+impl From<NaiveDate> for NormDate {
+	fn from( item: NaiveDate ) -> Self {
+		Self::from( NormTime::from( item ) )
+	}
+}
+
+/// Converting `NormDate` to the `chrono::NaiveDate` of its start.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormDate;
+/// use chrono::NaiveDate;
+///
+/// assert_eq!( NaiveDate::from( NormDate::from_ymd_opt( 0, 0, 1 ).unwrap() ), NaiveDate::from_ymd_opt( 2068, 1, 2 ).unwrap() );
+/// ```
+#[cfg( feature = "chrono" )]
+impl From<NormDate> for NaiveDate {
+	fn from( item: NormDate ) -> Self {
+		NaiveDate::from( NormTime::from( item ) )
+	}
+}
+
+impl FromStr for NormDate {
+	type Err = TimeError;
+
+	/// Parses a normdate like `0045-03-12`. Fields exceeding their range are rolled into the next greater unit like for `NormTime`. Strings with a clock are rejected.
+	fn from_str( s: &str ) -> Result<Self, Self::Err> {
+		if s.contains( 'N' ) {
+			return Err( TimeError::ParseError( s.to_string() ) );
+		}
+
+		Ok( Self::from( s.parse::<NormTime>()? ) )
+	}
+}
+
+
+#[cfg( feature = "serde" )]
+mod normdate_serde {
+	use super::NormDate;
+
+	use std::fmt;
+
+	impl serde::Serialize for NormDate {
+		fn serialize<S>( &self, serializer: S ) -> Result<S::Ok, S::Error>
+		where
+			S: serde::Serializer,
+		{
+			serializer.collect_str( self )
+		}
+	}
+
+	struct NormDateVisitor;
+
+	impl<'de> serde::de::Visitor<'de> for NormDateVisitor {
+		type Value = NormDate;
+
+		fn expecting( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+			formatter.write_str( "a formatted date string" )
+		}
+
+		fn visit_str<E>( self, value: &str ) -> Result<Self::Value, E>
+		where
+			E: serde::de::Error,
+		{
+			value.parse().map_err( E::custom )
+		}
+	}
+
+	impl<'de> serde::Deserialize<'de> for NormDate {
+		fn deserialize<D>( deserializer: D ) -> Result<Self, D::Error>
+		where
+			D: serde::Deserializer<'de>,
+		{
+			deserializer.deserialize_str( NormDateVisitor )
+		}
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	#[cfg( feature = "serde" )]
+	use serde_test::{Token, assert_tokens};
+
+	#[test]
+	fn normdate_fields() {
+		let d = NormDate::from( NormTime( -1 ) );
+		assert_eq!( ( d.year(), d.month(), d.day() ), ( -1, 9, 29 ) );
+		assert_eq!( d, NormDate::from_ymd_opt( -1, 9, 29 ).unwrap() );
+		assert!( NormDate::from_ymd_opt( 0, 0, 30 ).is_none() );
+
+		assert_eq!( NormTime::from( NormDate::from( NormTime( i64::MIN ) ) ), NormTime( i64::MIN ) );
+		assert_eq!( NormDate::from( NormTime( i64::MAX ) ).to_string(), NormTime( i64::MAX ).to_string_date() );
+	}
+
+	#[test]
+	fn parse_normdate() {
+		assert_eq!( "-0001-09-29".parse(), Ok( NormDate( -1 ) ) );
+		assert_eq!( "0001-00-30".parse(), NormDate::from_ymd_opt( 1, 1, 0 ).ok_or( TimeError::ParseError( String::new() ) ) );
+		assert!( "0045-03-12N08:00:00".parse::<NormDate>().is_err() );
+	}
+
+	#[test]
+	#[cfg( feature = "serde" )]
+	fn test_serialize_deserialize() {
+		assert_tokens( &NormDate::from_ymd_opt( 45, 3, 12 ).unwrap(), &[ Token::Str( "0045-03-12" ) ] );
+	}
+}
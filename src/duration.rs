@@ -7,12 +7,15 @@
 // Crates
 
 
+#[cfg( feature = "chrono" )] use std::cmp::Ordering;
+use std::borrow::Borrow;
 use std::iter::Sum;
 use std::fmt;
-use std::ops::{Add, Sub, Mul, Div};
+use std::ops::{Add, Sub, Mul, Div, Bound, RangeBounds};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering as AtomicOrdering};
 
-use chrono::TimeDelta;
+#[cfg( feature = "chrono" )] use chrono::TimeDelta;
 use thiserror::Error;
 
 #[cfg( feature = "i18n" )] use fluent_templates::Loader;
@@ -22,6 +25,8 @@ use thiserror::Error;
 #[cfg( feature = "tex" )] use crate::{Latex, LatexSym};
 #[cfg( all( feature = "i18n", feature = "tex" ) )] use crate::LatexLocale;
 #[cfg( feature = "tex" )] use crate::TexOptions;
+use crate::TextOptions;
+use crate::unit_fmt;
 
 use crate::{DUR_NORMYEAR, DUR_NORMMONTH, DUR_NORMWEEK, DUR_NORMDAY, DUR_TERRAYEAR, DUR_HOUR, DUR_MINUTE};
 #[cfg( feature = "i18n" )] use crate::LOCALES;
@@ -33,10 +38,20 @@ use crate::{DUR_NORMYEAR, DUR_NORMMONTH, DUR_NORMWEEK, DUR_NORMDAY, DUR_TERRAYEA
 // Errors
 
 
+/// Errors occurring when parsing or converting a `Unit`, `NormTimeDelta` or `NormPeriod`.
 #[derive( Error, Debug )]
 pub enum ConversionError {
 	#[error( "Cannot parse into `Unit`: {0}" )]
 	FromStrFail( String ),
+
+	#[error( "Cannot convert into `NormTimeDelta`: {0}" )]
+	InvalidValue( String ),
+
+	#[error( "Cannot parse into `NormTimeDelta`: {0}" )]
+	ParseError( String ),
+
+	#[error( "Cannot parse into `NormPeriod`: {0}" )]
+	PeriodParseError( String ),
 }
 
 
@@ -58,6 +73,10 @@ const NANOS_PER_SEC: i32 = 1_000_000_000;
 const MILLIS_PER_SEC: i64 = 1000;
 
 
+/// The units used by [`NormTimeDelta::to_string_default_units`] as bit set, each unit represented by the bit `1 << unit as u8`.
+static DEFAULT_UNITS: AtomicU8 = AtomicU8::new( 1 << Unit::Second as u8 );
+
+
 
 
 //=============================================================================
@@ -66,6 +85,9 @@ const MILLIS_PER_SEC: i64 = 1000;
 
 /// Returns the last digit of an unsigned integer number.
 #[derive( Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Debug )]
+#[cfg_attr( feature = "rkyv", derive( rkyv::Archive, rkyv::Serialize, rkyv::Deserialize ) )]
+#[cfg_attr( feature = "rkyv", rkyv( compare( PartialEq ), derive( Debug ) ) )]
+#[cfg_attr( feature = "uniffi", derive( uniffi::Enum ) )]
 pub enum Unit {
 	Year,
 	Month,
@@ -77,6 +99,9 @@ pub enum Unit {
 }
 
 impl Unit {
+	/// All units ordered from largest to smallest.
+	pub(super) const ALL: [Self; 7] = [ Self::Year, Self::Month, Self::Week, Self::Day, Self::Hour, Self::Minute, Self::Second ];
+
 	/// Represent unit as symbol.
 	///
 	/// # Example
@@ -84,11 +109,11 @@ impl Unit {
 	/// ```
 	/// use normtime::Unit;
 	///
-	/// assert_eq!( Unit::Year.to_string_sym(), "y" );
-	/// assert_eq!( Unit::Second.to_string_sym(), "s" );
+	/// assert_eq!( Unit::Year.as_sym(), "y" );
+	/// assert_eq!( Unit::Second.as_sym(), "s" );
 	/// ```
-	pub fn to_string_sym( &self ) -> String {
-		let res = match self {
+	pub const fn as_sym( &self ) -> &'static str {
+		match self {
 			Self::Year => "y",
 			Self::Month => "m",
 			Self::Week => "w",
@@ -96,9 +121,69 @@ impl Unit {
 			Self::Hour => "h",
 			Self::Minute => "min",
 			Self::Second => "s",
-		};
+		}
+	}
 
-		res.to_string()
+	/// Represent unit as symbol.
+	///
+	/// Prefer [`Unit::as_sym`], which does not allocate.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::Unit;
+	///
+	/// assert_eq!( Unit::Year.to_string_sym(), "y" );
+	/// assert_eq!( Unit::Second.to_string_sym(), "s" );
+	/// ```
+	pub fn to_string_sym( &self ) -> String {
+		self.as_sym().to_string()
+	}
+
+	/// Represent unit by its (plural) name. This is identical to the `Display` representation.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::Unit;
+	///
+	/// assert_eq!( Unit::Year.as_str(), "normyears" );
+	/// assert_eq!( Unit::Second.as_str(), "seconds" );
+	/// ```
+	pub const fn as_str( &self ) -> &'static str {
+		match self {
+			Self::Year => "normyears",
+			Self::Month => "normmonths",
+			Self::Week => "normweeks",
+			Self::Day => "normdays",
+			Self::Hour => "hours",
+			Self::Minute => "minutes",
+			Self::Second => "seconds",
+		}
+	}
+
+	/// Returns the singular name of the unit, e.g. `"normyear"`.
+	pub(super) fn as_str_singular( &self ) -> &'static str {
+		let name = self.as_str();
+		&name[..name.len() - 1]
+	}
+
+	/// Returns the unit represented by the symbol `sym`, e.g. `"d"`.
+	pub(super) fn from_sym( sym: &str ) -> Option<Self> {
+		Self::ALL.into_iter().find( |x| x.as_sym() == sym )
+	}
+
+	/// Returns the duration of the unit in seconds.
+	pub(super) const fn seconds( &self ) -> i64 {
+		match self {
+			Self::Year => DUR_NORMYEAR,
+			Self::Month => DUR_NORMMONTH,
+			Self::Week => DUR_NORMWEEK,
+			Self::Day => DUR_NORMDAY,
+			Self::Hour => DUR_HOUR,
+			Self::Minute => DUR_MINUTE,
+			Self::Second => 1,
+		}
 	}
 }
 
@@ -135,15 +220,7 @@ impl FromStr for Unit {
 /// ```
 impl fmt::Display for Unit {
 	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result {
-		match self {
-			Self::Year => write!( f, "normyears" ),
-			Self::Month => write!( f, "normmonths" ),
-			Self::Week => write!( f, "normweeks" ),
-			Self::Day => write!( f, "normdays" ),
-			Self::Hour => write!( f, "hours" ),
-			Self::Minute => write!( f, "minutes" ),
-			Self::Second => write!( f, "seconds" ),
-		}
+		f.pad( self.as_str() )
 	}
 }
 
@@ -199,7 +276,11 @@ impl LatexSym for Unit {
 	/// assert_eq!( Unit::Year.to_latex_sym( &TexOptions::new() ), r"\normyear" );
 	/// assert_eq!( Unit::Second.to_latex_sym( &TexOptions::new() ), r"\second" );
 	/// ```
-	fn to_latex_sym( &self, _options: &TexOptions ) -> String {
+	fn to_latex_sym( &self, options: &TexOptions ) -> String {
+		if let Some( name ) = options.unit_macro_of( *self ) {
+			return name.to_string();
+		}
+
 		let res = match self {
 			Self::Year => r"\normyear",
 			Self::Month => r"\normmonth",
@@ -230,6 +311,20 @@ fn last_digit( number: u64 ) -> u64 {
 	number % 10
 }
 
+/// Returns `units` as bit set like [`DEFAULT_UNITS`]. An empty `units` results in seconds.
+fn units_to_bits( units: &[Unit] ) -> u8 {
+	let bits = units.iter().fold( 0, |acc, x| acc | 1 << *x as u8 );
+
+	if bits == 0 { 1 << Unit::Second as u8 } else { bits }
+}
+
+/// Returns the units of the bit set `bits`, ordered from largest to smallest.
+fn units_from_bits( bits: u8 ) -> Vec<Unit> {
+	Unit::ALL.into_iter()
+		.filter( |x| bits & 1 << *x as u8 != 0 )
+		.collect()
+}
+
 
 
 
@@ -241,10 +336,14 @@ fn last_digit( number: u64 ) -> u64 {
 /// `NormTimeDelta` differs from e.g. `chrono::TimeDelta`, that it uses normdays, normweeks etc. that have a different duration than standard days etc. The duration of a second is identical, though.
 ///
 /// The range is restricted between `-i64::MAX` and `i64::MAX` *milliseconds*.
-#[derive( Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Debug )]
+#[derive( Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default )]
+#[cfg_attr( feature = "rkyv", derive( rkyv::Archive, rkyv::Serialize, rkyv::Deserialize ) )]
+#[cfg_attr( feature = "rkyv", rkyv( compare( PartialEq ), derive( Debug ) ) )]
+#[cfg_attr( feature = "diesel", derive( diesel::expression::AsExpression, diesel::deserialize::FromSqlRow ) )]
+#[cfg_attr( feature = "diesel", diesel( sql_type = diesel::sql_types::BigInt ) )]
 pub struct NormTimeDelta{
 	pub(super) secs: i64,
-	nanos: i32,
+	pub(super) nanos: i32,
 }
 
 impl NormTimeDelta {
@@ -255,13 +354,13 @@ impl NormTimeDelta {
 	};
 
 	/// The minimum possible `NormTimeDelta`. This is limited to `-i64::MAX` milliseconds.
-	const MIN: Self = Self {
+	pub(super) const MIN: Self = Self {
 		secs: -i64::MAX / MILLIS_PER_SEC - 1,
 		nanos: NANOS_PER_SEC + ( -i64::MAX % MILLIS_PER_SEC ) as i32 * NANOS_PER_MILLI,
 	};
 
 	/// The maximum possible `NormTimeDelta`. This is limited to `i64::MAX` milliseconds.
-	const MAX: NormTimeDelta = NormTimeDelta {
+	pub(super) const MAX: NormTimeDelta = NormTimeDelta {
 		secs: i64::MAX / MILLIS_PER_SEC,
 		nanos: ( i64::MAX % MILLIS_PER_SEC ) as i32 * NANOS_PER_MILLI,
 	};
@@ -300,7 +399,7 @@ impl NormTimeDelta {
 	///
 	/// assert_eq!( NormTimeDelta::new_seconds( 0 ), NormTimeDelta::ZERO );
 	/// ```
-	pub fn new_seconds( secs: i64 ) -> Self {
+	pub const fn new_seconds( secs: i64 ) -> Self {
 		Self::new( secs, 0 ).expect( "NormTimeDelta::new_seconds is out of bounds" )
 	}
 
@@ -316,7 +415,7 @@ impl NormTimeDelta {
 	/// assert_eq!( NormTimeDelta::new_minutes( 0 ), NormTimeDelta::ZERO );
 	/// assert_eq!( NormTimeDelta::new_minutes( 1 ), NormTimeDelta::new_seconds( 60 ) );
 	/// ```
-	pub fn new_minutes( minutes: i64 ) -> Self {
+	pub const fn new_minutes( minutes: i64 ) -> Self {
 		Self::new( minutes * DUR_MINUTE, 0 ).expect( "NormTimeDelta::new_minutes is out of bounds" )
 	}
 
@@ -331,8 +430,11 @@ impl NormTimeDelta {
 	///
 	/// assert_eq!( NormTimeDelta::new_hours( 0 ), NormTimeDelta::ZERO );
 	/// assert_eq!( NormTimeDelta::new_hours( 1 ), NormTimeDelta::new_seconds( 3600 ) );
+	///
+	/// const SHIFT: NormTimeDelta = NormTimeDelta::new_hours( 8 );
+	/// assert_eq!( SHIFT.seconds(), 28_800 );
 	/// ```
-	pub fn new_hours( hours: i64 ) -> Self {
+	pub const fn new_hours( hours: i64 ) -> Self {
 		Self::new( hours * DUR_HOUR, 0 ).expect( "NormTimeDelta::new_hours is out of bounds" )
 	}
 
@@ -347,10 +449,40 @@ impl NormTimeDelta {
 	///
 	/// assert_eq!( NormTimeDelta::new_days( 1 ), NormTimeDelta::new_seconds( 100_000 ) );
 	/// ```
-	pub fn new_days( days: i64 ) -> Self {
+	pub const fn new_days( days: i64 ) -> Self {
 		Self::new( days * DUR_NORMDAY, 0 ).expect( "NormTimeDelta::new_days is out of bounds" )
 	}
 
+	/// Creates a new `NormTimeDelta` that has a duration of `weeks` normweeks.
+	///
+	/// **Note:** If the duration cannot be expressed in `i64` seconds, this method will panic.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDelta;
+	///
+	/// assert_eq!( NormTimeDelta::new_weeks( 1 ), NormTimeDelta::new_days( 10 ) );
+	/// ```
+	pub const fn new_weeks( weeks: i64 ) -> Self {
+		Self::new( weeks * DUR_NORMWEEK, 0 ).expect( "NormTimeDelta::new_weeks is out of bounds" )
+	}
+
+	/// Creates a new `NormTimeDelta` that has a duration of `months` normmonths.
+	///
+	/// **Note:** If the duration cannot be expressed in `i64` seconds, this method will panic.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDelta;
+	///
+	/// assert_eq!( NormTimeDelta::new_months( 1 ), NormTimeDelta::new_days( 30 ) );
+	/// ```
+	pub const fn new_months( months: i64 ) -> Self {
+		Self::new( months * DUR_NORMMONTH, 0 ).expect( "NormTimeDelta::new_months is out of bounds" )
+	}
+
 	/// Creates a new `NormTimeDelta` that has a duration of `years` normyears.
 	///
 	/// **Note:** If the duration cannot be expressed in `i64` seconds, this method will panic.
@@ -361,7 +493,7 @@ impl NormTimeDelta {
 	/// use normtime::NormTimeDelta;
 	/// assert_eq!( NormTimeDelta::new_years( 1 ), NormTimeDelta::new_seconds( 30_000_000 ) );
 	/// ```
-	pub fn new_years( years: i64 ) -> Self {
+	pub const fn new_years( years: i64 ) -> Self {
 		Self::new( years * DUR_NORMYEAR, 0 ).expect( "NormTimeDelta::new_years is out of bounds" )
 	}
 
@@ -375,10 +507,100 @@ impl NormTimeDelta {
 	/// use normtime::NormTimeDelta;
 	/// assert_eq!( NormTimeDelta::new_earthyears( 1 ), NormTimeDelta::new_seconds( 31_557_600 ) );
 	/// ```
-	pub fn new_earthyears( years: i64 ) -> Self {
+	pub const fn new_earthyears( years: i64 ) -> Self {
 		Self::new( years * DUR_TERRAYEAR, 0 ).expect( "NormTimeDelta::new_earthyears is out of bounds" )
 	}
 
+	/// Creates a new `NormTimeDelta` that has a duration of `value` times `unit`. This is useful if the unit is only known at runtime.
+	///
+	/// **Note:** If the duration cannot be expressed in `i64` seconds, this method will panic.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTimeDelta, Unit};
+	///
+	/// assert_eq!( NormTimeDelta::from_unit( 3, Unit::Day ), NormTimeDelta::new_days( 3 ) );
+	/// assert_eq!( NormTimeDelta::from_unit( -2, Unit::Week ), NormTimeDelta::new_seconds( -2_000_000 ) );
+	/// ```
+	pub const fn from_unit( value: i64, unit: Unit ) -> Self {
+		match value.checked_mul( unit.seconds() ) {
+			Some( x ) => Self::new( x, 0 ).expect( "NormTimeDelta::from_unit is out of bounds" ),
+			None => panic!( "NormTimeDelta::from_unit is out of bounds" ),
+		}
+	}
+
+	/// Returns `self` as 12 bytes in big-endian order: 8 bytes of seconds with flipped sign bit followed by 4 bytes of nanoseconds. The lexicographic order of the bytes matches the order of the durations.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDelta;
+	///
+	/// let short = NormTimeDelta::new_seconds( -5 );
+	/// let long = NormTimeDelta::new( 3, 500 ).unwrap();
+	/// assert!( short.to_be_bytes() < long.to_be_bytes() );
+	/// assert_eq!( NormTimeDelta::from_be_bytes( long.to_be_bytes() ), Some( long ) );
+	/// ```
+	pub const fn to_be_bytes( &self ) -> [u8; 12] {
+		let secs = ( self.secs as u64 ^ ( 1 << 63 ) ).to_be_bytes();
+		let nanos = ( self.nanos as u32 ).to_be_bytes();
+
+		let mut res = [ 0; 12 ];
+		let mut i = 0;
+		while i < 8 {
+			res[i] = secs[i];
+			i += 1;
+		}
+		while i < 12 {
+			res[i] = nanos[i - 8];
+			i += 1;
+		}
+
+		res
+	}
+
+	/// Creates a `NormTimeDelta` from the bytes returned by [`NormTimeDelta::to_be_bytes`]. Returns `None` if the bytes do not represent a valid `NormTimeDelta`.
+	pub const fn from_be_bytes( bytes: [u8; 12] ) -> Option<Self> {
+		let secs = u64::from_be_bytes( [ bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7] ] ) ^ ( 1 << 63 );
+		let nanos = u32::from_be_bytes( [ bytes[8], bytes[9], bytes[10], bytes[11] ] );
+
+		Self::new( secs as i64, nanos )
+	}
+
+	/// Returns the sum of the `parts` in const contexts, each given as number of a unit. Returns `None` if the sum is out of bounds. Used by the [`normdelta!`](crate::normdelta) macro.
+	#[doc( hidden )]
+	pub const fn __sum_const( parts: &[( i64, Unit )] ) -> Option<Self> {
+		let mut secs: i64 = 0;
+		let mut i = 0;
+		while i < parts.len() {
+			let ( number, unit ) = parts[i];
+			secs = match number.checked_mul( unit.seconds() ) {
+				Some( x ) => match secs.checked_add( x ) {
+					Some( y ) => y,
+					None => return None,
+				},
+				None => return None,
+			};
+			i += 1;
+		}
+
+		Self::new( secs, 0 )
+	}
+
+	/// Creates a new `NormTimeDelta` of `nanos` nanoseconds. `nanos` has to be within the range of `NormTimeDelta`.
+	pub(super) const fn from_total_nanos( nanos: i128 ) -> Self {
+		Self {
+			secs: nanos.div_euclid( NANOS_PER_SEC as i128 ) as i64,
+			nanos: nanos.rem_euclid( NANOS_PER_SEC as i128 ) as i32,
+		}
+	}
+
+	/// Returns the duration of `self` in nanoseconds.
+	pub(super) const fn total_nanos( &self ) -> i128 {
+		self.secs as i128 * NANOS_PER_SEC as i128 + self.nanos as i128
+	}
+
 	/// Returns the subsecond fraction of `NormTimeDelta` as number of nanoseconds.
 	///
 	/// # Example
@@ -388,7 +610,7 @@ impl NormTimeDelta {
 	///
 	/// assert_eq!( NormTimeDelta::new( 1, 10 ).unwrap().subsec_nanos(), 10 );
 	/// ```
-	pub fn subsec_nanos( &self ) -> i32 {
+	pub const fn subsec_nanos( &self ) -> i32 {
 		if self.secs < 0 && self.nanos > 0 {
 			self.nanos - NANOS_PER_SEC
 		} else {
@@ -404,7 +626,7 @@ impl NormTimeDelta {
 	/// use normtime::NormTimeDelta;
 	/// assert_eq!( NormTimeDelta::new_years( -1 ).abs(), NormTimeDelta::new_seconds( 30_000_000 ) );
 	/// ```
-	pub fn abs( self ) -> Self {
+	pub const fn abs( self ) -> Self {
 		if self.secs < 0 && self.nanos != 0 {
 			Self {
 				secs: ( self.secs + 1 ).abs(),
@@ -419,12 +641,62 @@ impl NormTimeDelta {
 	}
 
 	/// Returns `true` if `self` has a duration of 0 seconds.
-	pub fn is_zero( &self ) -> bool {
+	pub const fn is_zero( &self ) -> bool {
 		self.secs == 0 && self.nanos == 0
 	}
 
+	/// Returns `true` if `self` is shorter than zero, even if only by a fraction of a second.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDelta;
+	///
+	/// assert!( NormTimeDelta::new( -1, 999_999_999 ).unwrap().is_negative() );
+	/// assert!( !NormTimeDelta::ZERO.is_negative() );
+	/// ```
+	pub const fn is_negative( &self ) -> bool {
+		// The nanoseconds are always positive, so the sign is determined by the seconds alone.
+		self.secs < 0
+	}
+
+	/// Returns `true` if `self` is longer than zero, even if only by a fraction of a second.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDelta;
+	///
+	/// assert!( NormTimeDelta::new( 0, 1 ).unwrap().is_positive() );
+	/// assert!( !NormTimeDelta::ZERO.is_positive() );
+	/// ```
+	pub const fn is_positive( &self ) -> bool {
+		self.secs > 0 || ( self.secs == 0 && self.nanos > 0 )
+	}
+
+	/// Returns `-1` if `self` is negative, `0` if it is zero and `1` if it is positive.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDelta;
+	///
+	/// assert_eq!( NormTimeDelta::new( -1, 500_000_000 ).unwrap().signum(), -1 );
+	/// assert_eq!( NormTimeDelta::ZERO.signum(), 0 );
+	/// assert_eq!( NormTimeDelta::new( 0, 500_000_000 ).unwrap().signum(), 1 );
+	/// ```
+	pub const fn signum( &self ) -> i64 {
+		if self.is_negative() {
+			-1
+		} else if self.is_positive() {
+			1
+		} else {
+			0
+		}
+	}
+
 	/// Returns the duration of `self` in seconds.
-	pub fn seconds( &self ) -> i64 {
+	pub const fn seconds( &self ) -> i64 {
 		if self.secs < 0 && self.nanos > 0 {
 			self.secs + 1
 		} else {
@@ -443,7 +715,7 @@ impl NormTimeDelta {
 	/// assert_eq!( NormTimeDelta::new_seconds( 119 ).minutes(), 1 );
 	/// assert_eq!( NormTimeDelta::new_seconds( 120 ).minutes(), 2 );
 	/// ```
-	pub fn minutes( &self ) -> i64 {
+	pub const fn minutes( &self ) -> i64 {
 		self.seconds() / DUR_MINUTE
 	}
 
@@ -458,7 +730,7 @@ impl NormTimeDelta {
 	/// assert_eq!( NormTimeDelta::new_seconds( 7199 ).hours(), 1 );
 	/// assert_eq!( NormTimeDelta::new_seconds( 7200 ).hours(), 2 );
 	/// ```
-	pub fn hours( &self ) -> i64 {
+	pub const fn hours( &self ) -> i64 {
 		self.seconds() / DUR_HOUR
 	}
 
@@ -473,7 +745,7 @@ impl NormTimeDelta {
 	/// assert_eq!( NormTimeDelta::new_seconds( 199_999 ).days(), 1 );
 	/// assert_eq!( NormTimeDelta::new_seconds( 200_000 ).days(), 2 );
 	/// ```
-	pub fn days( &self ) -> i64 {
+	pub const fn days( &self ) -> i64 {
 		self.seconds() / DUR_NORMDAY
 	}
 
@@ -488,7 +760,7 @@ impl NormTimeDelta {
 	/// assert_eq!( NormTimeDelta::new_seconds( 1_999_999 ).weeks(), 1 );
 	/// assert_eq!( NormTimeDelta::new_seconds( 2_000_000 ).weeks(), 2 );
 	/// ```
-	pub fn weeks( &self ) -> i64 {
+	pub const fn weeks( &self ) -> i64 {
 		self.seconds() / DUR_NORMWEEK
 	}
 
@@ -503,7 +775,7 @@ impl NormTimeDelta {
 	/// assert_eq!( NormTimeDelta::new_seconds( 3_999_999 ).months(), 1 );
 	/// assert_eq!( NormTimeDelta::new_seconds( 6_000_000 ).months(), 2 );
 	/// ```
-	pub fn months( &self ) -> i64 {
+	pub const fn months( &self ) -> i64 {
 		self.seconds() / DUR_NORMMONTH
 	}
 
@@ -518,10 +790,25 @@ impl NormTimeDelta {
 	/// assert_eq!( NormTimeDelta::new_seconds( 89_000_000 ).years(), 2 );
 	/// assert_eq!( NormTimeDelta::new_seconds( 90_000_000 ).years(), 3 );
 	/// ```
-	pub fn years( &self ) -> i64 {
+	pub const fn years( &self ) -> i64 {
 		self.seconds() / DUR_NORMYEAR
 	}
 
+	/// Returns the duration of `self` in `unit`, including fractions. This is useful if the unit is only known at runtime.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTimeDelta, Unit};
+	///
+	/// assert_eq!( NormTimeDelta::new_days( 3 ).to_unit( Unit::Day ), 3.0 );
+	/// assert_eq!( NormTimeDelta::new_days( 3 ).to_unit( Unit::Week ), 0.3 );
+	/// assert_eq!( NormTimeDelta::new( -1, 500_000_000 ).unwrap().to_unit( Unit::Second ), -0.5 );
+	/// ```
+	pub fn to_unit( &self, unit: Unit ) -> f64 {
+		( self.secs as f64 + f64::from( self.nanos ) / 1e9 ) / unit.seconds() as f64
+	}
+
 	/// Returns the duration of `self` in rough categories. E.g. "Kleinkind", "Kind", "Teenager", "Anfang 20", "Mitte 20", "Ende 20" etc.
 	///
 	/// # Example
@@ -560,47 +847,24 @@ impl NormTimeDelta {
 		}
 	}
 
-	/// Returns duration as a vector of unit representations with selectable units rounded to the smallest unit provided.
-	fn as_units( &self, units: &[Unit] ) -> Vec<(i64, Unit)> {
-		let mut number = self.seconds();
-
-		let mut elems: Vec<(i64, Unit)> = Vec::new();
-
-		if units.iter().any( |x| x == &Unit::Year ) {
-			let val = number / DUR_NORMYEAR;
-			elems.push( ( val, Unit::Year ) );
-			number -= val * DUR_NORMYEAR;
-		}
-		if units.iter().any( |x| x == &Unit::Month ) {
-			let val = number / DUR_NORMMONTH;
-			elems.push( ( val, Unit::Month ) );
-			number -= val * DUR_NORMMONTH;
-		}
-		if units.iter().any( |x| x == &Unit::Week ) {
-			let val = number / DUR_NORMWEEK;
-			elems.push( ( val, Unit::Week ) );
-			number -= val * DUR_NORMWEEK;
-		}
-		if units.iter().any( |x| x == &Unit::Day ) {
-			let val = number / DUR_NORMDAY;
-			elems.push( ( val, Unit::Day ) );
-			number -= val * DUR_NORMDAY;
-		}
-		if units.iter().any( |x| x == &Unit::Hour ) {
-			let val = number / DUR_HOUR;
-			elems.push( ( val, Unit::Hour ) );
-			number -= val * DUR_HOUR;
-		}
-		if units.iter().any( |x| x == &Unit::Minute ) {
-			let val = number / DUR_MINUTE;
-			elems.push( ( val, Unit::Minute ) );
-			number -= val * DUR_MINUTE;
-		}
-		if units.iter().any( |x| x == &Unit::Second ) {
-			elems.push( ( number, Unit::Second ) );
-		}
+	/// Returns how many whole `unit`s fit into `self` and the remaining duration. The split is euclidean: The remainder is never negative and always shorter than `unit`, so negative durations round the quotient down.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTimeDelta, Unit};
+	///
+	/// let delta = NormTimeDelta::new_days( 25 );
+	/// assert_eq!( delta.div_mod_unit( Unit::Week ), ( 2, NormTimeDelta::new_days( 5 ) ) );
+	///
+	/// let delta = NormTimeDelta::new_days( -25 );
+	/// assert_eq!( delta.div_mod_unit( Unit::Week ), ( -3, NormTimeDelta::new_days( 5 ) ) );
+	/// ```
+	pub const fn div_mod_unit( &self, unit: Unit ) -> ( i64, Self ) {
+		let unit_nanos = unit.seconds() as i128 * NANOS_PER_SEC as i128;
+		let nanos = self.total_nanos();
 
-		elems
+		( nanos.div_euclid( unit_nanos ) as i64, Self::from_total_nanos( nanos.rem_euclid( unit_nanos ) ) )
 	}
 
 	/// Returns the duration as string with symbol as unit.
@@ -617,8 +881,47 @@ impl NormTimeDelta {
 		format!( r"{} s", self.secs )
 	}
 
+	/// Returns the duration as digital clock `HH:MM:SS`. Hours are not wrapped into normdays, so there may be more than two digits. Negative durations are prefixed with `-`. Subseconds are truncated, so durations between -1 and 0 seconds are shown without sign.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDelta;
+	///
+	/// assert_eq!( NormTimeDelta::new_seconds( 90 ).to_string_clock(), "00:01:30" );
+	/// assert_eq!( NormTimeDelta::new_days( 1 ).to_string_clock(), "27:46:40" );
+	/// assert_eq!( NormTimeDelta::new_hours( -120 ).to_string_clock(), "-120:00:00" );
+	/// ```
+	pub fn to_string_clock( &self ) -> String {
+		let secs = self.abs().secs;
+		let sign = if self.secs < 0 && secs > 0 { "-" } else { "" };
+
+		format!( "{}{:0>2}:{:0>2}:{:0>2}", sign, secs / DUR_HOUR, secs % DUR_HOUR / DUR_MINUTE, secs % DUR_MINUTE )
+	}
+
+	/// Returns the duration as digital clock prefixed by the number of normdays: `Dd HH:MM:SS`. Negative durations are prefixed with `-`. Subseconds are truncated like in [`to_string_clock`](Self::to_string_clock).
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDelta;
+	///
+	/// assert_eq!( NormTimeDelta::new_seconds( 90 ).to_string_clock_days(), "0d 00:01:30" );
+	/// assert_eq!( NormTimeDelta::new_days( 1 ).to_string_clock_days(), "1d 00:00:00" );
+	/// assert_eq!( NormTimeDelta::new_hours( -120 ).to_string_clock_days(), "-4d 08:53:20" );
+	/// ```
+	pub fn to_string_clock_days( &self ) -> String {
+		let secs = self.abs().secs;
+		let sign = if self.secs < 0 && secs > 0 { "-" } else { "" };
+		let subday = secs % DUR_NORMDAY;
+
+		format!( "{}{}d {:0>2}:{:0>2}:{:0>2}", sign, secs / DUR_NORMDAY, subday / DUR_HOUR, subday % DUR_HOUR / DUR_MINUTE, subday % DUR_MINUTE )
+	}
+
 	/// Returns a string representation of `self` with selectable units rounded to the smallest unit provided. Selected units, that are too large (would be 0) are omitted.
 	///
+	/// Every component of a negative duration carries the sign, e.g. `"-900 normdays -1 hour"`. Up to version 0.4.2, the negative components were omitted as well, which resulted in an empty string for all negative durations.
+	///
 	/// # Example
 	///
 	/// ```
@@ -640,19 +943,120 @@ impl NormTimeDelta {
 	/// );
 	/// ```
 	pub fn to_string_unit( &self, units: &[Unit] ) -> String {
-		self.as_units( units ).iter()
-			.filter( |( k, _ )| k > &0 )
-			.map( |( k, v )| {
-				let name_unit = v.to_string();
-				let postfix = if *k == 1 {
-					name_unit[0..name_unit.len()-1].to_string()
-				} else {
-					name_unit
-				};
-				format!( "{} {}", k, postfix )
-			} )
-			.collect::<Vec<String>>()
-			.join( " " )
+		self.to_string_unit_with( units, &TextOptions::new() )
+	}
+
+	/// Returns a string representation of `self` like [`to_string_unit`](Self::to_string_unit) formatted according to `options`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{Notation, NormTimeDelta, TextOptions, Unit};
+	///
+	/// let options = TextOptions {
+	///     notation: Notation::SiPrefix,
+	///     ..TextOptions::new()
+	/// };
+	///
+	/// let delta = NormTimeDelta::new_years( 3_217_543 );
+	/// assert_eq!( delta.to_string_unit_with( &[ Unit::Year ], &options ), "3.22M normyears" );
+	/// ```
+	pub fn to_string_unit_with( &self, units: &[Unit], options: &TextOptions ) -> String {
+		unit_fmt::unit( i128::from( self.seconds() ), units, options )
+	}
+
+	/// Returns the largest unit of which `self` amounts to at least one, regardless of the sign. Durations shorter than a second return `Unit::Second`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTimeDelta, Unit};
+	///
+	/// assert_eq!( NormTimeDelta::new_days( 25 ).best_unit(), Unit::Week );
+	/// assert_eq!( NormTimeDelta::new_hours( -5 ).best_unit(), Unit::Hour );
+	/// assert_eq!( NormTimeDelta::ZERO.best_unit(), Unit::Second );
+	/// ```
+	pub fn best_unit( &self ) -> Unit {
+		let secs = self.seconds().unsigned_abs();
+
+		Unit::ALL.into_iter()
+			.find( |x| secs >= x.seconds() as u64 )
+			.unwrap_or( Unit::Second )
+	}
+
+	/// Returns `self` as string in its [`best_unit`](Self::best_unit), truncating the remainder, e.g. `"2 normweeks"`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDelta;
+	///
+	/// assert_eq!( NormTimeDelta::new_days( 25 ).to_string_auto(), "2 normweeks" );
+	/// assert_eq!( NormTimeDelta::new_days( 1 ).to_string_auto(), "1 normday" );
+	/// assert_eq!( NormTimeDelta::new_hours( -5 ).to_string_auto(), "-5 hours" );
+	/// assert_eq!( NormTimeDelta::ZERO.to_string_auto(), "0 seconds" );
+	/// ```
+	pub fn to_string_auto( &self ) -> String {
+		let unit = self.best_unit();
+		let value = self.seconds() / unit.seconds();
+		let name = if value.abs() == 1 { unit.as_str_singular() } else { unit.as_str() };
+
+		format!( "{} {}", value, name )
+	}
+
+	/// Sets the units used by [`to_string_default_units`](Self::to_string_default_units) for the whole application. Duplicates and the order of `units` are irrelevant, an empty `units` resets the default of seconds.
+	pub fn set_default_units( units: &[Unit] ) {
+		DEFAULT_UNITS.store( units_to_bits( units ), AtomicOrdering::Relaxed );
+	}
+
+	/// Returns the units used by [`to_string_default_units`](Self::to_string_default_units), ordered from largest to smallest.
+	pub fn default_units() -> Vec<Unit> {
+		units_from_bits( DEFAULT_UNITS.load( AtomicOrdering::Relaxed ) )
+	}
+
+	/// Returns a string representation of `self` like [`to_string_unit`](Self::to_string_unit) using the units set by [`set_default_units`](Self::set_default_units). Without setting the units, the duration is written in seconds.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTimeDelta, Unit};
+	///
+	/// let delta = NormTimeDelta::new_seconds( 90_005_000 );
+	/// assert_eq!( delta.to_string_default_units(), "90005000 seconds" );
+	///
+	/// NormTimeDelta::set_default_units( &[ Unit::Day, Unit::Hour ] );
+	/// assert_eq!( NormTimeDelta::default_units(), [ Unit::Day, Unit::Hour ] );
+	/// assert_eq!( delta.to_string_default_units(), "900 normdays 1 hour" );
+	/// ```
+	pub fn to_string_default_units( &self ) -> String {
+		self.to_string_unit( &Self::default_units() )
+	}
+
+	/// Returns the `n` largest units of `self` that are not zero, when decomposing `self` over all units.
+	fn top_units( &self, n: usize ) -> Vec<Unit> {
+		unit_fmt::as_units( i128::from( self.seconds() ), &Unit::ALL )
+			.filter( |( k, _ )| *k != 0 )
+			.take( n )
+			.map( |( _, v )| v )
+			.collect()
+	}
+
+	/// Returns a string representation of `self` decomposed over all units, emitting only the `n` most significant components that are not zero. The smaller components are truncated.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDelta;
+	///
+	/// let delta = NormTimeDelta::new_years( 2 ) + NormTimeDelta::new_days( 90 ) + NormTimeDelta::new_seconds( 5_000 );
+	/// assert_eq!( delta.to_string_top_units( 2 ), "2 normyears 3 normmonths" );
+	/// assert_eq!( delta.to_string_top_units( 3 ), "2 normyears 3 normmonths 1 hour" );
+	///
+	/// assert_eq!( NormTimeDelta::new_seconds( 90 ).to_string_top_units( 2 ), "1 minute 30 seconds" );
+	/// assert_eq!( NormTimeDelta::new_seconds( -90 ).to_string_top_units( 2 ), "-1 minute -30 seconds" );
+	/// ```
+	pub fn to_string_top_units( &self, n: usize ) -> String {
+		self.to_string_unit( &self.top_units( n ) )
 	}
 
 	/// Returns a string representation of `self` with selectable units rounded to the smallest unit provided. Selected units, that are too large (would be 0) are omitted.
@@ -679,32 +1083,40 @@ impl NormTimeDelta {
 	/// ```
 	#[cfg( feature = "tex" )]
 	pub fn to_latex_unit( &self, units: &[Unit] ) -> String {
-		self.as_units( units ).iter()
-			.filter( |( k, _ )| k > &0 )
-			.map( |( k, v )| {
-				let name_unit = v.to_string();
-				let postfix = if *k == 1 {
-					name_unit[0..name_unit.len()-1].to_string()
-				} else {
-					name_unit
-				};
-				format!( "{}~{}", k, postfix )
-			} )
-			.collect::<Vec<String>>()
-			.join( " " )
+		self.to_latex_unit_with( units, &TexOptions::new() )
 	}
 
-	/// Returns a string representation of `self` with selectable units rounded to the smallest unit provided. Selected units, that are too large (would be 0) are omitted. The string is using the language that is provided by `locale`.
+	/// Returns a string representation of `self` like [`to_latex_unit`](Self::to_latex_unit) formatted according to `options`.
+	///
+	/// This method is only available when the **tex** feature has been activated.
 	///
 	/// # Example
 	///
 	/// ```
-	/// use unic_langid::LanguageIdentifier;
-	/// use unic_langid::langid;
-	/// use normtime::{NormTimeDelta, Unit};
+	/// use normtime::{NormTimeDelta, TexOptions, Unit};
 	///
-	/// const US_ENGLISH: LanguageIdentifier = langid!( "en-US" );
-	/// const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+	/// let options = TexOptions::new().siunitx( "group-digits", "none" );
+	/// let delta = NormTimeDelta::new_days( 1_500 );
+	/// assert_eq!( delta.to_latex_unit_with( &[ Unit::Day ], &options ), r"\num[group-digits=none]{1500}~normdays" );
+	/// ```
+	#[cfg( feature = "tex" )]
+	pub fn to_latex_unit_with( &self, units: &[Unit], options: &TexOptions ) -> String {
+		unit_fmt::latex_unit( i128::from( self.seconds() ), units, options )
+	}
+
+	/// Returns a string representation of `self` with selectable units rounded to the smallest unit provided. Selected units, that are too large (would be 0) are omitted. The string is using the language that is provided by `locale`.
+	///
+	/// Like [`to_string_unit`](Self::to_string_unit), every component of a negative duration carries the sign, e.g. `"-900 Normtage -1 Stunde"`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use unic_langid::LanguageIdentifier;
+	/// use unic_langid::langid;
+	/// use normtime::{NormTimeDelta, Unit};
+	///
+	/// const US_ENGLISH: LanguageIdentifier = langid!( "en-US" );
+	/// const GERMAN: LanguageIdentifier = langid!( "de-DE" );
 	///
 	/// let delta = NormTimeDelta::new_seconds( 90_005_000 );
 	/// assert_eq!( delta.to_string_unit_locale( &[ Unit::Day ], &US_ENGLISH ), "900 normdays" );
@@ -746,19 +1158,32 @@ impl NormTimeDelta {
 	/// ```
 	#[cfg( feature = "i18n" )]
 	pub fn to_string_unit_locale( &self, units: &[Unit], locale: &LanguageIdentifier ) -> String {
-		self.as_units( units ).iter()
-			.filter( |( k, _ )| k > &0 )
-			.map( |( k, v )| {
-				let name_unit = v.to_string_locale( locale );
-				let postfix = if *k == 1 {
-					name_unit[0..name_unit.len()-1].to_string()
-				} else {
-					name_unit
-				};
-				format!( "{} {}", k, postfix )
-			} )
-			.collect::<Vec<String>>()
-			.join( " " )
+		self.to_string_unit_locale_with( units, locale, &TextOptions::new() )
+	}
+
+	/// Returns a string representation of `self` like [`to_string_unit_locale`](Self::to_string_unit_locale) formatted according to `options`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use unic_langid::langid;
+	/// use normtime::{NormTimeDelta, TextOptions, Unit};
+	///
+	/// let options = TextOptions {
+	///     spell_out: true,
+	///     ..TextOptions::new()
+	/// };
+	///
+	/// let delta = NormTimeDelta::new_days( 3 ) + NormTimeDelta::new_hours( 1 );
+	/// assert_eq!( delta.to_string_unit_locale_with( &[ Unit::Day, Unit::Hour ], &langid!( "en-US" ), &options ), "three normdays one hour" );
+	/// assert_eq!( delta.to_string_unit_locale_with( &[ Unit::Day, Unit::Hour ], &langid!( "de-DE" ), &options ), "drei Normtage eine Stunde" );
+	///
+	/// let delta = NormTimeDelta::new_days( 13 );
+	/// assert_eq!( delta.to_string_unit_locale_with( &[ Unit::Day ], &langid!( "en-US" ), &options ), "13 normdays" );
+	/// ```
+	#[cfg( feature = "i18n" )]
+	pub fn to_string_unit_locale_with( &self, units: &[Unit], locale: &LanguageIdentifier, options: &TextOptions ) -> String {
+		unit_fmt::unit_locale( i128::from( self.seconds() ), units, locale, options )
 	}
 
 	/// Returns a string representation of `self` with selectable units rounded to the smallest unit provided. Selected units, that are too large (would be 0) are omitted. This string is intended to be used by LaTeX. The string is translated into the language that is provided by `locale`.
@@ -813,23 +1238,36 @@ impl NormTimeDelta {
 	/// ```
 	#[cfg( all( feature = "i18n", feature = "tex" ) )]
 	pub fn to_latex_unit_locale( &self, units: &[Unit], locale: &LanguageIdentifier ) -> String {
-		self.as_units( units ).iter()
-			.filter( |( k, _ )| k > &0 )
-			.map( |( k, v )| {
-				let name_unit = v.to_string_locale( locale );
-				let postfix = if *k == 1 {
-					name_unit[0..name_unit.len()-1].to_string()
-				} else {
-					name_unit
-				};
-				format!( "{}~{}", k, postfix )
-			} )
-			.collect::<Vec<String>>()
-			.join( " " )
+		self.to_latex_unit_locale_with( units, locale, &TexOptions::new() )
+	}
+
+	/// Returns a string representation of `self` like [`to_latex_unit_locale`](Self::to_latex_unit_locale) formatted according to `options`.
+	///
+	/// This method is only available when the **i18n** and the **tex** features have been activated.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use unic_langid::langid;
+	/// use normtime::{NormTimeDelta, TexMinus, TexOptions, Unit};
+	///
+	/// let options = TexOptions::new().siunitx( "group-digits", "none" );
+	/// let delta = NormTimeDelta::new_days( 1_500 );
+	/// assert_eq!( delta.to_latex_unit_locale_with( &[ Unit::Day ], &langid!( "de-DE" ), &options ), r"\num[group-digits=none]{1500}~Normtage" );
+	///
+	/// let options = TexOptions::new().minus( TexMinus::Hyphen );
+	/// let delta = NormTimeDelta::new_days( -1 );
+	/// assert_eq!( delta.to_latex_unit_locale_with( &[ Unit::Day ], &langid!( "de-DE" ), &options ), "-1~Normtag" );
+	/// ```
+	#[cfg( all( feature = "i18n", feature = "tex" ) )]
+	pub fn to_latex_unit_locale_with( &self, units: &[Unit], locale: &LanguageIdentifier, options: &TexOptions ) -> String {
+		unit_fmt::latex_unit_locale( i128::from( self.seconds() ), units, locale, options )
 	}
 
 	/// Returns a string representation of `self` with selectable units rounded to the smallest unit provided. The units are expressed as symbols.
 	///
+	/// Like [`to_string_unit`](Self::to_string_unit), every component of a negative duration carries the sign, e.g. `"-900 d -1 h"`.
+	///
 	/// # Example
 	///
 	/// ```
@@ -851,11 +1289,12 @@ impl NormTimeDelta {
 	/// );
 	/// ```
 	pub fn to_string_sym_unit( &self, units: &[Unit] ) -> String {
-		self.as_units( units ).iter()
-			.filter( |( k, _ )| k > &0 )
-			.map( |( k, v )| format!( "{} {}", k, v.to_string_sym() ) )
-			.collect::<Vec<String>>()
-			.join( " " )
+		self.to_string_sym_unit_with( units, &TextOptions::new() )
+	}
+
+	/// Returns a string representation of `self` like [`to_string_sym_unit`](Self::to_string_sym_unit) formatted according to `options`.
+	pub fn to_string_sym_unit_with( &self, units: &[Unit], options: &TextOptions ) -> String {
+		unit_fmt::sym_unit( i128::from( self.seconds() ), units, options )
 	}
 
 	/// Returns a LaTeX-string representation of `self` with selectable units rounded to the smallest unit provided. The units are expressed as symbols using the LaTeX `{siunitx}` package.
@@ -893,11 +1332,25 @@ impl NormTimeDelta {
 	/// ```
 	#[cfg( feature = "tex" )]
 	pub fn to_latex_sym_unit( &self, units: &[Unit] ) -> String {
-		self.as_units( units ).iter()
-			.filter( |( k, _ )| k > &0 )
-			.map( |( k, v )| format!( r"\qty{{{}}}{{{}}}", k, v.to_latex_sym( &TexOptions::new() ) ) )
-			.collect::<Vec<String>>()
-			.join( "\\," )
+		self.to_latex_sym_unit_with( units, &TexOptions::new() )
+	}
+
+	/// Returns a LaTeX-string representation of `self` like [`to_latex_sym_unit`](Self::to_latex_sym_unit) formatted according to `options`.
+	///
+	/// This method is only available when the **tex** feature has been activated.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTimeDelta, TexOptions, Unit};
+	///
+	/// let options = TexOptions::new().siunitx( "round-mode", "places" );
+	/// let delta = NormTimeDelta::new_days( 1_500 );
+	/// assert_eq!( delta.to_latex_sym_unit_with( &[ Unit::Day ], &options ), r"\qty[round-mode=places]{1500}{\normday}" );
+	/// ```
+	#[cfg( feature = "tex" )]
+	pub fn to_latex_sym_unit_with( &self, units: &[Unit], options: &TexOptions ) -> String {
+		unit_fmt::latex_sym_unit( i128::from( self.seconds() ), units, options )
 	}
 
 	/// Adding two `NormTimeDelta`s. If an overflow occurs, this function returns `None`.
@@ -1020,6 +1473,165 @@ impl NormTimeDelta {
 
 		Some( Self { secs, nanos } )
 	}
+
+	/// Adds up all durations of `iter`. Unlike `.sum()`, which panics on overflow, this returns `None` if an overflow occurs.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDelta;
+	///
+	/// let items = [ NormTimeDelta::new_seconds( 10 ), NormTimeDelta::new_seconds( 11 ) ];
+	/// assert_eq!( NormTimeDelta::try_sum( &items ), Some( NormTimeDelta::new_seconds( 21 ) ) );
+	/// assert_eq!( NormTimeDelta::try_sum( items ), Some( NormTimeDelta::new_seconds( 21 ) ) );
+	///
+	/// let items = [ NormTimeDelta::new_seconds( i64::MAX / 1000 ); 2 ];
+	/// assert!( NormTimeDelta::try_sum( items ).is_none() );
+	/// ```
+	pub fn try_sum<I>( iter: I ) -> Option<Self>
+	where
+		I: IntoIterator,
+		I::Item: Borrow<Self>,
+	{
+		iter.into_iter().try_fold( Self::ZERO, |acc, x| acc.checked_add( x.borrow() ) )
+	}
+
+	/// Returns the absolute difference between `self` and `other`. The result saturates at the largest possible `NormTimeDelta` instead of panicking.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDelta;
+	///
+	/// let a = NormTimeDelta::new_seconds( -10 );
+	/// let b = NormTimeDelta::new_seconds( 5 );
+	/// assert_eq!( a.abs_diff( b ), NormTimeDelta::new_seconds( 15 ) );
+	/// assert_eq!( b.abs_diff( a ), NormTimeDelta::new_seconds( 15 ) );
+	/// ```
+	pub const fn abs_diff( self, other: Self ) -> Self {
+		let nanos = ( self.total_nanos() - other.total_nanos() ).abs();
+
+		if nanos > Self::MAX.total_nanos() {
+			return Self::MAX;
+		}
+
+		Self::from_total_nanos( nanos )
+	}
+
+	/// Returns an iterator splitting `self` into pieces of `chunk`. The last piece is shorter, if `self` is not a multiple of `chunk`. A duration that is zero or negative yields no pieces.
+	///
+	/// **Note:** Panics if `chunk` is not positive.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDelta;
+	///
+	/// let mission = NormTimeDelta::new_days( 25 );
+	/// let legs: Vec<NormTimeDelta> = mission.chunks( NormTimeDelta::new_days( 10 ) ).collect();
+	/// assert_eq!( legs, [ NormTimeDelta::new_days( 10 ), NormTimeDelta::new_days( 10 ), NormTimeDelta::new_days( 5 ) ] );
+	/// ```
+	pub fn chunks( self, chunk: Self ) -> impl Iterator<Item = Self> {
+		assert!( chunk.is_positive(), "`chunk` must be positive" );
+
+		let chunk = chunk.total_nanos();
+		let mut rest = self.total_nanos();
+
+		std::iter::from_fn( move || {
+			if rest <= 0 {
+				return None;
+			}
+
+			let res = rest.min( chunk );
+			rest -= res;
+
+			Some( Self::from_total_nanos( res ) )
+		} )
+	}
+
+	/// Returns an iterator splitting `self` into `n` parts of equal duration. If `self` cannot be divided evenly, the first parts are one nanosecond longer than the others, so that the parts always add up to `self`.
+	///
+	/// **Note:** Panics if `n` is zero.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDelta;
+	///
+	/// let workload = NormTimeDelta::new_days( 1 );
+	/// let shifts: Vec<NormTimeDelta> = workload.split_n( 4 ).collect();
+	/// assert_eq!( shifts, [ NormTimeDelta::new_seconds( 25_000 ); 4 ] );
+	///
+	/// let parts: Vec<NormTimeDelta> = NormTimeDelta::new( 0, 5 ).unwrap().split_n( 3 ).collect();
+	/// assert_eq!( parts, [ NormTimeDelta::new( 0, 2 ).unwrap(), NormTimeDelta::new( 0, 2 ).unwrap(), NormTimeDelta::new( 0, 1 ).unwrap() ] );
+	/// ```
+	pub fn split_n( self, n: u32 ) -> impl Iterator<Item = Self> {
+		assert!( n > 0, "`n` must not be zero" );
+
+		let nanos = self.total_nanos();
+		let part = nanos.div_euclid( n as i128 );
+		let longer = nanos.rem_euclid( n as i128 );
+
+		( 0..n as i128 ).map( move |i| Self::from_total_nanos( if i < longer { part + 1 } else { part } ) )
+	}
+
+	/// Restricts `self` to be within `min` and `max`.
+	///
+	/// **Note:** Panics if `min` is greater than `max`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDelta;
+	///
+	/// let ( min, max ) = ( NormTimeDelta::new_seconds( 10 ), NormTimeDelta::new_hours( 1 ) );
+	/// assert_eq!( NormTimeDelta::new_seconds( 1 ).clamp( min, max ), min );
+	/// assert_eq!( NormTimeDelta::new_minutes( 2 ).clamp( min, max ), NormTimeDelta::new_minutes( 2 ) );
+	/// assert_eq!( NormTimeDelta::new_days( 1 ).clamp( min, max ), max );
+	/// ```
+	pub const fn clamp( self, min: Self, max: Self ) -> Self {
+		assert!( min.total_nanos() <= max.total_nanos(), "`min` must not be greater than `max`" );
+
+		if self.total_nanos() < min.total_nanos() {
+			min
+		} else if self.total_nanos() > max.total_nanos() {
+			max
+		} else {
+			self
+		}
+	}
+
+	/// Restricts `self` to `range`. Since `NormTimeDelta` has a resolution of nanoseconds, an excluded upper bound clamps to one nanosecond less than the bound and an excluded lower bound to one nanosecond more.
+	///
+	/// **Note:** Panics if the range is empty.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDelta;
+	///
+	/// let timeout = NormTimeDelta::new_seconds( 30 );
+	/// assert_eq!( NormTimeDelta::new_minutes( 1 ).clamp_to_range( ..=timeout ), timeout );
+	/// assert_eq!( NormTimeDelta::new_seconds( -5 ).clamp_to_range( NormTimeDelta::ZERO.. ), NormTimeDelta::ZERO );
+	/// assert_eq!(
+	///     NormTimeDelta::new_minutes( 1 ).clamp_to_range( NormTimeDelta::ZERO..timeout ),
+	///     NormTimeDelta::new( 29, 999_999_999 ).unwrap()
+	/// );
+	/// ```
+	pub fn clamp_to_range<R: RangeBounds<Self>>( self, range: R ) -> Self {
+		let min = match range.start_bound() {
+			Bound::Included( x ) => *x,
+			Bound::Excluded( x ) => Self::from_total_nanos( x.total_nanos() + 1 ),
+			Bound::Unbounded => Self::MIN,
+		};
+		let max = match range.end_bound() {
+			Bound::Included( x ) => *x,
+			Bound::Excluded( x ) => Self::from_total_nanos( x.total_nanos() - 1 ),
+			Bound::Unbounded => Self::MAX,
+		};
+
+		self.clamp( min, max )
+	}
 }
 
 
@@ -1058,6 +1670,11 @@ impl Div<i32> for NormTimeDelta {
 	}
 }
 
+forward_ref_binop!( impl Add, add for NormTimeDelta, NormTimeDelta );
+forward_ref_binop!( impl Sub, sub for NormTimeDelta, NormTimeDelta );
+forward_ref_binop!( impl Mul, mul for NormTimeDelta, i32 );
+forward_ref_binop!( impl Div, div for NormTimeDelta, i32 );
+
 
 impl<'a> Sum<&'a NormTimeDelta> for NormTimeDelta {
 	fn sum<I: Iterator<Item = &'a NormTimeDelta>>( iter: I ) -> Self {
@@ -1071,12 +1688,106 @@ impl Sum<NormTimeDelta> for NormTimeDelta {
 	}
 }
 
+/// `TimeDelta` and `NormTimeDelta` share the same range, so every `TimeDelta` can be converted.
+///
+/// # Example
+///
+/// ```
+/// use chrono::TimeDelta;
+/// use normtime::NormTimeDelta;
+///
+/// assert_eq!( NormTimeDelta::from( TimeDelta::milliseconds( -1500 ) ), NormTimeDelta::new( -2, 500_000_000 ).unwrap() );
+/// ```
+#[cfg( feature = "chrono" )]
 impl From<TimeDelta> for NormTimeDelta {
 	fn from( item: TimeDelta ) -> Self {
-		Self {
-			secs: item.num_seconds(),
-			nanos: item.subsec_nanos(),
+		// `TimeDelta` rounds the seconds of negative durations towards zero and returns negative nanoseconds. `NormTimeDelta` always stores non-negative nanoseconds to keep `Eq` and `Hash` consistent.
+		let secs = item.num_seconds();
+		let nanos = item.subsec_nanos();
+
+		if nanos < 0 {
+			return Self {
+				secs: secs - 1,
+				nanos: nanos + NANOS_PER_SEC,
+			};
+		}
+
+		Self { secs, nanos }
+	}
+}
+
+/// Comparing a `NormTimeDelta` with a `TimeDelta` without explicit conversion. Both measure SI seconds.
+///
+/// # Example
+///
+/// ```
+/// use chrono::TimeDelta;
+/// use normtime::NormTimeDelta;
+///
+/// assert_eq!( NormTimeDelta::new_seconds( 60 ), TimeDelta::minutes( 1 ) );
+/// assert_eq!( TimeDelta::minutes( 1 ), NormTimeDelta::new_seconds( 60 ) );
+/// assert!( NormTimeDelta::new_days( 1 ) > TimeDelta::days( 1 ) );
+/// assert!( TimeDelta::milliseconds( -1500 ) < NormTimeDelta::new_seconds( -1 ) );
+/// ```
+#[cfg( feature = "chrono" )]
+impl PartialEq<TimeDelta> for NormTimeDelta {
+	fn eq( &self, other: &TimeDelta ) -> bool {
+		*self == Self::from( *other )
+	}
+}
+
+#[cfg( feature = "chrono" )]
+impl PartialEq<NormTimeDelta> for TimeDelta {
+	fn eq( &self, other: &NormTimeDelta ) -> bool {
+		NormTimeDelta::from( *self ) == *other
+	}
+}
+
+#[cfg( feature = "chrono" )]
+impl PartialOrd<TimeDelta> for NormTimeDelta {
+	fn partial_cmp( &self, other: &TimeDelta ) -> Option<Ordering> {
+		self.partial_cmp( &Self::from( *other ) )
+	}
+}
+
+#[cfg( feature = "chrono" )]
+impl PartialOrd<NormTimeDelta> for TimeDelta {
+	fn partial_cmp( &self, other: &NormTimeDelta ) -> Option<Ordering> {
+		NormTimeDelta::from( *self ).partial_cmp( other )
+	}
+}
+
+/// The alternate form (`{:#?}`) additionally shows the duration broken down into all units like [`NormTimeDelta::to_string_unit`]. Durations shorter than a second are shown as `"0 seconds"`.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormTimeDelta;
+///
+/// let delta = NormTimeDelta::new_days( 1 ) + NormTimeDelta::new_hours( 2 );
+/// assert_eq!( format!( "{:?}", delta ), "NormTimeDelta { secs: 107200, nanos: 0 }" );
+/// assert_eq!(
+///     format!( "{:#?}", delta ),
+///     "NormTimeDelta {\n    secs: 107200,\n    nanos: 0,\n    units: \"1 normday 2 hours\",\n}"
+/// );
+/// ```
+impl fmt::Debug for NormTimeDelta {
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result {
+		let alternate = f.alternate();
+
+		let mut res = f.debug_struct( "NormTimeDelta" );
+		res.field( "secs", &self.secs );
+		res.field( "nanos", &self.nanos );
+
+		if alternate {
+			let units = match self.to_string_unit( &Unit::ALL ) {
+				x if x.is_empty() => format!( "0 {}", Unit::Second.as_str() ),
+				x => x,
+			};
+			res.field( "units", &units );
 		}
+
+		res.finish()
 	}
 }
 
@@ -1089,12 +1800,128 @@ impl From<TimeDelta> for NormTimeDelta {
 ///
 /// assert_eq!( NormTimeDelta::new_seconds( 100 ).to_string(), "100 seconds" );
 /// assert_eq!( NormTimeDelta::new_days( 1 ).to_string(), "100000 seconds" );
+/// assert_eq!( format!( "{:>15}|", NormTimeDelta::new_seconds( 100 ) ), "    100 seconds|" );
+/// assert_eq!( format!( "{:<15}|", NormTimeDelta::new_seconds( 1 ) ), "1 second       |" );
 /// ```
 impl fmt::Display for NormTimeDelta {
 	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result {
+		f.pad( &unit_fmt::seconds( i128::from( self.secs ) ) )
+	}
+}
+
+/// Returns the number of seconds of the ISO 8601 like duration `s`, e.g. `P1Y3DT2H`. Years, months, weeks and days are interpreted as normyears, normmonths, normweeks and normdays. Returns `None` if `s` is malformed or the duration overflows.
+fn parse_iso_duration( s: &str ) -> Option<i64> {
+	let ( sign, text ) = match s.strip_prefix( '-' ) {
+		Some( x ) => ( -1, x ),
+		None => ( 1, s ),
+	};
+	let text = text.strip_prefix( 'P' )?;
+
+	let ( date, clock ) = match text.split_once( 'T' ) {
+		Some( ( x, y ) ) if !y.is_empty() => ( x, y ),
+		Some( _ ) => return None,
+		None => ( text, "" ),
+	};
+
+	let units_date = [ ( 'Y', DUR_NORMYEAR ), ( 'M', DUR_NORMMONTH ), ( 'W', DUR_NORMWEEK ), ( 'D', DUR_NORMDAY ) ];
+	let units_clock = [ ( 'H', DUR_HOUR ), ( 'M', DUR_MINUTE ), ( 'S', 1 ) ];
+
+	let mut secs: i64 = 0;
+	let mut found = false;
+	for ( part, units ) in [ ( date, &units_date[..] ), ( clock, &units_clock[..] ) ] {
+		// Units have to appear in descending order, each at most once.
+		let mut start = 0;
+		let mut next_unit = 0;
+		for ( i, c ) in part.char_indices() {
+			if c.is_ascii_digit() {
+				continue;
+			}
+
+			let pos = next_unit + units[next_unit..].iter().position( |( x, _ )| *x == c )?;
+			let number = part[start..i].parse::<i64>().ok()?;
+			secs = secs.checked_add( number.checked_mul( units[pos].1 )? )?;
+
+			start = i + c.len_utf8();
+			next_unit = pos + 1;
+			found = true;
+		}
+
+		// A number without unit.
+		if start != part.len() {
+			return None;
+		}
+	}
+
+	if !found {
+		return None;
+	}
+
+	secs.checked_mul( sign )
+}
+
+/// Parses the output of `Display` (`"100 seconds"`) and `.to_string_sym()` (`"100 s"`). Also accepts several pairs of numbers and units or unit symbols like the output of `.to_string_unit()` and `.to_string_sym_unit()`.
+///
+/// Additionally durations similar to ISO 8601 like `"P1Y3DT2H"` are accepted. Years, months, weeks and days are normyears, normmonths, normweeks and normdays.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{NormTimeDelta, Unit};
+///
+/// let delta = NormTimeDelta::new_days( 1 ) + NormTimeDelta::new_hours( 2 );
+///
+/// assert_eq!( delta.to_string().parse::<NormTimeDelta>().unwrap(), delta );
+/// assert_eq!( delta.to_string_sym().parse::<NormTimeDelta>().unwrap(), delta );
+/// assert_eq!( "1 normday 2 hours".parse::<NormTimeDelta>().unwrap(), delta );
+/// assert_eq!( "1 d 2 h".parse::<NormTimeDelta>().unwrap(), delta );
+/// assert_eq!( "-1 second".parse::<NormTimeDelta>().unwrap(), NormTimeDelta::new_seconds( -1 ) );
+/// assert!( "100".parse::<NormTimeDelta>().is_err() );
+///
+/// assert_eq!( "PT90S".parse::<NormTimeDelta>().unwrap(), NormTimeDelta::new_seconds( 90 ) );
+/// assert_eq!( "P1DT2H".parse::<NormTimeDelta>().unwrap(), delta );
+/// assert_eq!( "-P1Y".parse::<NormTimeDelta>().unwrap(), NormTimeDelta::new_years( -1 ) );
+/// ```
+impl FromStr for NormTimeDelta {
+	type Err = ConversionError;
+
+	fn from_str( s: &str ) -> Result<Self, Self::Err> {
+		let error = || ConversionError::ParseError( s.to_string() );
+
+		if s.strip_prefix( '-' ).unwrap_or( s ).starts_with( 'P' ) {
+			return parse_iso_duration( s )
+				.and_then( |x| Self::new( x, 0 ) )
+				.ok_or_else( error );
+		}
+
+		let elems: Vec<&str> = s.split_whitespace().collect();
+		if elems.is_empty() || !elems.len().is_multiple_of( 2 ) {
+			return Err( error() );
+		}
+
+		let mut secs: i64 = 0;
+		for pair in elems.chunks( 2 ) {
+			let number = pair[0].parse::<i64>().map_err( |_| error() )?;
+			let unit = match Unit::from_sym( pair[1] ) {
+				Some( x ) => x,
+				None => pair[1].parse::<Unit>()?,
+			};
+
+			secs = number.checked_mul( unit.seconds() )
+				.and_then( |x| secs.checked_add( x ) )
+				.ok_or_else( error )?;
+		}
+
+		Self::new( secs, 0 ).ok_or_else( error )
+	}
+}
+
+#[cfg( feature = "defmt" )]
+impl defmt::Format for NormTimeDelta {
+	/// Formats `self` like `Display` does, e.g. `100 seconds`, without using `core::fmt`.
+	fn format( &self, f: defmt::Formatter ) {
 		match self.secs {
-			1 => write!( f, "{} second", self.secs ),
-			_ => write!( f, "{} seconds", self.secs ),
+			1 => defmt::write!( f, "{=i64} second", self.secs ),
+			_ => defmt::write!( f, "{=i64} seconds", self.secs ),
 		}
 	}
 }
@@ -1120,10 +1947,7 @@ impl DisplayLocale for NormTimeDelta {
 	/// assert_eq!( NormTimeDelta::new_seconds( 10 ).to_string_locale( &GERMAN ), "10 Sekunden" );
 	/// ```
 	fn to_string_locale( &self, locale: &LanguageIdentifier ) -> String {
-		match self.secs {
-			1 => format!( "{} {}", self.secs, LOCALES.lookup( locale, "second" ) ),
-			_ => format!( "{} {}", self.secs, LOCALES.lookup( locale, "seconds" ) ),
-		}
+		unit_fmt::seconds_locale( i128::from( self.secs ), locale )
 	}
 }
 
@@ -1141,11 +1965,8 @@ impl Latex for NormTimeDelta {
 	/// assert_eq!( NormTimeDelta::new_seconds( 100 ).to_latex( &TexOptions::new() ), "100~seconds" );
 	/// assert_eq!( NormTimeDelta::new_days( 1 ).to_latex( &TexOptions::new() ), "100000~seconds" );
 	/// ```
-	fn to_latex( &self, _options: &TexOptions ) -> String {
-		match self.secs {
-			1 => format!( "{}~second", self.secs ),
-			_ => format!( "{}~seconds", self.secs ),
-		}
+	fn to_latex( &self, options: &TexOptions ) -> String {
+		unit_fmt::latex_seconds( i128::from( self.secs ), options )
 	}
 }
 
@@ -1181,11 +2002,8 @@ impl LatexLocale for NormTimeDelta {
 	///     "10~Sekunden"
 	/// );
 	/// ```
-	fn to_latex_locale( &self, locale: &LanguageIdentifier, _options: &TexOptions ) -> String {
-		match self.secs {
-			1 => format!( "{}~{}", self.secs, LOCALES.lookup( locale, "second" ) ),
-			_ => format!( "{}~{}", self.secs, LOCALES.lookup( locale, "seconds" ) ),
-		}
+	fn to_latex_locale( &self, locale: &LanguageIdentifier, options: &TexOptions ) -> String {
+		unit_fmt::latex_seconds_locale( i128::from( self.secs ), locale, options )
 	}
 }
 
@@ -1209,8 +2027,8 @@ impl LatexSym for NormTimeDelta {
 	/// );
 	/// ```
 	#[cfg( feature = "tex" )]
-	fn to_latex_sym( &self, _options: &TexOptions ) -> String {
-		format!( r"\qty{{{}}}{{\second}}", self.secs )
+	fn to_latex_sym( &self, options: &TexOptions ) -> String {
+		unit_fmt::latex_sym_seconds( i128::from( self.secs ), options )
 	}
 }
 
@@ -1236,7 +2054,14 @@ mod normtime_serde {
 		type Value = NormTimeDelta;
 
 		fn expecting( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
-			formatter.write_str( "an integer between -2^63 and 2^63" )
+			formatter.write_str( "an integer between -2^63 and 2^63 or a duration string" )
+		}
+
+		fn visit_str<E>( self, value: &str ) -> Result<Self::Value, E>
+		where
+			E: serde::de::Error,
+		{
+			value.parse().map_err( E::custom )
 		}
 
 		fn visit_i8<E>( self, value: i8 ) -> Result<Self::Value, E>
@@ -1305,33 +2130,329 @@ mod normtime_serde {
 		where
 			D: serde::Deserializer<'de>,
 		{
+			// Human readable formats like YAML or TOML may contain durations written as string, e.g. `"1 normyear 3 normdays"`. See `FromStr`.
+			if deserializer.is_human_readable() {
+				return deserializer.deserialize_any( NormTimeDeltaVisitor );
+			}
+
 			deserializer.deserialize_i64( NormTimeDeltaVisitor )
 		}
 	}
 }
 
 
+// `BigInt` stores the duration in seconds, matching the `serde` representation. Subseconds cannot be stored and are dropped, which rounds down to whole seconds, e.g. -1.5 s is written as -2.
+#[cfg( feature = "diesel" )]
+mod normtime_diesel {
+	use super::NormTimeDelta;
 
+	use diesel::backend::Backend;
+	use diesel::deserialize::{self, FromSql};
+	use diesel::serialize::{self, Output, ToSql};
+	use diesel::sql_types::BigInt;
+
+	impl<DB> ToSql<BigInt, DB> for NormTimeDelta
+	where
+		DB: Backend,
+		i64: ToSql<BigInt, DB>,
+	{
+		fn to_sql<'b>( &'b self, out: &mut Output<'b, '_, DB> ) -> serialize::Result {
+			self.secs.to_sql( out )
+		}
+	}
 
-//=============================================================================
-// Testing
+	impl<DB> FromSql<BigInt, DB> for NormTimeDelta
+	where
+		DB: Backend,
+		i64: FromSql<BigInt, DB>,
+	{
+		fn from_sql( bytes: DB::RawValue<'_> ) -> deserialize::Result<Self> {
+			let secs = i64::from_sql( bytes )?;
+
+			NormTimeDelta::new( secs, 0 )
+				.ok_or_else( || format!( "NormTimeDelta out of range: {} seconds", secs ).into() )
+		}
+	}
+}
 
 
-#[cfg( test )]
-mod tests {
-	use super::*;
+// `NormTimeDelta` is written as integer seconds. Reading accepts integers as well as text holding an integer.
+#[cfg( feature = "rusqlite" )]
+mod normtime_rusqlite {
+	use super::NormTimeDelta;
 
-	#[cfg( feature = "serde" )]
-	use serde_test::{Token, assert_tokens};
+	use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
 
-	#[test]
-	fn test_last_digit() {
-		assert_eq!( last_digit( 5 ), 5 );
-		assert_eq!( last_digit( 11 ), 1 );
-		assert_eq!( last_digit( 23 ), 3 );
-		assert_eq!( last_digit( 123 ), 3 );
-		assert_eq!( last_digit( 1234 ), 4 );
-		assert_eq!( last_digit( 12345 ), 5 );
+	impl ToSql for NormTimeDelta {
+		fn to_sql( &self ) -> rusqlite::Result<ToSqlOutput<'_>> {
+			Ok( ToSqlOutput::from( self.secs ) )
+		}
+	}
+
+	impl FromSql for NormTimeDelta {
+		fn column_result( value: ValueRef<'_> ) -> FromSqlResult<Self> {
+			let secs = match value {
+				ValueRef::Integer( secs ) => secs,
+				ValueRef::Text( _ ) => value.as_str()?.parse::<i64>().map_err( FromSqlError::other )?,
+				_ => return Err( FromSqlError::InvalidType ),
+			};
+
+			NormTimeDelta::new( secs, 0 ).ok_or( FromSqlError::OutOfRange( secs ) )
+		}
+	}
+}
+
+
+// `NormTimeDelta` is stored as `BigInt` holding the duration in seconds.
+#[cfg( feature = "sea-orm" )]
+mod normtime_sea_orm {
+	use super::NormTimeDelta;
+
+	use sea_orm::sea_query::{ArrayType, ColumnType, Nullable, Value, ValueType, ValueTypeErr};
+	use sea_orm::{ColIdx, QueryResult, TryGetError, TryGetable};
+
+	impl From<NormTimeDelta> for Value {
+		fn from( item: NormTimeDelta ) -> Self {
+			Value::BigInt( Some( item.secs ) )
+		}
+	}
+
+	impl TryGetable for NormTimeDelta {
+		fn try_get_by<I: ColIdx>( res: &QueryResult, index: I ) -> Result<Self, TryGetError> {
+			let secs = i64::try_get_by( res, index )?;
+
+			NormTimeDelta::new( secs, 0 )
+				.ok_or_else( || TryGetError::DbErr( sea_orm::DbErr::Type( format!( "NormTimeDelta out of range: {} seconds", secs ) ) ) )
+		}
+	}
+
+	impl ValueType for NormTimeDelta {
+		fn try_from( v: Value ) -> Result<Self, ValueTypeErr> {
+			let secs = <i64 as ValueType>::try_from( v )?;
+
+			NormTimeDelta::new( secs, 0 ).ok_or( ValueTypeErr )
+		}
+
+		fn type_name() -> String {
+			"NormTimeDelta".to_string()
+		}
+
+		fn array_type() -> ArrayType {
+			ArrayType::BigInt
+		}
+
+		fn column_type() -> ColumnType {
+			ColumnType::BigInteger
+		}
+	}
+
+	impl Nullable for NormTimeDelta {
+		fn null() -> Value {
+			Value::BigInt( None )
+		}
+	}
+}
+
+
+// `NormTimeDelta` converts to and from `bson::Bson` as an `Int64` holding milliseconds.
+#[cfg( feature = "bson" )]
+mod normtime_bson {
+	use super::{NormTimeDelta, ConversionError, NANOS_PER_MILLI, MILLIS_PER_SEC};
+
+	impl From<NormTimeDelta> for bson::Bson {
+		fn from( item: NormTimeDelta ) -> Self {
+			// The range of `NormTimeDelta` is restricted to `i64` milliseconds, so this cannot overflow.
+			bson::Bson::Int64( item.secs * MILLIS_PER_SEC + ( item.nanos / NANOS_PER_MILLI ) as i64 )
+		}
+	}
+
+	impl TryFrom<bson::Bson> for NormTimeDelta {
+		type Error = ConversionError;
+
+		fn try_from( item: bson::Bson ) -> Result<Self, Self::Error> {
+			let millis = match item {
+				bson::Bson::Int32( x ) => x as i64,
+				bson::Bson::Int64( x ) => x,
+				_ => return Err( ConversionError::InvalidValue( item.to_string() ) ),
+			};
+
+			NormTimeDelta::new(
+				millis.div_euclid( MILLIS_PER_SEC ),
+				millis.rem_euclid( MILLIS_PER_SEC ) as u32 * NANOS_PER_MILLI as u32
+			).ok_or( ConversionError::InvalidValue( millis.to_string() ) )
+		}
+	}
+}
+
+
+// `INT8` stores the duration in seconds. `INTERVAL` stores the duration in microseconds. When reading an `INTERVAL`, a day is considered to be 86400 seconds and a month 30 of these days, as PostgreSQL itself does.
+#[cfg( feature = "postgres" )]
+mod normtime_postgres {
+	use super::{NormTimeDelta, NANOS_PER_SEC};
+
+	use std::error::Error;
+
+	use bytes::{Buf, BufMut, BytesMut};
+	use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+	/// The number of microseconds per second.
+	const MICROS_PER_SEC: i64 = 1_000_000;
+
+	/// The number of microseconds per standard day.
+	const MICROS_PER_DAY: i64 = 86_400 * MICROS_PER_SEC;
+
+	impl ToSql for NormTimeDelta {
+		fn to_sql( &self, ty: &Type, out: &mut BytesMut ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+			if *ty != Type::INTERVAL {
+				return self.secs.to_sql( ty, out );
+			}
+
+			let micros = self.secs.checked_mul( MICROS_PER_SEC )
+				.and_then( |x| x.checked_add( self.nanos as i64 / 1000 ) )
+				.ok_or( "NormTimeDelta out of range for INTERVAL" )?;
+
+			out.put_i64( micros );
+			out.put_i32( 0 );
+			out.put_i32( 0 );
+
+			Ok( IsNull::No )
+		}
+
+		accepts!( INT8, INTERVAL );
+
+		to_sql_checked!();
+	}
+
+	impl<'a> FromSql<'a> for NormTimeDelta {
+		fn from_sql( ty: &Type, mut raw: &'a [u8] ) -> Result<Self, Box<dyn Error + Sync + Send>> {
+			if *ty != Type::INTERVAL {
+				let secs = i64::from_sql( ty, raw )?;
+				return NormTimeDelta::new( secs, 0 ).ok_or( "NormTimeDelta out of range".into() );
+			}
+
+			if raw.len() != 16 {
+				return Err( "invalid INTERVAL message length".into() );
+			}
+
+			let micros = raw.get_i64() as i128
+				+ raw.get_i32() as i128 * MICROS_PER_DAY as i128
+				+ raw.get_i32() as i128 * 30 * MICROS_PER_DAY as i128;
+
+			let secs = i64::try_from( micros.div_euclid( MICROS_PER_SEC as i128 ) )?;
+			let nanos = micros.rem_euclid( MICROS_PER_SEC as i128 ) as u32 * ( NANOS_PER_SEC as u32 / MICROS_PER_SEC as u32 );
+
+			NormTimeDelta::new( secs, nanos ).ok_or( "NormTimeDelta out of range".into() )
+		}
+
+		accepts!( INT8, INTERVAL );
+	}
+}
+
+
+// Generated `NormTimeDelta`s respect the range of `-i64::MAX` to `i64::MAX` milliseconds and have nanosecond resolution.
+#[cfg( feature = "arbitrary" )]
+mod normtime_arbitrary {
+	use super::{NormTimeDelta, Unit};
+
+	use arbitrary::{Arbitrary, Unstructured};
+
+	impl<'a> Arbitrary<'a> for NormTimeDelta {
+		fn arbitrary( u: &mut Unstructured<'a> ) -> arbitrary::Result<Self> {
+			let nanos = u.int_in_range( NormTimeDelta::MIN.total_nanos()..=NormTimeDelta::MAX.total_nanos() )?;
+
+			Ok( NormTimeDelta::from_total_nanos( nanos ) )
+		}
+
+		fn size_hint( depth: usize ) -> ( usize, Option<usize> ) {
+			i128::size_hint( depth )
+		}
+	}
+
+	impl<'a> Arbitrary<'a> for Unit {
+		fn arbitrary( u: &mut Unstructured<'a> ) -> arbitrary::Result<Self> {
+			let units = [ Unit::Year, Unit::Month, Unit::Week, Unit::Day, Unit::Hour, Unit::Minute, Unit::Second ];
+
+			Ok( *u.choose( &units )? )
+		}
+
+		fn size_hint( depth: usize ) -> ( usize, Option<usize> ) {
+			u32::size_hint( depth )
+		}
+	}
+}
+
+
+// Generated `NormTimeDelta`s respect the range of `-i64::MAX` to `i64::MAX` milliseconds. Shrinking approaches `NormTimeDelta::ZERO`.
+#[cfg( feature = "quickcheck" )]
+mod normtime_quickcheck {
+	use super::{NormTimeDelta, NANOS_PER_SEC};
+
+	use quickcheck::{Arbitrary, Gen};
+
+	impl Arbitrary for NormTimeDelta {
+		fn arbitrary( g: &mut Gen ) -> Self {
+			let secs = i64::arbitrary( g ).clamp( NormTimeDelta::MIN.secs, NormTimeDelta::MAX.secs );
+			let nanos = u32::arbitrary( g ) % NANOS_PER_SEC as u32;
+
+			match NormTimeDelta::new( secs, nanos ) {
+				Some( x ) => x,
+				None if secs < 0 => NormTimeDelta::MIN,
+				None => NormTimeDelta::MAX,
+			}
+		}
+
+		fn shrink( &self ) -> Box<dyn Iterator<Item = Self>> {
+			Box::new( ( self.secs, self.nanos as u32 ).shrink().filter_map( |( secs, nanos )| NormTimeDelta::new( secs, nanos ) ) )
+		}
+	}
+}
+
+
+// `NormTimeDelta` is passed to foreign languages as seconds, matching the `serde` representation.
+#[cfg( feature = "uniffi" )]
+mod normtime_uniffi {
+	use super::NormTimeDelta;
+
+	uniffi::custom_type!( NormTimeDelta, i64 );
+
+	impl crate::UniffiCustomTypeConverter for NormTimeDelta {
+		type Builtin = i64;
+
+		fn into_custom( val: Self::Builtin ) -> uniffi::Result<Self> {
+			NormTimeDelta::new( val, 0 )
+				.ok_or_else( || uniffi::deps::anyhow::anyhow!( "NormTimeDelta out of range: {} seconds", val ) )
+		}
+
+		fn from_custom( obj: Self ) -> Self::Builtin {
+			obj.secs
+		}
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	use crate::Notation;
+
+	#[cfg( feature = "serde" )]
+	use serde_test::{Token, assert_tokens};
+
+	#[test]
+	fn test_last_digit() {
+		assert_eq!( last_digit( 5 ), 5 );
+		assert_eq!( last_digit( 11 ), 1 );
+		assert_eq!( last_digit( 23 ), 3 );
+		assert_eq!( last_digit( 123 ), 3 );
+		assert_eq!( last_digit( 1234 ), 4 );
+		assert_eq!( last_digit( 12345 ), 5 );
 	}
 
 	#[test]
@@ -1372,29 +2493,501 @@ mod tests {
 	}
 
 	#[test]
+	fn try_sum() {
+		let items = [
+			NormTimeDelta::new_seconds( 10 ),
+			NormTimeDelta::new_seconds( 11 ),
+			NormTimeDelta::new_seconds( 12 ),
+		];
+
+		assert_eq!( NormTimeDelta::try_sum( items.iter() ), Some( NormTimeDelta::new_seconds( 33 ) ) );
+		assert_eq!( NormTimeDelta::try_sum( [ NormTimeDelta::ZERO; 0 ] ), Some( NormTimeDelta::ZERO ) );
+		assert_eq!( NormTimeDelta::try_sum( [ NormTimeDelta::MIN, NormTimeDelta::MAX ] ), Some( NormTimeDelta::ZERO ) );
+		assert!( NormTimeDelta::try_sum( [ NormTimeDelta::new_seconds( -1 ), NormTimeDelta::MIN ].iter() ).is_none() );
+	}
+
+	#[test]
+	#[cfg( feature = "chrono" )]
 	fn tesxt_from_chrono_timedelta() {
 		assert_eq!( NormTimeDelta::from( TimeDelta::seconds( 10 ) ), NormTimeDelta::new_seconds( 10 ) );
 		assert_eq!( NormTimeDelta::from( TimeDelta::hours( 10 ) ), NormTimeDelta::new_hours( 10 ) );
 		assert_eq!( NormTimeDelta::from( TimeDelta::new( 10, 1111 ).unwrap() ), NormTimeDelta::new( 10, 1111 ).unwrap() );
 	}
 
+	#[test]
+	#[cfg( feature = "chrono" )]
+	fn sign_normtimedelta() {
+		let delta = NormTimeDelta::from( TimeDelta::milliseconds( -500 ) );
+		assert!( delta.is_negative() );
+		assert!( !delta.is_positive() );
+		assert_eq!( delta.signum(), -1 );
+		assert_eq!( delta.abs().signum(), 1 );
+		assert_eq!( NormTimeDelta::MIN.signum(), -1 );
+		assert_eq!( NormTimeDelta::MAX.signum(), 1 );
+	}
+
+	#[test]
+	fn div_mod_unit() {
+		let delta = NormTimeDelta::new( -2, 500_000_000 ).unwrap();
+		assert_eq!( delta.div_mod_unit( Unit::Second ), ( -2, NormTimeDelta::new( 0, 500_000_000 ).unwrap() ) );
+		assert_eq!( delta.div_mod_unit( Unit::Minute ), ( -1, NormTimeDelta::new( 58, 500_000_000 ).unwrap() ) );
+
+		for unit in Unit::ALL {
+			let ( quotient, remainder ) = NormTimeDelta::MIN.div_mod_unit( unit );
+			assert!( remainder.total_nanos() >= 0 && remainder.total_nanos() < unit.seconds() as i128 * 1_000_000_000 );
+			assert_eq!( quotient as i128 * unit.seconds() as i128 * 1_000_000_000 + remainder.total_nanos(), NormTimeDelta::MIN.total_nanos() );
+		}
+	}
+
+	#[test]
+	fn split_normtimedelta() {
+		assert_eq!( NormTimeDelta::ZERO.chunks( NormTimeDelta::new_seconds( 1 ) ).count(), 0 );
+		assert_eq!( NormTimeDelta::new_seconds( -10 ).chunks( NormTimeDelta::new_seconds( 1 ) ).count(), 0 );
+		assert_eq!( NormTimeDelta::MAX.chunks( NormTimeDelta::MAX ).collect::<Vec<_>>(), [ NormTimeDelta::MAX ] );
+		assert_eq!( NormTimeDelta::MAX.chunks( NormTimeDelta::new_years( 1_000_000 ) ).sum::<NormTimeDelta>(), NormTimeDelta::MAX );
+
+		assert_eq!( NormTimeDelta::MIN.split_n( 7 ).sum::<NormTimeDelta>(), NormTimeDelta::MIN );
+		assert_eq!( NormTimeDelta::new_seconds( -3 ).split_n( 2 ).collect::<Vec<_>>(), [ NormTimeDelta::new( -2, 500_000_000 ).unwrap(); 2 ] );
+	}
+
+	#[test]
+	fn notation() {
+		let counts = [ 999, 1000, -1000, 999_499, 999_500, 32_249_999, i64::MAX ];
+
+		let sci: Vec<String> = counts.iter().map( |x| Notation::Scientific.format_count( i128::from( *x ) ) ).collect();
+		assert_eq!( sci, [ "999", "1e3", "-1e3", "9.99e5", "1e6", "3.22e7", "9.22e18" ] );
+
+		let si: Vec<String> = counts.iter().map( |x| Notation::SiPrefix.format_count( i128::from( *x ) ) ).collect();
+		assert_eq!( si, [ "999", "1k", "-1k", "999k", "1M", "32.2M", "9.22E" ] );
+
+		assert_eq!( Notation::SiPrefix.format_count( i128::MAX ), "170000000Q" );
+	}
+
+	#[test]
+	fn runtime_units() {
+		for unit in Unit::ALL {
+			assert_eq!( NormTimeDelta::from_unit( 7, unit ), NormTimeDelta::new_seconds( 7 * unit.seconds() ) );
+			assert_eq!( NormTimeDelta::from_unit( -7, unit ).to_unit( unit ), -7.0 );
+		}
+		assert_eq!( NormTimeDelta::new_hours( 1 ).to_unit( Unit::Minute ), 60.0 );
+		assert_eq!( NormTimeDelta::new( 0, 250_000_000 ).unwrap().to_unit( Unit::Second ), 0.25 );
+	}
+
+	#[test]
+	#[should_panic]
+	fn from_unit_overflow() {
+		NormTimeDelta::from_unit( i64::MAX, Unit::Minute );
+	}
+
+	#[test]
+	fn be_bytes() {
+		let mut deltas = [ NormTimeDelta::MAX, NormTimeDelta::ZERO, NormTimeDelta::new( -1, 999_999_999 ).unwrap(), NormTimeDelta::MIN, NormTimeDelta::new( 0, 1 ).unwrap() ];
+		let mut bytes = deltas.map( |x| x.to_be_bytes() );
+		deltas.sort();
+		bytes.sort();
+		assert_eq!( bytes.map( |x| NormTimeDelta::from_be_bytes( x ).unwrap() ), deltas );
+
+		assert_eq!( NormTimeDelta::from_be_bytes( [ 0x80, 0, 0, 0, 0, 0, 0, 0, 0xff, 0, 0, 0 ] ), None );
+		assert_eq!( NormTimeDelta::from_be_bytes( [ 0xff; 12 ] ), None );
+	}
+
+	#[test]
+	#[allow( clippy::op_ref )]
+	fn ops_references() {
+		fn total<'a, T>( items: &'a [T] ) -> T
+		where
+			T: Copy + Default,
+			&'a T: Add<&'a T, Output = T>,
+			T: Add<&'a T, Output = T>,
+		{
+			items.iter().fold( T::default(), |acc, x| acc + x )
+		}
+
+		let deltas = [ NormTimeDelta::new_seconds( 3 ), NormTimeDelta::new_hours( 1 ) ];
+		assert_eq!( total( &deltas ), NormTimeDelta::new_seconds( 3_603 ) );
+		assert_eq!( &deltas[1] - &deltas[0], NormTimeDelta::new_seconds( 3_597 ) );
+		assert_eq!( &deltas[0] * &2, NormTimeDelta::new_seconds( 6 ) );
+		assert_eq!( &deltas[0] / 3, NormTimeDelta::new_seconds( 1 ) );
+
+		let time = crate::NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+		assert_eq!( &( &time + &deltas[0] ) - &time, deltas[0] );
+	}
+
+	#[test]
+	fn default_units() {
+		// The global `DEFAULT_UNITS` is shared by all tests, so only the conversion is tested here.
+		assert_eq!( units_from_bits( 1 << Unit::Second as u8 ), [ Unit::Second ] );
+
+		let units = units_from_bits( units_to_bits( &[ Unit::Hour, Unit::Year, Unit::Hour ] ) );
+		assert_eq!( units, [ Unit::Year, Unit::Hour ] );
+		assert_eq!( NormTimeDelta::new_years( 2 ).to_string_unit( &units ), "2 normyears" );
+
+		assert_eq!( units_from_bits( units_to_bits( &[] ) ), [ Unit::Second ] );
+		assert_eq!( units_from_bits( units_to_bits( &Unit::ALL ) ), Unit::ALL );
+	}
+
+	#[test]
+	fn text_typographic() {
+		let delta = NormTimeDelta::new_seconds( -90_005_000 );
+		assert_eq!( delta.to_string_unit( &[ Unit::Day, Unit::Hour ] ), "-900 normdays -1 hour" );
+		assert_eq!( delta.to_string_unit_with( &[ Unit::Day, Unit::Hour ], &TextOptions::typographic() ), "−900\u{202F}normdays −1\u{202F}hour" );
+
+		let options = TextOptions {
+			notation: Notation::SiPrefix,
+			..TextOptions::typographic()
+		};
+		assert_eq!( NormTimeDelta::new_days( -3_200 ).to_string_sym_unit_with( &[ Unit::Day ], &options ), "−3.2k\u{202F}d" );
+	}
+
+	#[test]
+	#[cfg( feature = "tex" )]
+	fn latex_negative() {
+		let delta = NormTimeDelta::new_seconds( -90_005_000 );
+		assert_eq!( delta.to_latex_unit( &[ Unit::Day, Unit::Hour ] ), "−900~normdays −1~hour" );
+		assert_eq!( delta.to_latex_sym_unit( &[ Unit::Day ] ), r"\qty{-900}{\normday}" );
+		assert_eq!(
+			delta.to_latex_unit_with( &[ Unit::Day ], &TexOptions::new().minus( crate::TexMinus::Num ) ),
+			r"\num{-900}~normdays"
+		);
+		assert_eq!( NormTimeDelta::new_seconds( 5 ).to_latex( &TexOptions::new().minus( crate::TexMinus::Num ) ), "5~seconds" );
+	}
+
+	#[test]
+	fn negative_units() {
+		let delta = NormTimeDelta::new_seconds( -90_005_000 );
+
+		// Up to version 0.4.2, negative components were omitted and these returned an empty string.
+		assert_eq!( delta.to_string_unit( &[ Unit::Day, Unit::Hour ] ), "-900 normdays -1 hour" );
+		assert_eq!( delta.to_string_sym_unit( &[ Unit::Day, Unit::Hour ] ), "-900 d -1 h" );
+		assert_eq!( NormTimeDelta::new_seconds( -1 ).to_string_unit( &[ Unit::Hour, Unit::Second ] ), "-1 second" );
+
+		// Components that are zero are still omitted.
+		assert_eq!( NormTimeDelta::new_days( -1 ).to_string_unit( &[ Unit::Day, Unit::Hour ] ), "-1 normday" );
+		assert_eq!( NormTimeDelta::new_seconds( 90_005_000 ).to_string_unit( &[ Unit::Day, Unit::Hour ] ), "900 normdays 1 hour" );
+	}
+
+	#[test]
+	#[cfg( feature = "i18n" )]
+	fn negative_units_locale() {
+		use unic_langid::langid;
+
+		let delta = NormTimeDelta::new_seconds( -90_005_000 );
+
+		// Up to version 0.4.2, negative components were omitted and these returned an empty string.
+		assert_eq!( delta.to_string_unit_locale( &[ Unit::Day, Unit::Hour ], &langid!( "de-DE" ) ), "-900 Normtage -1 Stunde" );
+		assert_eq!( delta.to_string_unit_locale( &[ Unit::Day, Unit::Hour ], &langid!( "en-US" ) ), "-900 normdays -1 hour" );
+	}
+
+	#[test]
+	fn best_unit() {
+		assert_eq!( NormTimeDelta::MIN.best_unit(), Unit::Year );
+		assert_eq!( NormTimeDelta::new( -1, 1 ).unwrap().best_unit(), Unit::Second );
+		assert_eq!( NormTimeDelta::new( -1, 1 ).unwrap().to_string_auto(), "0 seconds" );
+		assert_eq!( NormTimeDelta::new_seconds( -1 ).to_string_auto(), "-1 second" );
+	}
+
+	#[test]
+	fn top_units() {
+		let delta = NormTimeDelta::new_years( 2 ) + NormTimeDelta::new_days( 1 );
+		assert_eq!( delta.to_string_top_units( 2 ), "2 normyears 1 normday" );
+		assert_eq!( delta.to_string_top_units( 0 ), "" );
+		assert_eq!( NormTimeDelta::ZERO.to_string_top_units( 2 ), "" );
+
+		assert_eq!( NormTimeDelta::new_seconds( -2_500_000 ).to_string_top_units( 2 ), "-2 normweeks -5 normdays" );
+		assert_eq!( ( NormTimeDelta::ZERO - delta ).to_string_top_units( 2 ), "-2 normyears -1 normday" );
+	}
+
+	#[test]
+	#[should_panic]
+	fn chunks_zero() {
+		let _ = NormTimeDelta::new_seconds( 1 ).chunks( NormTimeDelta::ZERO );
+	}
+
+	#[test]
+	fn abs_diff_normtimedelta() {
+		assert_eq!( NormTimeDelta::MIN.abs_diff( NormTimeDelta::MAX ), NormTimeDelta::MAX );
+		assert_eq!( NormTimeDelta::MIN.abs_diff( NormTimeDelta::ZERO ), NormTimeDelta::MAX );
+		assert_eq!(
+			NormTimeDelta::new( -2, 500_000_000 ).unwrap().abs_diff( NormTimeDelta::new_seconds( 1 ) ),
+			NormTimeDelta::new( 2, 500_000_000 ).unwrap()
+		);
+	}
+
+	#[test]
+	fn clamp_normtimedelta() {
+		let delta = NormTimeDelta::new( -2, 500_000_000 ).unwrap();
+		assert_eq!( delta.clamp( NormTimeDelta::MIN, NormTimeDelta::MAX ), delta );
+		assert_eq!( delta.clamp( NormTimeDelta::new_seconds( -1 ), NormTimeDelta::ZERO ), NormTimeDelta::new_seconds( -1 ) );
+		assert_eq!( delta.clamp_to_range( .. ), delta );
+		assert_eq!( NormTimeDelta::MIN.clamp_to_range( ( Bound::Excluded( delta ), Bound::Unbounded ) ), NormTimeDelta::new( -2, 500_000_001 ).unwrap() );
+	}
+
+	#[test]
+	#[should_panic]
+	fn clamp_normtimedelta_empty() {
+		NormTimeDelta::ZERO.clamp_to_range( NormTimeDelta::ZERO..NormTimeDelta::ZERO );
+	}
+
+	#[test]
+	fn clock() {
+		let delta = NormTimeDelta::new( -1, 500_000_000 ).unwrap();
+		assert_eq!( delta.to_string_clock(), "00:00:00" );
+		assert_eq!( delta.to_string_clock_days(), "0d 00:00:00" );
+
+		let delta = NormTimeDelta::new( -2, 500_000_000 ).unwrap();
+		assert_eq!( delta.to_string_clock(), "-00:00:01" );
+		assert_eq!( delta.to_string_clock_days(), "-0d 00:00:01" );
+	}
+
+	#[test]
+	fn debug_alternate() {
+		let units = |x: NormTimeDelta| {
+			let res = format!( "{:#?}", x );
+			res.lines().find( |y| y.trim_start().starts_with( "units" ) ).unwrap().trim().to_string()
+		};
+
+		assert_eq!( units( NormTimeDelta::new_days( -1 ) + NormTimeDelta::new_hours( -2 ) ), "units: \"-1 normday -2 hours\"," );
+		assert_eq!( units( NormTimeDelta::ZERO ), "units: \"0 seconds\"," );
+		assert_eq!( units( NormTimeDelta::new( -1, 500_000_000 ).unwrap() ), "units: \"0 seconds\"," );
+	}
+
 	#[test]
 	fn time_delta_display() {
 		assert_eq!( NormTimeDelta::new_seconds( 1 ).to_string(), "1 second" );
 		assert_eq!( NormTimeDelta::new_seconds( 10 ).to_string(), "10 seconds" );
 	}
 
+	#[test]
+	#[cfg( feature = "diesel-sqlite" )]
+	fn test_diesel() {
+		use diesel::{Connection, IntoSql, RunQueryDsl, SqliteConnection};
+		use diesel::sql_types::BigInt;
+
+		let mut conn = SqliteConnection::establish( ":memory:" ).unwrap();
+		let mut roundtrip = |x: NormTimeDelta| diesel::select( x.into_sql::<BigInt>() ).get_result::<NormTimeDelta>( &mut conn ).unwrap();
+
+		for delta in [ NormTimeDelta::ZERO, NormTimeDelta::new_days( -3 ), NormTimeDelta::new_seconds( i64::MAX / 1_000 ) ] {
+			assert_eq!( roundtrip( delta ), delta );
+		}
+
+		// Subseconds are dropped.
+		assert_eq!( roundtrip( NormTimeDelta::new( 2, 500_000_000 ).unwrap() ), NormTimeDelta::new_seconds( 2 ) );
+		assert_eq!( roundtrip( NormTimeDelta::new( -2, 500_000_000 ).unwrap() ), NormTimeDelta::new_seconds( -2 ) );
+
+		let res = diesel::select( i64::MIN.into_sql::<BigInt>() ).get_result::<NormTimeDelta>( &mut conn );
+		assert!( res.is_err() );
+	}
+
+	#[test]
+	#[cfg( feature = "rusqlite" )]
+	fn test_rusqlite() {
+		let conn = rusqlite::Connection::open_in_memory().unwrap();
+		conn.execute( "CREATE TABLE log (num INTEGER, txt TEXT)", () ).unwrap();
+
+		let delta = NormTimeDelta::new_days( -3 );
+		conn.execute( "INSERT INTO log (num, txt) VALUES (?1, ?1)", [ delta ] ).unwrap();
+
+		let row: (NormTimeDelta, NormTimeDelta) = conn
+			.query_row( "SELECT num, txt FROM log", (), |row| Ok( ( row.get( 0 )?, row.get( 1 )? ) ) )
+			.unwrap();
+
+		assert_eq!( row, ( delta, delta ) );
+	}
+
+	#[test]
+	#[cfg( feature = "sea-orm" )]
+	fn test_sea_orm_value() {
+		use sea_orm::sea_query::{Value, ValueType};
+
+		let item = NormTimeDelta::new_days( -3 );
+		assert_eq!( Value::from( item ), Value::BigInt( Some( -300_000 ) ) );
+		assert_eq!( <NormTimeDelta as ValueType>::try_from( Value::from( item ) ).unwrap(), item );
+		assert!( <NormTimeDelta as ValueType>::try_from( Value::String( None ) ).is_err() );
+	}
+
+	#[test]
+	#[cfg( feature = "bson" )]
+	fn test_bson() {
+		let delta = NormTimeDelta::new( -2, 500_000_000 ).unwrap();
+
+		assert_eq!( bson::Bson::from( delta ), bson::Bson::Int64( -1500 ) );
+		assert_eq!( NormTimeDelta::try_from( bson::Bson::Int64( -1500 ) ).unwrap(), delta );
+		assert_eq!( NormTimeDelta::try_from( bson::Bson::Int32( 3000 ) ).unwrap(), NormTimeDelta::new_seconds( 3 ) );
+		assert!( NormTimeDelta::try_from( bson::Bson::Int64( i64::MIN ) ).is_err() );
+		assert!( NormTimeDelta::try_from( bson::Bson::Double( 1.0 ) ).is_err() );
+	}
+
+	#[test]
+	#[cfg( feature = "postgres" )]
+	fn test_postgres() {
+		use postgres_types::{FromSql, ToSql, Type};
+
+		let delta = NormTimeDelta::new( -2, 500_000_000 ).unwrap();
+
+		let mut buf = bytes::BytesMut::new();
+		delta.to_sql_checked( &Type::INTERVAL, &mut buf ).unwrap();
+		assert_eq!( &buf[..], &[ 0xff, 0xff, 0xff, 0xff, 0xff, 0xe9, 0x1c, 0xa0, 0, 0, 0, 0, 0, 0, 0, 0 ] );
+		assert_eq!( NormTimeDelta::from_sql( &Type::INTERVAL, &buf ).unwrap(), delta );
+
+		// 1 month, 1 day and 1 second.
+		let raw = [ 0, 0, 0, 0, 0, 0x0f, 0x42, 0x40, 0, 0, 0, 1, 0, 0, 0, 1 ];
+		assert_eq!( NormTimeDelta::from_sql( &Type::INTERVAL, &raw ).unwrap(), NormTimeDelta::new_seconds( 31 * 86_400 + 1 ) );
+
+		let mut buf = bytes::BytesMut::new();
+		NormTimeDelta::new_days( 3 ).to_sql_checked( &Type::INT8, &mut buf ).unwrap();
+		assert_eq!( NormTimeDelta::from_sql( &Type::INT8, &buf ).unwrap(), NormTimeDelta::new_days( 3 ) );
+	}
+
+	#[test]
+	#[cfg( feature = "rkyv" )]
+	fn test_rkyv() {
+		let items = ( NormTimeDelta::new( -2, 500_000_000 ).unwrap(), Unit::Week );
+
+		let bytes = rkyv::to_bytes::<rkyv::rancor::Error>( &items ).unwrap();
+		let archived = rkyv::access::<rkyv::Archived<(NormTimeDelta, Unit)>, rkyv::rancor::Error>( &bytes ).unwrap();
+		assert_eq!( archived.0, items.0 );
+		assert_eq!( archived.1, items.1 );
+		assert_eq!( rkyv::deserialize::<(NormTimeDelta, Unit), rkyv::rancor::Error>( archived ).unwrap(), items );
+	}
+
+	#[test]
+	fn parse_normtimedelta() {
+		for secs in [ -DUR_NORMYEAR, -1, 0, 1, 59, DUR_NORMDAY + 1 ] {
+			let delta = NormTimeDelta::new_seconds( secs );
+			assert_eq!( delta.to_string().parse::<NormTimeDelta>().unwrap(), delta );
+			assert_eq!( delta.to_string_sym().parse::<NormTimeDelta>().unwrap(), delta );
+		}
+
+		assert_eq!( "3 normweeks 1 min".parse::<NormTimeDelta>().unwrap(), NormTimeDelta::new_days( 30 ) + NormTimeDelta::new_minutes( 1 ) );
+		assert!( "1 lightyear".parse::<NormTimeDelta>().is_err() );
+		assert!( "1 s 2".parse::<NormTimeDelta>().is_err() );
+		assert!( "".parse::<NormTimeDelta>().is_err() );
+		assert!( format!( "{} y", i64::MAX ).parse::<NormTimeDelta>().is_err() );
+		assert!( format!( "{} s", i64::MAX ).parse::<NormTimeDelta>().is_err() );
+	}
+
+	#[test]
+	fn test_parse_iso_duration() {
+		assert_eq!( parse_iso_duration( "P1Y2M3W4DT5H6M7S" ), Some( DUR_NORMYEAR + 2 * DUR_NORMMONTH + 3 * DUR_NORMWEEK + 4 * DUR_NORMDAY + 5 * DUR_HOUR + 6 * DUR_MINUTE + 7 ) );
+		assert_eq!( parse_iso_duration( "PT1M" ), Some( 60 ) );
+		assert_eq!( parse_iso_duration( "P1M" ), Some( DUR_NORMMONTH ) );
+		assert_eq!( parse_iso_duration( "-PT1S" ), Some( -1 ) );
+
+		assert_eq!( parse_iso_duration( "P" ), None );
+		assert_eq!( parse_iso_duration( "PT" ), None );
+		assert_eq!( parse_iso_duration( "P1DT" ), None );
+		assert_eq!( parse_iso_duration( "P1" ), None );
+		assert_eq!( parse_iso_duration( "PD" ), None );
+		assert_eq!( parse_iso_duration( "P1D1Y" ), None );
+		assert_eq!( parse_iso_duration( "P1H" ), None );
+		assert_eq!( parse_iso_duration( "P99999999999999Y" ), None );
+	}
+
+	#[test]
+	fn test_hash() {
+		use std::collections::HashSet;
+
+		let deltas: HashSet<NormTimeDelta> = [
+			NormTimeDelta::new_seconds( 60 ),
+			NormTimeDelta::new_minutes( 1 ),
+			NormTimeDelta::new( -2, 500_000_000 ).unwrap(),
+		].into_iter().collect();
+
+		assert_eq!( deltas.len(), 2 );
+	}
+
+	#[test]
+	#[cfg( feature = "chrono" )]
+	fn compare_timedelta() {
+		let delta = NormTimeDelta::new( -2, 500_000_000 ).unwrap();
+		assert_eq!( delta, TimeDelta::milliseconds( -1500 ) );
+		assert_ne!( delta, TimeDelta::milliseconds( -1499 ) );
+		assert!( delta < TimeDelta::milliseconds( -1499 ) );
+		assert!( TimeDelta::milliseconds( -1501 ) < delta );
+		assert!( TimeDelta::milliseconds( -1500 ) <= delta );
+	}
+
+	#[test]
+	#[cfg( feature = "chrono" )]
+	fn test_hash_timedelta() {
+		use std::collections::hash_map::DefaultHasher;
+		use std::hash::{Hash, Hasher};
+
+		fn hash( delta: NormTimeDelta ) -> u64 {
+			let mut hasher = DefaultHasher::new();
+			delta.hash( &mut hasher );
+			hasher.finish()
+		}
+
+		let delta = NormTimeDelta::from( TimeDelta::milliseconds( -1500 ) );
+		assert_eq!( delta, NormTimeDelta::new( -2, 500_000_000 ).unwrap() );
+		assert_eq!( hash( delta ), hash( NormTimeDelta::new( -2, 500_000_000 ).unwrap() ) );
+		assert_eq!( delta.subsec_nanos(), -500_000_000 );
+	}
+
+	#[test]
+	#[cfg( feature = "arbitrary" )]
+	fn test_arbitrary() {
+		use arbitrary::{Arbitrary, Unstructured};
+
+		let mut u = Unstructured::new( &[ 0xff; 32 ] );
+		let delta = NormTimeDelta::arbitrary( &mut u ).unwrap();
+		assert!( NormTimeDelta::new( delta.secs, delta.nanos as u32 ).is_some() );
+		assert!( Unit::arbitrary( &mut u ).is_ok() );
+
+		let mut u = Unstructured::new( &[] );
+		assert_eq!( NormTimeDelta::arbitrary( &mut u ).unwrap(), NormTimeDelta::MIN );
+		assert_eq!( Unit::arbitrary( &mut u ).unwrap(), Unit::Year );
+	}
+
+	#[test]
+	#[cfg( feature = "quickcheck" )]
+	fn test_quickcheck() {
+		use quickcheck::{Arbitrary, quickcheck};
+
+		fn abs_not_negative( delta: NormTimeDelta ) -> bool {
+			delta.abs() >= NormTimeDelta::ZERO
+		}
+
+		quickcheck( abs_not_negative as fn( NormTimeDelta ) -> bool );
+
+		assert!( NormTimeDelta::new_seconds( 100 ).shrink().all( |x| x.abs() < NormTimeDelta::new_seconds( 100 ) ) );
+	}
+
 	#[test]
 	#[cfg( feature = "serde" )]
 	fn test_serialize_deserilaize() {
+		// `serde_test` requires types with a distinct human readable representation to be marked as compact or readable.
+		use serde_test::Configure;
+
 		assert_tokens(
-			&NormTimeDelta::new_seconds( 10 ),
+			&NormTimeDelta::new_seconds( 10 ).compact(),
 			&[ Token::I64( 10 ), ]
 		);
 
 		assert_tokens(
-			&NormTimeDelta::new_years( 10 ),
+			&NormTimeDelta::new_years( 10 ).compact(),
 			&[ Token::I64( 10 * DUR_NORMYEAR ), ]
 		);
 	}
+
+	#[test]
+	#[cfg( feature = "serde" )]
+	fn test_serialize_readable() {
+		use serde_test::Configure;
+
+		assert_tokens( &NormTimeDelta::new_seconds( 10 ).readable(), &[ Token::I64( 10 ), ] );
+		assert_tokens( &NormTimeDelta::new_years( 10 ).readable(), &[ Token::I64( 10 * DUR_NORMYEAR ), ] );
+	}
+
+	#[test]
+	#[cfg( feature = "serde" )]
+	fn test_deserialize_str() {
+		use serde_test::{Configure, assert_de_tokens};
+
+		assert_de_tokens( &NormTimeDelta::new_seconds( 90 ).readable(), &[ Token::Str( "90 seconds" ), ] );
+		assert_de_tokens( &( NormTimeDelta::new_years( 1 ) + NormTimeDelta::new_days( 3 ) ).readable(), &[ Token::Str( "1 normyear 3 normdays" ), ] );
+		assert_de_tokens( &NormTimeDelta::new_days( 3 ).readable(), &[ Token::Str( "P3D" ), ] );
+		assert_de_tokens( &NormTimeDelta::new_seconds( 3 ).readable(), &[ Token::U8( 3 ), ] );
+	}
 }
@@ -0,0 +1,172 @@
+//! Extension traits providing conversions into Normtime as methods of foreign types.
+//!
+//! The traits are opt-in: their methods are only available after importing them.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+#[cfg( feature = "chrono" )] use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeDelta, TimeZone};
+
+#[cfg( feature = "chrono" )] use crate::NormTime;
+use crate::NormTimeDelta;
+
+
+
+
+//=============================================================================
+// Numbers
+
+
+/// Creating `NormTimeDelta`s from integers like `5.normdays()`.
+///
+/// **Note:** Like the constructors of `NormTimeDelta`, these methods panic if the duration cannot be expressed in `i64` seconds.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{NormDurationExt, NormTimeDelta};
+///
+/// assert_eq!( 5.normdays(), NormTimeDelta::new_days( 5 ) );
+/// assert_eq!( 3.normyears() + 90.seconds(), NormTimeDelta::new_seconds( 90_000_090 ) );
+/// assert_eq!( ( -2i32 ).hours(), NormTimeDelta::new_hours( -2 ) );
+/// ```
+pub trait NormDurationExt {
+	/// Returns a `NormTimeDelta` of `self` seconds.
+	fn seconds( self ) -> NormTimeDelta;
+
+	/// Returns a `NormTimeDelta` of `self` minutes.
+	fn minutes( self ) -> NormTimeDelta;
+
+	/// Returns a `NormTimeDelta` of `self` hours.
+	fn hours( self ) -> NormTimeDelta;
+
+	/// Returns a `NormTimeDelta` of `self` normdays.
+	fn normdays( self ) -> NormTimeDelta;
+
+	/// Returns a `NormTimeDelta` of `self` normweeks.
+	fn normweeks( self ) -> NormTimeDelta;
+
+	/// Returns a `NormTimeDelta` of `self` normmonths.
+	fn normmonths( self ) -> NormTimeDelta;
+
+	/// Returns a `NormTimeDelta` of `self` normyears.
+	fn normyears( self ) -> NormTimeDelta;
+}
+
+macro_rules! impl_norm_duration_ext {
+	( $( $t:ty ),* ) => {
+		$(
+			impl NormDurationExt for $t {
+				fn seconds( self ) -> NormTimeDelta {
+					NormTimeDelta::new_seconds( i64::from( self ) )
+				}
+
+				fn minutes( self ) -> NormTimeDelta {
+					NormTimeDelta::new_minutes( i64::from( self ) )
+				}
+
+				fn hours( self ) -> NormTimeDelta {
+					NormTimeDelta::new_hours( i64::from( self ) )
+				}
+
+				fn normdays( self ) -> NormTimeDelta {
+					NormTimeDelta::new_days( i64::from( self ) )
+				}
+
+				fn normweeks( self ) -> NormTimeDelta {
+					NormTimeDelta::new_weeks( i64::from( self ) )
+				}
+
+				fn normmonths( self ) -> NormTimeDelta {
+					NormTimeDelta::new_months( i64::from( self ) )
+				}
+
+				fn normyears( self ) -> NormTimeDelta {
+					NormTimeDelta::new_years( i64::from( self ) )
+				}
+			}
+		)*
+	};
+}
+
+impl_norm_duration_ext!( i32, i64 );
+
+
+
+
+//=============================================================================
+// Chrono
+
+
+/// Converting instants of `chrono` into `NormTime` by calling `.to_normtime()`.
+///
+/// This trait is only available, if the **`chrono`** feature has been enabled.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{NaiveDate, TimeZone, Utc};
+/// use normtime::{NormTime, NormTimeExt};
+///
+/// let expected = NormTime::from_ymd_opt( 0, 0, 0 ).unwrap().and_hms( 0, 0, 1 );
+/// assert_eq!( NaiveDate::from_ymd_opt( 2068, 1, 1 ).unwrap().and_hms_opt( 0, 0, 1 ).unwrap().to_normtime(), expected );
+/// assert_eq!( Utc.with_ymd_and_hms( 2068, 1, 1, 0, 0, 1 ).unwrap().to_normtime(), expected );
+/// ```
+#[cfg( feature = "chrono" )]
+pub trait NormTimeExt {
+	/// Returns the `NormTime` representing the same instant as `self`.
+	fn to_normtime( &self ) -> NormTime;
+}
+
+#[cfg( feature = "chrono" )]
+impl NormTimeExt for NaiveDateTime {
+	fn to_normtime( &self ) -> NormTime {
+		NormTime::from( *self )
+	}
+}
+
+/// The time component is considered to be zero.
+#[cfg( feature = "chrono" )]
+impl NormTimeExt for NaiveDate {
+	fn to_normtime( &self ) -> NormTime {
+		NormTime::from( *self )
+	}
+}
+
+/// Time zone offsets are respected, so the `NormTime` represents the same instant in UTC.
+#[cfg( feature = "chrono" )]
+impl<Tz: TimeZone> NormTimeExt for DateTime<Tz> {
+	fn to_normtime( &self ) -> NormTime {
+		NormTime::from( self.naive_utc() )
+	}
+}
+
+
+/// Converting durations of `chrono` into `NormTimeDelta` by calling `.to_normdelta()`.
+///
+/// This trait is only available, if the **`chrono`** feature has been enabled.
+///
+/// # Example
+///
+/// ```
+/// use chrono::TimeDelta;
+/// use normtime::{NormDeltaExt, NormTimeDelta};
+///
+/// assert_eq!( TimeDelta::seconds( 100_000 ).to_normdelta(), NormTimeDelta::new_days( 1 ) );
+/// ```
+#[cfg( feature = "chrono" )]
+pub trait NormDeltaExt {
+	/// Returns the `NormTimeDelta` of the same duration as `self`.
+	fn to_normdelta( &self ) -> NormTimeDelta;
+}
+
+#[cfg( feature = "chrono" )]
+impl NormDeltaExt for TimeDelta {
+	fn to_normdelta( &self ) -> NormTimeDelta {
+		NormTimeDelta::from( *self )
+	}
+}
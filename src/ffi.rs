@@ -0,0 +1,118 @@
+//! Functions exported to foreign languages (Kotlin, Swift, Python) using UniFFI.
+//!
+//! `NormTime` is passed as the number of seconds since 0000-00-00N00:00:00 and `NormTimeDelta` as a number of seconds. Since UniFFI only exports free functions for these custom types, all operations are provided as functions taking the values as arguments. They all forward to the methods of the Rust types, so foreign code uses the exact same arithmetic and formatting.
+//!
+//! This module is only available, if the **`uniffi`** feature has been enabled.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use crate::{NormTime, NormTimeDelta, Unit};
+
+
+
+
+//=============================================================================
+// NormTime
+
+
+/// Returns the `NormTime` of `normyear`, `normmonth`, `normday`, `hour`, `min` and `sec`. See [`NormTime::from_ymd_opt`] and [`NormTime::and_hms`].
+#[uniffi::export]
+pub fn normtime_from_ymd_hms( normyear: i32, normmonth: u32, normday: u32, hour: u32, min: u32, sec: u32 ) -> Option<NormTime> {
+	Some( NormTime::from_ymd_opt( normyear, normmonth, normday )?.and_hms( hour, min, sec ) )
+}
+
+/// Returns the `NormTime` of the Unix timestamp `secs`. See [`NormTime::from_timestamp`].
+#[uniffi::export]
+pub fn normtime_from_timestamp( secs: i64 ) -> Option<NormTime> {
+	NormTime::from_timestamp( secs )
+}
+
+/// Returns the current time. See [`NormTime::now`].
+#[uniffi::export]
+pub fn normtime_now() -> NormTime {
+	NormTime::now()
+}
+
+/// Returns the Unix timestamp of `time`.
+#[uniffi::export]
+pub fn normtime_timestamp( time: NormTime ) -> i64 {
+	time.timestamp()
+}
+
+/// Parses `text` into a `NormTime`. Returns `None` if `text` is not a valid `NormTime`.
+#[uniffi::export]
+pub fn normtime_parse( text: String ) -> Option<NormTime> {
+	text.parse().ok()
+}
+
+/// Returns the string representation of `time`, e.g. `0123-04-05N06:07:08`.
+#[uniffi::export]
+pub fn normtime_to_string( time: NormTime ) -> String {
+	time.to_string()
+}
+
+/// Returns `time` shifted by `delta`.
+#[uniffi::export]
+pub fn normtime_add( time: NormTime, delta: NormTimeDelta ) -> NormTime {
+	time + delta
+}
+
+/// Returns the duration from `start` to `stop`.
+#[uniffi::export]
+pub fn normtime_diff( stop: NormTime, start: NormTime ) -> NormTimeDelta {
+	stop - start
+}
+
+
+
+
+//=============================================================================
+// NormTimeDelta
+
+
+/// Returns the string representation of `delta`, e.g. `100 seconds`.
+#[uniffi::export]
+pub fn normtimedelta_to_string( delta: NormTimeDelta ) -> String {
+	delta.to_string()
+}
+
+/// Returns the string representation of `delta` using `units`. See [`NormTimeDelta::to_string_unit`].
+#[uniffi::export]
+pub fn normtimedelta_to_string_unit( delta: NormTimeDelta, units: Vec<Unit> ) -> String {
+	delta.to_string_unit( &units )
+}
+
+/// Returns the string representation of `delta` using the symbols of `units`. See [`NormTimeDelta::to_string_sym_unit`].
+#[uniffi::export]
+pub fn normtimedelta_to_string_sym_unit( delta: NormTimeDelta, units: Vec<Unit> ) -> String {
+	delta.to_string_sym_unit( &units )
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn exported_functions() {
+		let start = normtime_from_ymd_hms( 123, 4, 5, 6, 7, 8 ).unwrap();
+		let stop = normtime_add( start, NormTimeDelta::new_days( 1 ) );
+
+		assert!( normtime_from_ymd_hms( 123, 10, 5, 6, 7, 8 ).is_none() );
+		assert_eq!( normtime_to_string( stop ), "0123-04-06N06:07:08" );
+		assert_eq!( normtime_parse( "0123-04-06N06:07:08".to_string() ), Some( stop ) );
+		assert_eq!( normtime_diff( stop, start ), NormTimeDelta::new_days( 1 ) );
+		assert_eq!( normtimedelta_to_string_unit( normtime_diff( stop, start ), vec![ Unit::Day ] ), "1 normday" );
+	}
+}
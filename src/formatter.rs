@@ -0,0 +1,265 @@
+//! Formatting many `NormTime`s the same way.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+#[cfg( feature = "i18n" )] use fluent_templates::Loader;
+#[cfg( feature = "i18n" )] use unic_langid::LanguageIdentifier;
+
+use crate::NormTime;
+use crate::names::{MonthNames, WeekdayNames};
+use crate::{DUR_NORMYEAR, DUR_NORMMONTH, DUR_NORMDAY, DUR_HOUR, DUR_MINUTE};
+#[cfg( feature = "i18n" )] use crate::LOCALES;
+
+
+
+
+//=============================================================================
+// Options
+
+
+/// How normyears before and within the norm era are told apart.
+#[derive( Clone, Copy, PartialEq, Eq, Hash, Default, Debug )]
+pub enum EraStyle {
+	/// Normyears before the norm era are prefixed by `-`, e.g. `-0005-03-12`.
+	#[default]
+	Sign,
+
+	/// Normyears are written without sign and followed by the era, e.g. `0005-03-12 BNE` and `0045-03-12 NE`.
+	Suffix,
+}
+
+
+
+
+//=============================================================================
+// Formatter
+
+
+/// A reusable set of formatting options for `NormTime`s. The formatter is built once and can then be applied to many `NormTime`s.
+///
+/// The default formatter produces the same string as `Display`.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{EraStyle, NormTime, NormTimeFormatter};
+///
+/// let formatter = NormTimeFormatter::new()
+///     .date_separator( '.' )
+///     .separator( ' ' )
+///     .era( EraStyle::Suffix );
+///
+/// let d = NormTime::from_ymd_opt( -5, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+/// assert_eq!( formatter.format( d ), "0005.03.12 08:00:00 BNE" );
+///
+/// let formatter = NormTimeFormatter::new().clock( false ).year_width( 0 ).field_width( 1 );
+/// assert_eq!( formatter.format( d ), "-5-3-12" );
+/// ```
+#[derive( Clone, PartialEq, Debug )]
+pub struct NormTimeFormatter {
+	date_separator: char,
+	clock_separator: char,
+	separator: char,
+	year_width: usize,
+	field_width: usize,
+	clock: bool,
+	era: EraStyle,
+	month_names: Option<MonthNames>,
+	weekday_names: Option<WeekdayNames>,
+	#[cfg( feature = "i18n" )]
+	locale: Option<LanguageIdentifier>,
+}
+
+impl NormTimeFormatter {
+	/// Creates a formatter producing the same string as `Display`, e.g. `0045-03-12N08:00:00`.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Sets the separator between normyear, normmonth and normday. Default is `-`.
+	pub fn date_separator( mut self, separator: char ) -> Self {
+		self.date_separator = separator;
+		self
+	}
+
+	/// Sets the separator between hours, minutes and seconds. Default is `:`.
+	pub fn clock_separator( mut self, separator: char ) -> Self {
+		self.clock_separator = separator;
+		self
+	}
+
+	/// Sets the separator between date and clock. Default is `N`.
+	pub fn separator( mut self, separator: char ) -> Self {
+		self.separator = separator;
+		self
+	}
+
+	/// Sets the minimum number of digits of the normyear. Shorter normyears are padded with zeros. Default is 4.
+	pub fn year_width( mut self, width: usize ) -> Self {
+		self.year_width = width;
+		self
+	}
+
+	/// Sets the minimum number of digits of all other fields. Shorter fields are padded with zeros. Default is 2.
+	pub fn field_width( mut self, width: usize ) -> Self {
+		self.field_width = width;
+		self
+	}
+
+	/// Sets whether the clock is included. Default is `true`.
+	pub fn clock( mut self, clock: bool ) -> Self {
+		self.clock = clock;
+		self
+	}
+
+	/// Sets how normyears before the norm era are marked. Default is [`EraStyle::Sign`].
+	pub fn era( mut self, era: EraStyle ) -> Self {
+		self.era = era;
+		self
+	}
+
+	/// Writes normmonths by their name from `names` instead of their number.
+	pub fn month_names( mut self, names: MonthNames ) -> Self {
+		self.month_names = Some( names );
+		self
+	}
+
+	/// Writes the name of the normday of the normweek from `names` in front of the date.
+	pub fn weekday_names( mut self, names: WeekdayNames ) -> Self {
+		self.weekday_names = Some( names );
+		self
+	}
+
+	/// Sets the language used for the era names of [`EraStyle::Suffix`].
+	///
+	/// This method is only available, if the **`i18n`** feature has been enabled.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use unic_langid::langid;
+	/// use normtime::{EraStyle, NormTime, NormTimeFormatter};
+	///
+	/// let formatter = NormTimeFormatter::new().era( EraStyle::Suffix ).locale( langid!( "de-DE" ) );
+	///
+	/// let d = NormTime::from_ymd_opt( -5, 3, 12 ).unwrap();
+	/// assert_eq!( formatter.format( d ), "0005-03-12N00:00:00 vNÄ" );
+	/// ```
+	#[cfg( feature = "i18n" )]
+	pub fn locale( mut self, locale: LanguageIdentifier ) -> Self {
+		self.locale = Some( locale );
+		self
+	}
+
+	/// Returns the name of the norm era (`before == false`) or the era before.
+	fn era_name( &self, before: bool ) -> String {
+		#[cfg( feature = "i18n" )]
+		if let Some( locale ) = &self.locale {
+			let key = if before { "era-before-norm" } else { "era-norm" };
+			return LOCALES.lookup( locale, key );
+		}
+
+		if before { "BNE".to_string() } else { "NE".to_string() }
+	}
+
+	/// Returns `time` formatted according to `self`.
+	pub fn format( &self, time: NormTime ) -> String {
+		let year = time.0.div_euclid( DUR_NORMYEAR );
+		let subyear = time.0.rem_euclid( DUR_NORMYEAR );
+		let subday = subyear % DUR_NORMDAY;
+
+		let sign = if year < 0 && self.era == EraStyle::Sign { "-" } else { "" };
+
+		let month = match &self.month_names {
+			Some( names ) => names.of( time ).to_string(),
+			None => format!( "{:0>fw$}", subyear / DUR_NORMMONTH, fw = self.field_width ),
+		};
+
+		let weekday = match &self.weekday_names {
+			Some( names ) => format!( "{} ", names.of( time ) ),
+			None => String::new(),
+		};
+
+		let mut res = format!(
+			"{weekday}{sign}{:0>yw$}{ds}{}{ds}{:0>fw$}",
+			year.unsigned_abs(),
+			month,
+			subyear % DUR_NORMMONTH / DUR_NORMDAY,
+			ds = self.date_separator,
+			yw = self.year_width,
+			fw = self.field_width,
+		);
+
+		if self.clock {
+			res.push_str( &format!(
+				"{}{:0>fw$}{cs}{:0>fw$}{cs}{:0>fw$}",
+				self.separator,
+				subday / DUR_HOUR,
+				subday % DUR_HOUR / DUR_MINUTE,
+				subday % DUR_MINUTE,
+				cs = self.clock_separator,
+				fw = self.field_width,
+			) );
+		}
+
+		if self.era == EraStyle::Suffix {
+			res.push( ' ' );
+			res.push_str( &self.era_name( year < 0 ) );
+		}
+
+		res
+	}
+}
+
+impl Default for NormTimeFormatter {
+	fn default() -> Self {
+		Self {
+			date_separator: '-',
+			clock_separator: ':',
+			separator: 'N',
+			year_width: 4,
+			field_width: 2,
+			clock: true,
+			era: EraStyle::Sign,
+			month_names: None,
+			weekday_names: None,
+			#[cfg( feature = "i18n" )]
+			locale: None,
+		}
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn default_equals_display() {
+		let formatter = NormTimeFormatter::new();
+
+		for time in [ NormTime( 0 ), NormTime( -1 ), NormTime( i64::MIN ), NormTime( i64::MAX ) ] {
+			assert_eq!( formatter.format( time ), time.to_string() );
+		}
+	}
+
+	#[test]
+	fn era_suffix_equals_alternate_display() {
+		let formatter = NormTimeFormatter::new().separator( ' ' ).era( EraStyle::Suffix );
+
+		for time in [ NormTime( 0 ), NormTime( -1 ), NormTime::from_ymd_opt( 45, 3, 12 ).unwrap() ] {
+			assert_eq!( formatter.format( time ), format!( "{:#}", time ) );
+		}
+	}
+}
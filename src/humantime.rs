@@ -0,0 +1,182 @@
+//! Parsing and formatting durations in the style of the `humantime` crate, e.g. `"2days 3h"`, using the lengths of the Normtime units.
+//!
+//! A normday has 100'000 seconds, a normweek 10 normdays, a normmonth 30 normdays and a normyear 10 normmonths. Hours, minutes, seconds and their fractions have their usual lengths. The function names match those of `humantime`, so CLIs can switch to Normtime by replacing the import.
+//!
+//! # Example
+//!
+//! ```
+//! use normtime::NormTimeDelta;
+//! use normtime::humantime::{parse_duration, format_duration};
+//!
+//! let delta = parse_duration( "2days 3h" ).unwrap();
+//! assert_eq!( delta, NormTimeDelta::new_days( 2 ) + NormTimeDelta::new_hours( 3 ) );
+//! assert_eq!( format_duration( delta ), "2days 3h" );
+//! ```
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use crate::{ConversionError, NormTimeDelta};
+use crate::{DUR_NORMYEAR, DUR_NORMMONTH, DUR_NORMWEEK, DUR_NORMDAY, DUR_HOUR, DUR_MINUTE};
+
+
+
+
+//=============================================================================
+// Constants
+
+
+/// The number of nanoseconds per second.
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+/// The units of the format (without weeks) with their length in nanoseconds, the name used for singular and plural.
+const UNITS_FORMAT: [( i128, &str, &str ); 9] = [
+	( DUR_NORMYEAR as i128 * NANOS_PER_SEC, "year", "years" ),
+	( DUR_NORMMONTH as i128 * NANOS_PER_SEC, "month", "months" ),
+	( DUR_NORMDAY as i128 * NANOS_PER_SEC, "day", "days" ),
+	( DUR_HOUR as i128 * NANOS_PER_SEC, "h", "h" ),
+	( DUR_MINUTE as i128 * NANOS_PER_SEC, "m", "m" ),
+	( NANOS_PER_SEC, "s", "s" ),
+	( 1_000_000, "ms", "ms" ),
+	( 1_000, "us", "us" ),
+	( 1, "ns", "ns" ),
+];
+
+
+
+
+//=============================================================================
+// Functions
+
+
+/// Returns the length of `unit` in nanoseconds. Units are case sensitive, since `M` is a month and `m` a minute.
+fn unit_nanos( unit: &str ) -> Option<i128> {
+	let res = match unit {
+		"nanos" | "nsec" | "ns" => 1,
+		"micros" | "usec" | "us" | "µs" => 1_000,
+		"millis" | "msec" | "ms" => 1_000_000,
+		"seconds" | "second" | "secs" | "sec" | "s" => NANOS_PER_SEC,
+		"minutes" | "minute" | "mins" | "min" | "m" => DUR_MINUTE as i128 * NANOS_PER_SEC,
+		"hours" | "hour" | "hrs" | "hr" | "h" => DUR_HOUR as i128 * NANOS_PER_SEC,
+		"days" | "day" | "d" => DUR_NORMDAY as i128 * NANOS_PER_SEC,
+		"weeks" | "week" | "w" => DUR_NORMWEEK as i128 * NANOS_PER_SEC,
+		"months" | "month" | "M" => DUR_NORMMONTH as i128 * NANOS_PER_SEC,
+		"years" | "year" | "y" => DUR_NORMYEAR as i128 * NANOS_PER_SEC,
+		_ => return None,
+	};
+
+	Some( res )
+}
+
+/// Parses `text` as sequence of numbers each followed by a unit, e.g. `"2days 3h"` or `"1M5d"`. Spaces between the elements are optional. A leading `-` negates the whole duration.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormTimeDelta;
+/// use normtime::humantime::parse_duration;
+///
+/// assert_eq!( parse_duration( "1year 2months" ).unwrap(), NormTimeDelta::new_days( 360 ) );
+/// assert_eq!( parse_duration( "1w1d" ).unwrap(), NormTimeDelta::new_days( 11 ) );
+/// assert_eq!( parse_duration( "-1h 500ms" ).unwrap(), NormTimeDelta::new( -3601, 500_000_000 ).unwrap() );
+/// assert!( parse_duration( "1 fortnight" ).is_err() );
+/// ```
+pub fn parse_duration( text: &str ) -> Result<NormTimeDelta, ConversionError> {
+	let error = || ConversionError::ParseError( text.to_string() );
+
+	let ( sign, mut rest ) = match text.trim().strip_prefix( '-' ) {
+		Some( x ) => ( -1, x ),
+		None => ( 1, text.trim() ),
+	};
+
+	let mut nanos: i128 = 0;
+	let mut found = false;
+	while !rest.is_empty() {
+		let end_number = rest.find( |c: char| !c.is_ascii_digit() ).unwrap_or( rest.len() );
+		let number = rest[..end_number].parse::<i128>().map_err( |_| error() )?;
+		rest = rest[end_number..].trim_start();
+
+		let end_unit = rest.find( |c: char| c.is_ascii_digit() || c.is_whitespace() ).unwrap_or( rest.len() );
+		let unit = unit_nanos( &rest[..end_unit] ).ok_or_else( error )?;
+		rest = rest[end_unit..].trim_start();
+
+		nanos = number.checked_mul( unit )
+			.and_then( |x| nanos.checked_add( x ) )
+			.ok_or_else( error )?;
+		found = true;
+	}
+
+	if !found || nanos > NormTimeDelta::MAX.total_nanos() {
+		return Err( error() );
+	}
+
+	Ok( NormTimeDelta::from_total_nanos( sign * nanos ) )
+}
+
+/// Returns `delta` in the style of `humantime`, e.g. `"1year 2months 3days 4h 5m 6s 7ms"`. Units that would be zero are omitted. A zero duration is formatted as `"0s"`, negative durations are prefixed by `-`.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormTimeDelta;
+/// use normtime::humantime::format_duration;
+///
+/// assert_eq!( format_duration( NormTimeDelta::new_days( 31 ) ), "1month 1day" );
+/// assert_eq!( format_duration( NormTimeDelta::new( 90, 5_000_000 ).unwrap() ), "1m 30s 5ms" );
+/// assert_eq!( format_duration( NormTimeDelta::new_seconds( -3600 ) ), "-1h" );
+/// assert_eq!( format_duration( NormTimeDelta::ZERO ), "0s" );
+/// ```
+pub fn format_duration( delta: NormTimeDelta ) -> String {
+	let total = delta.total_nanos();
+	if total == 0 {
+		return "0s".to_string();
+	}
+
+	let mut rest = total.abs();
+	let mut elems = Vec::new();
+	for ( nanos, singular, plural ) in UNITS_FORMAT {
+		let number = rest / nanos;
+		rest %= nanos;
+
+		match number {
+			0 => {},
+			1 => elems.push( format!( "1{}", singular ) ),
+			_ => elems.push( format!( "{}{}", number, plural ) ),
+		}
+	}
+
+	let sign = if total < 0 { "-" } else { "" };
+
+	format!( "{}{}", sign, elems.join( " " ) )
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn roundtrip() {
+		for text in [ "1year 2months 3days 4h 5m 6s 7ms 8us 9ns", "-9years", "1day 1ns", "0s" ] {
+			assert_eq!( format_duration( parse_duration( text ).unwrap() ), text );
+		}
+
+		assert_eq!( parse_duration( "1M 1m" ).unwrap(), NormTimeDelta::new_days( 30 ) + NormTimeDelta::new_minutes( 1 ) );
+		assert_eq!( format_duration( NormTimeDelta::MIN ), format!( "-{}", format_duration( NormTimeDelta::MAX ) ) );
+
+		assert!( parse_duration( "" ).is_err() );
+		assert!( parse_duration( "5" ).is_err() );
+		assert!( parse_duration( "h" ).is_err() );
+		assert!( parse_duration( "1000000000000years" ).is_err() );
+	}
+}
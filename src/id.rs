@@ -0,0 +1,198 @@
+//! Sortable 128-bit IDs seeded by `NormTime`.
+//!
+//! A [`NormId`] stores the milliseconds since 0000-00-00N00:00:00 in its high 64 bits and randomness in its low 64 bits, similar to a ULID. IDs therefore sort chronologically in Normtime, and the instant of their creation can be extracted again.
+//!
+//! # Example
+//!
+//! ```
+//! use normtime::NormTime;
+//! use normtime::id::NormId;
+//!
+//! let first = NormId::new();
+//! let second = NormId::new();
+//! assert!( first.millis() <= second.millis() );
+//! assert!( first.time() <= NormTime::now() );
+//!
+//! let id: NormId = first.to_string().parse().unwrap();
+//! assert_eq!( id, first );
+//! ```
+//!
+//! This module is only available, if the **`id`** feature has been enabled.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use std::fmt;
+use std::str::FromStr;
+#[cfg( not( all( feature = "wasm", target_arch = "wasm32" ) ) )] use std::time::{SystemTime, UNIX_EPOCH};
+
+use rand::Rng;
+
+use crate::{NormTime, TimeError, NORMTIME_OFFSET};
+
+
+
+
+//=============================================================================
+// Id
+
+
+/// A 128-bit ID, whose high 64 bits encode a `NormTime` with millisecond precision and whose low 64 bits are random.
+///
+/// The order of `NormId`s, their `u128` representation and their string representation (32 lowercase hexadecimal digits) all match the chronological order of the encoded instants. IDs created within the same millisecond are ordered randomly.
+#[derive( Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Debug )]
+pub struct NormId( u128 );
+
+impl NormId {
+	/// Flipping the sign bit of the milliseconds lets the unsigned order of the high bits match the signed order of the milliseconds.
+	const SIGN: u64 = 1 << 63;
+
+	/// Creates a new `NormId` for the current instant using the thread-local random number generator.
+	///
+	/// On `wasm32` targets with the **`wasm`** feature enabled, the time is taken from the browser's `Date.now()`.
+	#[allow( clippy::new_without_default )]
+	pub fn new() -> Self {
+		#[cfg( all( feature = "wasm", target_arch = "wasm32" ) )]
+		let millis = js_sys::Date::now().floor() as i64;
+
+		#[cfg( not( all( feature = "wasm", target_arch = "wasm32" ) ) )]
+		let millis = match SystemTime::now().duration_since( UNIX_EPOCH ) {
+			Ok( dur ) => dur.as_millis() as i64,
+			Err( err ) => -( err.duration().as_millis() as i64 ),
+		};
+
+		Self::from_parts( millis - NORMTIME_OFFSET * 1000, rand::rng().random() )
+	}
+
+	/// Creates a new `NormId` for `time` using `rng` for the random bits. Returns `None` if the milliseconds of `time` overflow an `i64`, which happens roughly 292 million normyears away from 0000-00-00N00:00:00.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use rand::SeedableRng;
+	/// use rand::rngs::StdRng;
+	/// use normtime::NormTime;
+	/// use normtime::id::NormId;
+	///
+	/// let mut rng = StdRng::seed_from_u64( 42 );
+	///
+	/// let early = NormTime::from_ymd_opt( -5, 3, 12 ).unwrap();
+	/// let late = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+	/// let id_early = NormId::with_time( early, &mut rng ).unwrap();
+	/// let id_late = NormId::with_time( late, &mut rng ).unwrap();
+	///
+	/// assert!( id_early < id_late );
+	/// assert_eq!( id_early.time(), early );
+	/// ```
+	pub fn with_time<R: Rng + ?Sized>( time: NormTime, rng: &mut R ) -> Option<Self> {
+		Some( Self::from_parts( time.0.checked_mul( 1000 )?, rng.random() ) )
+	}
+
+	/// Creates a `NormId` from the milliseconds since 0000-00-00N00:00:00 and the random bits.
+	pub const fn from_parts( millis: i64, random: u64 ) -> Self {
+		Self( ( ( millis as u64 ^ Self::SIGN ) as u128 ) << 64 | random as u128 )
+	}
+
+	/// Creates a `NormId` from its `u128` representation.
+	pub const fn from_u128( value: u128 ) -> Self {
+		Self( value )
+	}
+
+	/// Returns the `u128` representation of `self`.
+	pub const fn as_u128( self ) -> u128 {
+		self.0
+	}
+
+	/// Returns the milliseconds since 0000-00-00N00:00:00 encoded in `self`.
+	pub const fn millis( self ) -> i64 {
+		( ( self.0 >> 64 ) as u64 ^ Self::SIGN ) as i64
+	}
+
+	/// Returns the random bits of `self`.
+	pub const fn random( self ) -> u64 {
+		self.0 as u64
+	}
+
+	/// Returns the `NormTime` encoded in `self`, truncated to full seconds.
+	pub const fn time( self ) -> NormTime {
+		NormTime( self.millis().div_euclid( 1000 ) )
+	}
+}
+
+impl fmt::Display for NormId {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		write!( f, "{:032x}", self.0 )
+	}
+}
+
+impl FromStr for NormId {
+	type Err = TimeError;
+
+	/// Parses the 32 hexadecimal digits written by `Display`. Letters are accepted in upper and lower case.
+	fn from_str( s: &str ) -> Result<Self, Self::Err> {
+		if s.len() != 32 || !s.bytes().all( |x| x.is_ascii_hexdigit() ) {
+			return Err( TimeError::ParseError( s.to_string() ) );
+		}
+
+		Ok( Self( u128::from_str_radix( s, 16 )? ) )
+	}
+}
+
+impl From<NormId> for u128 {
+	fn from( value: NormId ) -> Self {
+		value.0
+	}
+}
+
+impl From<u128> for NormId {
+	fn from( value: u128 ) -> Self {
+		Self( value )
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	use rand::SeedableRng;
+	use rand::rngs::StdRng;
+
+	#[test]
+	fn order() {
+		let mut rng = StdRng::seed_from_u64( 42 );
+
+		let mut millis = [ i64::MAX, 0, -1, i64::MIN, 1_000, -1_000 ];
+		let mut ids = millis.map( |x| NormId::from_parts( x, rng.random() ) );
+		millis.sort();
+		ids.sort();
+		assert_eq!( ids.map( NormId::millis ), millis );
+
+		let mut texts = ids.map( |x| x.to_string() );
+		texts.sort();
+		assert_eq!( texts, ids.map( |x| x.to_string() ) );
+	}
+
+	#[test]
+	fn parts() {
+		let id = NormId::from_parts( -1_500, 42 );
+		assert_eq!( id.millis(), -1_500 );
+		assert_eq!( id.random(), 42 );
+		assert_eq!( id.time(), NormTime( -2 ) );
+		assert_eq!( NormId::from_u128( id.as_u128() ), id );
+
+		assert_eq!( NormId::with_time( NormTime( i64::MAX ), &mut rand::rng() ), None );
+		assert!( "0123".parse::<NormId>().is_err() );
+		assert!( "+0000000000000000000000000000000".parse::<NormId>().is_err() );
+	}
+}
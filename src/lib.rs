@@ -1,6 +1,8 @@
 // Replace crate links with internal links when creating documentation with `cargo`.
 //! [`NormTime`]: crate::NormTime
 //! [`NormTimeDelta`]: crate::NormTimeDelta
+//! [`NormTimeDeltaWide`]: crate::NormTimeDeltaWide
+//! [`Unit`]: crate::Unit
 //! [`DateTime`]: chrono::DateTime
 //! [`NaiveDateTime`]: chrono::NaiveDateTime
 //! [`fluent_templates`]: fluent_templates
@@ -21,14 +23,59 @@
 // Crates
 
 
+#[cfg( feature = "i18n" )] use std::collections::HashMap;
 #[cfg( any( feature = "i18n", feature = "tex" ) )] use std::fmt;
 
+#[cfg( feature = "i18n" )] use fluent_templates::{Loader, fluent_bundle::FluentValue};
 #[cfg( feature = "i18n" )] use unic_langid::LanguageIdentifier;
 
+#[macro_use] mod macros;
 mod time;
-pub use crate::time::NormTime;
+pub use crate::time::{NormTime, NormTimeFormat, ParseOptions, TimeError};
+mod date;
+pub use crate::date::NormDate;
+mod time_of_day;
+pub use crate::time_of_day::NormTimeOfDay;
+mod offset;
+pub use crate::offset::{NormTimeOffset, OffsetNormTime};
 mod duration;
-pub use crate::duration::{NormTimeDelta, Unit};
+pub use crate::duration::{ConversionError, NormTimeDelta, Unit};
+mod wide;
+pub use crate::wide::NormTimeDeltaWide;
+mod breakdown;
+pub use crate::breakdown::NormBreakdown;
+mod period;
+pub use crate::period::NormPeriod;
+mod unit_fmt;
+mod formatter;
+pub use crate::formatter::{EraStyle, NormTimeFormatter};
+mod season;
+pub use crate::season::{NormQuarters, NormSeason, NormSeasons};
+mod shift;
+pub use crate::shift::{NormShift, NormShifts};
+mod stopwatch;
+pub use crate::stopwatch::{NormStopwatch, StopwatchClock, SystemClock};
+mod map;
+pub use crate::map::NormTimeMap;
+mod ext;
+pub use crate::ext::NormDurationExt;
+#[cfg( feature = "chrono" )] pub use crate::ext::{NormDeltaExt, NormTimeExt};
+pub mod humantime;
+pub mod names;
+pub mod stats;
+#[cfg( feature = "arrow" )] pub mod arrow;
+#[cfg( feature = "clap" )] pub mod clap;
+#[cfg( feature = "id" )] pub mod id;
+#[cfg( feature = "log-fmt" )] pub mod log_fmt;
+#[cfg( feature = "polars" )] pub mod polars;
+#[cfg( feature = "proptest" )] pub mod proptest;
+#[cfg( feature = "rand" )] pub mod rand;
+#[cfg( feature = "serde" )] pub mod serde;
+#[cfg( feature = "tokio" )] pub mod tokio;
+#[cfg( feature = "tracing" )] pub mod tracing;
+#[cfg( feature = "uniffi" )] pub mod ffi;
+
+#[cfg( feature = "uniffi" )] uniffi::setup_scaffolding!();
 
 
 
@@ -129,14 +176,168 @@ const DUR_MINUTE: i64 = 60;
 // Structs
 
 
-/// Representing options to LaTeX commands provided by the `Latex` trait.
+/// Representing options to the plain text unit formatters like [`NormTimeDelta::to_string_unit_with`].
+///
+/// # Example
+///
+/// ```
+/// use normtime::{Notation, NormTimeDeltaWide, TextOptions, Unit};
+///
+/// let options = TextOptions {
+///     notation: Notation::Scientific,
+///     ..TextOptions::new()
+/// };
+///
+/// let delta = NormTimeDeltaWide::new_years( 3_200_000 );
+/// assert_eq!( delta.to_string_unit_with( &[ Unit::Year ], &options ), "3.2e6 normyears" );
+/// ```
+#[derive( Clone, PartialEq, Default, Debug )]
+pub struct TextOptions {
+	/// The notation of the numbers of units.
+	pub notation: Notation,
+
+	/// Writing the sign of negative numbers as Unicode minus sign `−` (U+2212) instead of a hyphen.
+	pub unicode_minus: bool,
+
+	/// Separating numbers from their units by a narrow no-break space (U+202F) instead of a space.
+	pub narrow_space: bool,
+
+	/// Spelling out counts from 1 to 12 as words, e.g. "three normdays". Only the localized formatters like [`NormTimeDelta::to_string_unit_locale_with`] spell out numbers, all other formatters ignore this option.
+	pub spell_out: bool,
+}
+
+impl TextOptions {
+	/// Creates the default `TextOptions`, producing the same strings as the formatters without options.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Creates `TextOptions` for high-quality typography, using the Unicode minus sign and narrow no-break spaces.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTimeDelta, TextOptions, Unit};
+	///
+	/// let delta = NormTimeDelta::new_days( -3 );
+	/// assert_eq!( delta.to_string_unit_with( &[ Unit::Day ], &TextOptions::typographic() ), "−3\u{202F}normdays" );
+	/// assert_eq!( delta.to_string_sym_unit_with( &[ Unit::Day ], &TextOptions::typographic() ), "−3\u{202F}d" );
+	/// ```
+	pub fn typographic() -> Self {
+		Self {
+			unicode_minus: true,
+			narrow_space: true,
+			..Self::default()
+		}
+	}
+
+	/// Returns `count` written according to `self`.
+	pub(crate) fn format_count( &self, count: i128 ) -> String {
+		let res = self.notation.format_count( count );
+
+		if self.unicode_minus && count < 0 {
+			return res.replacen( '-', "−", 1 );
+		}
+
+		res
+	}
+
+	/// Returns `count` of `unit` written according to `self` in the language `locale`. Spelled out numbers are provided by the message `number-word` of the fluent resources.
+	#[cfg( feature = "i18n" )]
+	pub(crate) fn format_count_locale( &self, count: i128, unit: Unit, locale: &LanguageIdentifier ) -> String {
+		if !self.spell_out || !( 1..=12 ).contains( &count ) {
+			return self.format_count( count );
+		}
+
+		let args = HashMap::from( [
+			( "count", FluentValue::from( count as i64 ) ),
+			( "unit", FluentValue::from( unit.as_str_singular() ) ),
+		] );
+
+		LOCALES.lookup_with_args( locale, "number-word", &args )
+	}
+
+	/// Returns the separator between a number and its unit.
+	pub(crate) fn unit_separator( &self ) -> &'static str {
+		if self.narrow_space {
+			"\u{202F}"
+		} else {
+			" "
+		}
+	}
+}
+
+
+/// The notation of the numbers of units in [`TextOptions`]. Numbers below 1000 are always written in full.
+#[derive( Clone, Copy, PartialEq, Eq, Hash, Default, Debug )]
+pub enum Notation {
+	/// All digits, e.g. `3217543`.
+	#[default]
+	Plain,
+
+	/// Scientific notation with up to 3 significant digits, e.g. `3.22e6`.
+	Scientific,
+
+	/// Up to 3 significant digits followed by an SI prefix, e.g. `3.22M`.
+	SiPrefix,
+}
+
+impl Notation {
+	/// The SI prefixes for powers of 1000.
+	const SI_PREFIXES: [&'static str; 11] = [ "", "k", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q" ];
+
+	/// Returns `count` written in this notation.
+	pub(crate) fn format_count( self, count: i128 ) -> String {
+		if self == Self::Plain || count.unsigned_abs() < 1000 {
+			return count.to_string();
+		}
+
+		// Rounding to 3 significant digits may carry into the next power of ten, which `{:e}` takes care of.
+		let sci = format!( "{:.2e}", count as f64 );
+		let ( mantissa, exp ) = sci.split_once( 'e' ).expect( "`{:e}` always contains an exponent" );
+		let exp: i32 = exp.parse().expect( "`{:e}` always has an integer exponent" );
+		let trim = |x: String| if x.contains( '.' ) { x.trim_end_matches( '0' ).trim_end_matches( '.' ).to_string() } else { x };
+
+		match self {
+			Self::Plain => unreachable!(),
+			Self::Scientific => format!( "{}e{}", trim( mantissa.to_string() ), exp ),
+			Self::SiPrefix => {
+				let power = ( exp / 3 ).min( Self::SI_PREFIXES.len() as i32 - 1 );
+				let shift = exp - 3 * power;
+				let value: f64 = mantissa.parse::<f64>().expect( "`{:e}` always has a valid mantissa" ) * 10f64.powi( shift );
+
+				format!( "{}{}", trim( format!( "{:.*}", ( 2 - shift ).max( 0 ) as usize, value ) ), Self::SI_PREFIXES[ power as usize ] )
+			},
+		}
+	}
+}
+
+
+/// Representing options to LaTeX commands provided by the `Latex` trait and the LaTeX unit formatters like [`NormTimeDelta::to_latex_sym_unit_with`].
 ///
 /// **Note:** This struct is only available, if the **`tex`** feature has been enabled.
 ///
-/// TODO: This is currently not being used, but will provide the possibility to fine-tune the TeX output. It is added to keep the function signatures stable.
+/// # Example
+///
+/// ```
+/// use normtime::{LatexSym, NormTimeDelta, TexOptions};
+///
+/// let options = TexOptions::new()
+///     .siunitx( "group-digits", "none" )
+///     .siunitx( "round-mode", "places" );
+///
+/// assert_eq!(
+///     NormTimeDelta::new_seconds( 10_000 ).to_latex_sym( &options ),
+///     r"\qty[group-digits=none,round-mode=places]{10000}{\second}"
+/// );
+/// ```
 #[cfg( feature = "tex" )]
-#[derive( PartialEq, Default, Debug )]
-pub struct TexOptions {}
+#[derive( Clone, PartialEq, Default, Debug )]
+pub struct TexOptions {
+	siunitx: Vec<( String, String )>,
+	minus: TexMinus,
+	unit_macros: [Option<String>; Unit::ALL.len()],
+}
 
 #[cfg( feature = "tex" )]
 impl TexOptions {
@@ -149,6 +350,108 @@ impl TexOptions {
 	pub fn none() -> Self {
 		Self::default()
 	}
+
+	/// Adds the `{siunitx}` option `key` with `value`, which is passed as optional argument to every `\qty` and `\num` command, e.g. `\qty[round-mode=places]{10}{\second}`. If any `{siunitx}` option is set, numbers written without unit symbol are wrapped in `\num`.
+	pub fn siunitx( mut self, key: impl Into<String>, value: impl Into<String> ) -> Self {
+		self.siunitx.push( ( key.into(), value.into() ) );
+		self
+	}
+
+	/// Sets how the sign of negative numbers is written. See [`TexMinus`].
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{Latex, NormTimeDelta, TexMinus, TexOptions};
+	///
+	/// let delta = NormTimeDelta::new_seconds( -5 );
+	/// assert_eq!( delta.to_latex( &TexOptions::new() ), "−5~seconds" );
+	/// assert_eq!( delta.to_latex( &TexOptions::new().minus( TexMinus::Num ) ), r"\num{-5}~seconds" );
+	/// assert_eq!( delta.to_latex( &TexOptions::new().minus( TexMinus::Hyphen ) ), "-5~seconds" );
+	/// ```
+	pub fn minus( mut self, minus: TexMinus ) -> Self {
+		self.minus = minus;
+		self
+	}
+
+	/// Overrides the LaTeX macro emitted for `unit` by [`LatexSym`], e.g. `\nday` instead of `\normday`. The macro has to be defined in the LaTeX document.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{LatexSym, NormTimeDelta, TexOptions, Unit};
+	///
+	/// let options = TexOptions::new()
+	///     .unit_macro( Unit::Year, r"\nyr" )
+	///     .unit_macro( Unit::Day, r"\nday" );
+	///
+	/// assert_eq!( Unit::Day.to_latex_sym( &options ), r"\nday" );
+	/// assert_eq!( Unit::Hour.to_latex_sym( &options ), r"\hour" );
+	/// assert_eq!(
+	///     NormTimeDelta::new_days( 305 ).to_latex_sym_unit_with( &[ Unit::Year, Unit::Day ], &options ),
+	///     r"\qty{1}{\nyr}\,\qty{5}{\nday}"
+	/// );
+	/// ```
+	pub fn unit_macro( mut self, unit: Unit, name: impl Into<String> ) -> Self {
+		self.unit_macros[ unit as usize ] = Some( name.into() );
+		self
+	}
+
+	/// Returns the LaTeX macro overriding the default one of `unit`, if any.
+	pub(crate) fn unit_macro_of( &self, unit: Unit ) -> Option<&str> {
+		self.unit_macros[ unit as usize ].as_deref()
+	}
+
+	/// Returns the optional argument of `{siunitx}` commands, e.g. `[round-mode=places]`. Returns an empty string, if no `{siunitx}` option is set.
+	fn siunitx_arg( &self ) -> String {
+		if self.siunitx.is_empty() {
+			return String::new();
+		}
+
+		let options: Vec<String> = self.siunitx.iter()
+			.map( |( k, v )| format!( "{}={}", k, v ) )
+			.collect();
+
+		format!( "[{}]", options.join( "," ) )
+	}
+
+	/// Returns `number` as LaTeX code. The number is wrapped in `\num`, if any `{siunitx}` option is set or a negative number is to be typeset by `{siunitx}`.
+	pub(crate) fn num( &self, number: impl fmt::Display ) -> String {
+		let number = number.to_string();
+		let negative = number.starts_with( '-' );
+
+		if !self.siunitx.is_empty() || ( negative && self.minus == TexMinus::Num ) {
+			return format!( r"\num{}{{{}}}", self.siunitx_arg(), number );
+		}
+
+		match ( negative, self.minus ) {
+			( true, TexMinus::Unicode ) => number.replacen( '-', "−", 1 ),
+			_ => number,
+		}
+	}
+
+	/// Returns `number` with the unit `unit` as `{siunitx}` command `\qty`.
+	pub(crate) fn qty( &self, number: impl fmt::Display, unit: &str ) -> String {
+		format!( r"\qty{}{{{}}}{{{}}}", self.siunitx_arg(), number, unit )
+	}
+}
+
+
+/// The representation of the sign of negative numbers in LaTeX code, which are not typeset by `{siunitx}` anyway. Numbers within `\qty` are always left to `{siunitx}`.
+///
+/// **Note:** This enum is only available, if the **`tex`** feature has been enabled.
+#[cfg( feature = "tex" )]
+#[derive( Clone, Copy, PartialEq, Eq, Hash, Default, Debug )]
+pub enum TexMinus {
+	/// The Unicode minus sign `−` (U+2212), like in [`NormTime::to_latex_date`].
+	#[default]
+	Unicode,
+
+	/// Wrapping negative numbers in `\num{}`, leaving the sign to `{siunitx}`.
+	Num,
+
+	/// The plain ASCII hyphen `-`.
+	Hyphen,
 }
 
 
@@ -166,5 +469,8 @@ fluent_templates::static_loader! {
 
 		// The language to falback on if something is not present.
 		fallback_language: "en-US",
+
+		// Arguments like the components of date patterns are inserted without Unicode isolation marks.
+		customise: |bundle| bundle.set_use_isolating( false ),
 	};
 }
@@ -0,0 +1,169 @@
+//! Rendering the time of `log` records as Normtime.
+//!
+//! [`format_record`] can be passed directly to `env_logger::Builder::format`. For `fern` and other loggers, [`timestamp`] returns the current time to be used in their format closures. To select a locale, use [`LogFormat`].
+//!
+//! # Example
+//!
+//! ```
+//! use std::fmt::Write;
+//!
+//! let mut line = String::new();
+//! write!( line, "[{}] message", normtime::log_fmt::timestamp() ).unwrap();
+//! ```
+//!
+//! This module is only available, if the **`log-fmt`** feature has been enabled.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use std::io;
+
+use log::Record;
+use unic_langid::LanguageIdentifier;
+
+use crate::{DisplayLocale, NormTime};
+
+
+
+
+//=============================================================================
+// Functions
+
+
+/// Returns the current time to be used as timestamp of log messages.
+pub fn timestamp() -> NormTime {
+	NormTime::now()
+}
+
+/// Writes `record` as line `[<normtime> <level> <target>] <message>` into `buf`.
+///
+/// # Example
+///
+/// ```
+/// let record = log::Record::builder()
+///     .args( format_args!( "Ship launched" ) )
+///     .level( log::Level::Info )
+///     .target( "fleet" )
+///     .build();
+///
+/// let mut buf = Vec::new();
+/// normtime::log_fmt::format_record( &mut buf, &record ).unwrap();
+///
+/// let line = String::from_utf8( buf ).unwrap();
+/// assert!( line.ends_with( " INFO  fleet] Ship launched\n" ) );
+/// ```
+pub fn format_record<W: io::Write + ?Sized>( buf: &mut W, record: &Record<'_> ) -> io::Result<()> {
+	write_line( buf, &timestamp().to_string(), record )
+}
+
+/// Writes `record` as line prefixed with the timestamp `time`.
+fn write_line<W: io::Write + ?Sized>( buf: &mut W, time: &str, record: &Record<'_> ) -> io::Result<()> {
+	writeln!( buf, "[{} {:<5} {}] {}", time, record.level(), record.target(), record.args() )
+}
+
+
+
+
+//=============================================================================
+// Format
+
+
+/// Format of log lines using a localized timestamp.
+///
+/// # Example
+///
+/// ```
+/// use unic_langid::langid;
+/// use normtime::log_fmt::LogFormat;
+///
+/// let format = LogFormat::new( langid!( "de-DE" ) );
+///
+/// let record = log::Record::builder()
+///     .args( format_args!( "Schiff gestartet" ) )
+///     .level( log::Level::Warn )
+///     .build();
+///
+/// let mut buf = Vec::new();
+/// format.format( &mut buf, &record ).unwrap();
+/// assert!( String::from_utf8( buf ).unwrap().ends_with( " WARN  ] Schiff gestartet\n" ) );
+/// ```
+///
+/// With `env_logger`, the format is used as closure: `builder.format( move |buf, record| format.format( buf, record ) )`.
+#[derive( Clone, PartialEq, Debug )]
+pub struct LogFormat {
+	/// The locale used to render the timestamp.
+	pub locale: LanguageIdentifier,
+}
+
+impl LogFormat {
+	/// Creates a new `LogFormat` rendering timestamps in `locale`.
+	pub fn new( locale: LanguageIdentifier ) -> Self {
+		Self { locale }
+	}
+
+	/// Returns the current time as string localized according to `self.locale`, e.g. `12.03.0045 NÄ 08:00:00` for `de-DE`. See [`DisplayLocale for NormTime`](NormTime#impl-DisplayLocale-for-NormTime).
+	pub fn timestamp( &self ) -> String {
+		timestamp().to_string_locale( &self.locale )
+	}
+
+	/// Writes `record` as line `[<normtime> <level> <target>] <message>` into `buf`. See [`format_record`].
+	pub fn format<W: io::Write + ?Sized>( &self, buf: &mut W, record: &Record<'_> ) -> io::Result<()> {
+		write_line( buf, &self.timestamp(), record )
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	use unic_langid::langid;
+
+	#[test]
+	fn log_line() {
+		let record = Record::builder()
+			.args( format_args!( "message" ) )
+			.level( log::Level::Error )
+			.target( "target" )
+			.build();
+
+		let mut buf = Vec::new();
+		format_record( &mut buf, &record ).unwrap();
+		let line = String::from_utf8( buf ).unwrap();
+
+		let ( time, rest ) = line.trim_start_matches( '[' ).split_once( ' ' ).unwrap();
+		assert!( time.parse::<NormTime>().is_ok() );
+		assert_eq!( rest, "ERROR target] message\n" );
+	}
+
+	#[test]
+	fn localized_timestamp() {
+		let en = LogFormat::new( langid!( "en-US" ) ).timestamp();
+		let de = LogFormat::new( langid!( "de-DE" ) ).timestamp();
+		assert_ne!( en, de );
+		assert!( en.contains( "NE " ) );
+		assert!( de.contains( "NÄ " ) );
+
+		let record = Record::builder()
+			.args( format_args!( "message" ) )
+			.level( log::Level::Error )
+			.target( "target" )
+			.build();
+
+		let mut buf = Vec::new();
+		LogFormat::new( langid!( "de-DE" ) ).format( &mut buf, &record ).unwrap();
+		let line = String::from_utf8( buf ).unwrap();
+		assert!( line.contains( "NÄ " ) );
+		assert!( line.ends_with( " ERROR target] message\n" ) );
+	}
+}
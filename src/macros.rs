@@ -0,0 +1,130 @@
+//! Macros creating Normtime values from literals at compile time and macros used internally.
+
+
+
+
+//=============================================================================
+// Macros
+
+
+/// Creates a `NormTime` from a string literal in the canonical representation `YYYY-MM-DDNhh:mm:ss` or `YYYY-MM-DD` as written by `Display`. The literal is parsed and validated at compile time like by [`NormTime::parse_strict`](crate::NormTime::parse_strict), so an invalid literal fails to compile. The macro can be used in const contexts.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{normtime, NormTime};
+///
+/// const LANDING: NormTime = normtime!( "0045-03-12N08:00:00" );
+/// assert_eq!( LANDING, NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 ) );
+/// assert_eq!( normtime!( "-0005-03-12" ), NormTime::from_ymd_opt( -5, 3, 12 ).unwrap() );
+/// ```
+///
+/// Fields exceeding their range are rejected at compile time:
+///
+/// ```compile_fail
+/// use normtime::normtime;
+///
+/// let time = normtime!( "0045-03-30N08:00:00" );
+/// ```
+#[macro_export]
+macro_rules! normtime {
+	( $s:literal ) => {{
+		const TIME: $crate::NormTime = match $crate::NormTime::parse_const( $s ) {
+			Some( x ) => x,
+			None => panic!( concat!( "invalid NormTime literal: ", $s ) ),
+		};
+		TIME
+	}};
+}
+
+
+/// Creates a `NormTimeDelta` from a list of numbers with units like `normdelta!( 1 year, 2 weeks, 3 hours )`. The sum is calculated and checked at compile time, so durations out of bounds fail to compile. The macro can be used in const contexts.
+///
+/// The units are `year`, `month`, `week`, `day`, `hour`, `minute` and `second`, each also in plural and the units of the calendar with `norm` prefix, e.g. `normdays`. All calendar units refer to their norm variants.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{normdelta, NormTimeDelta};
+///
+/// const SHIFT: NormTimeDelta = normdelta!( 8 hours, 20 minutes );
+/// assert_eq!( SHIFT, NormTimeDelta::new_seconds( 30_000 ) );
+/// assert_eq!( normdelta!( 1 year, 2 weeks, -3 normdays ), NormTimeDelta::new_days( 317 ) );
+/// ```
+///
+/// Unknown units are rejected at compile time:
+///
+/// ```compile_fail
+/// use normtime::normdelta;
+///
+/// let delta = normdelta!( 3 fortnights );
+/// ```
+#[macro_export]
+macro_rules! normdelta {
+	( $( $number:literal $unit:ident ),+ $(,)? ) => {{
+		const DELTA: $crate::NormTimeDelta = match $crate::NormTimeDelta::__sum_const( &[ $( ( $number, $crate::__normdelta_unit!( $unit ) ) ),+ ] ) {
+			Some( x ) => x,
+			None => panic!( "normdelta! literal is out of bounds" ),
+		};
+		DELTA
+	}};
+}
+
+
+/// Returns the `Unit` named by a unit of the [`normdelta!`](crate::normdelta) macro.
+#[doc( hidden )]
+#[macro_export]
+macro_rules! __normdelta_unit {
+	( year ) => { $crate::Unit::Year };
+	( years ) => { $crate::Unit::Year };
+	( normyear ) => { $crate::Unit::Year };
+	( normyears ) => { $crate::Unit::Year };
+	( month ) => { $crate::Unit::Month };
+	( months ) => { $crate::Unit::Month };
+	( normmonth ) => { $crate::Unit::Month };
+	( normmonths ) => { $crate::Unit::Month };
+	( week ) => { $crate::Unit::Week };
+	( weeks ) => { $crate::Unit::Week };
+	( normweek ) => { $crate::Unit::Week };
+	( normweeks ) => { $crate::Unit::Week };
+	( day ) => { $crate::Unit::Day };
+	( days ) => { $crate::Unit::Day };
+	( normday ) => { $crate::Unit::Day };
+	( normdays ) => { $crate::Unit::Day };
+	( hour ) => { $crate::Unit::Hour };
+	( hours ) => { $crate::Unit::Hour };
+	( minute ) => { $crate::Unit::Minute };
+	( minutes ) => { $crate::Unit::Minute };
+	( second ) => { $crate::Unit::Second };
+	( seconds ) => { $crate::Unit::Second };
+}
+
+
+/// Implements the binary operator `$imp::$method` for all combinations of references to `$t` and `$u`, forwarding to the implementation on values. All types involved have to be `Copy`.
+macro_rules! forward_ref_binop {
+	( impl $imp:ident, $method:ident for $t:ty, $u:ty ) => {
+		impl $imp<$u> for &$t {
+			type Output = <$t as $imp<$u>>::Output;
+
+			fn $method( self, other: $u ) -> Self::Output {
+				$imp::$method( *self, other )
+			}
+		}
+
+		impl $imp<&$u> for $t {
+			type Output = <$t as $imp<$u>>::Output;
+
+			fn $method( self, other: &$u ) -> Self::Output {
+				$imp::$method( self, *other )
+			}
+		}
+
+		impl $imp<&$u> for &$t {
+			type Output = <$t as $imp<$u>>::Output;
+
+			fn $method( self, other: &$u ) -> Self::Output {
+				$imp::$method( *self, *other )
+			}
+		}
+	};
+}
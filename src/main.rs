@@ -0,0 +1,281 @@
+//! Command line interface to `normtime`.
+//!
+//! This binary is only available, if the **`cli`** feature has been enabled.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use clap::{Parser, Subcommand};
+use serde_json::{json, Value};
+use unic_langid::LanguageIdentifier;
+
+use normtime::{DisplayLocale, NormPeriod, NormTime, NormTimeDelta, Unit};
+
+
+
+
+//=============================================================================
+// Command line
+
+
+/// Tracking time as 'Normtime'.
+#[derive( Parser, Debug )]
+#[command( version, about )]
+struct Cli {
+	/// Print the result as JSON.
+	#[arg( long, global = true )]
+	json: bool,
+
+	/// Locale used when printing durations with units, e.g. `de-DE`.
+	#[arg( long, global = true )]
+	locale: Option<LanguageIdentifier>,
+
+	#[command( subcommand )]
+	command: Command,
+}
+
+#[derive( Subcommand, Debug )]
+enum Command {
+	/// Print the current time.
+	Now,
+
+	/// Convert an ISO 8601 date (time) into normtime or vice versa.
+	Convert {
+		/// Either a normtime like `0123-04-05N06:07:08` or an ISO 8601 date like `2024-03-01T12:00:00`. Normtimes are recognized by their `N` separator.
+		time: String,
+	},
+
+	/// Add a duration to a time.
+	Add {
+		/// The normtime to start from. `now` represents the current time.
+		time: String,
+
+		/// The duration to add, e.g. `100 seconds`, `-3 normdays` or `1 d 2 h`.
+		#[arg( allow_hyphen_values = true )]
+		delta: String,
+	},
+
+	/// Print the duration from `b` to `a`.
+	Diff {
+		/// The later normtime. `now` represents the current time.
+		a: String,
+
+		/// The earlier normtime. `now` represents the current time.
+		b: String,
+
+		/// Units used to represent the duration, e.g. `day,hour`.
+		#[arg( long, value_delimiter = ',' )]
+		units: Vec<Unit>,
+	},
+
+	/// Print a duration using the given units.
+	Format {
+		/// The duration, e.g. `100 seconds`, `-3 normdays` or `1 d 2 h`.
+		#[arg( allow_hyphen_values = true )]
+		delta: String,
+
+		/// Units used to represent the duration, e.g. `day,hour`.
+		#[arg( long, value_delimiter = ',' )]
+		units: Vec<Unit>,
+	},
+}
+
+
+
+
+//=============================================================================
+// Helpers
+
+
+/// Parses `text` as normtime. `now` represents the current time.
+fn parse_time( text: &str ) -> Result<NormTime, String> {
+	if text == "now" {
+		return Ok( NormTime::now() );
+	}
+
+	text.parse().map_err( |e| format!( "{}", e ) )
+}
+
+/// Parses `text` as duration like `NormTimeDelta::from_str` does, e.g. `-3 normdays` or `1 d 2 h`.
+fn parse_delta( text: &str ) -> Result<NormTimeDelta, String> {
+	NormTimeDelta::from_str( text.trim() ).map_err( |e| format!( "{}", e ) )
+}
+
+/// Parses `text` as ISO 8601 date time or date.
+fn parse_iso( text: &str ) -> Option<NaiveDateTime> {
+	if let Ok( x ) = NaiveDateTime::from_str( text ) {
+		return Some( x );
+	}
+
+	NaiveDate::from_str( text ).ok().map( |x| x.and_hms_opt( 0, 0, 0 ).unwrap() )
+}
+
+/// Returns the ISO 8601 representation of `time`. Fails if `time` is outside of the range supported by `chrono`.
+fn to_iso( time: NormTime ) -> Result<String, String> {
+	let error = || format!( "Normtime out of the range of ISO 8601 dates: {}", time );
+
+	// `NormTime::timestamp` overflows for the latest normtimes.
+	if NormTime::from_timestamp( i64::MAX ).is_some_and( |x| time > x ) {
+		return Err( error() );
+	}
+
+	DateTime::from_timestamp( time.timestamp(), 0 )
+		.map( |x| x.naive_utc().format( "%Y-%m-%dT%H:%M:%S" ).to_string() )
+		.ok_or_else( error )
+}
+
+/// Returns the string representation of `delta`. If `units` is empty, the duration is given in seconds. Durations shorter than the smallest of `units` are given as zero of that unit.
+fn format_delta( delta: NormTimeDelta, units: &[Unit], locale: Option<&LanguageIdentifier> ) -> String {
+	let Some( smallest ) = units.iter().min_by_key( |x| NormTimeDelta::from_unit( 1, **x ) ) else {
+		return delta.to_string();
+	};
+
+	let res = match locale {
+		Some( x ) => delta.to_string_unit_locale( units, x ),
+		None => delta.to_string_unit( units ),
+	};
+
+	if !res.is_empty() {
+		return res;
+	}
+
+	match locale {
+		Some( x ) => format!( "0 {}", smallest.to_string_locale( x ) ),
+		None => format!( "0 {}", smallest.as_str() ),
+	}
+}
+
+/// Returns the JSON representation of `time`.
+fn time_json( time: NormTime ) -> Result<Value, String> {
+	Ok( json!( {
+		"normtime": time.to_string(),
+		"iso": to_iso( time )?,
+		"timestamp": time.timestamp(),
+	} ) )
+}
+
+/// Returns the JSON representation of `delta`.
+fn delta_json( delta: NormTimeDelta, text: &str ) -> Value {
+	json!( {
+		"seconds": delta.seconds(),
+		"text": text,
+	} )
+}
+
+fn run( cli: Cli ) -> Result<(), String> {
+	let locale = cli.locale.as_ref();
+
+	let ( text, value ) = match cli.command {
+		Command::Now => {
+			let time = NormTime::now();
+			( time.to_string(), time_json( time )? )
+		},
+		Command::Convert { time } => {
+			if time.contains( 'N' ) {
+				let x = parse_time( &time )?;
+				( to_iso( x )?, time_json( x )? )
+			} else if let Some( x ) = parse_iso( &time ) {
+				let res = NormTime::from( x );
+				( res.to_string(), time_json( res )? )
+			} else {
+				return Err( format!( "Neither a normtime nor an ISO 8601 date: {}", time ) );
+			}
+		},
+		Command::Add { time, delta } => {
+			let res = parse_time( &time )?
+				.checked_add_period( &NormPeriod::new_seconds( parse_delta( &delta )?.seconds() ) )
+				.ok_or_else( || format!( "Normtime out of range: {} + {}", time, delta ) )?;
+			( res.to_string(), time_json( res )? )
+		},
+		Command::Diff { a, b, units } => {
+			let res = NormPeriod::between( parse_time( &b )?, parse_time( &a )? ).to_delta()
+				.ok_or_else( || format!( "Duration out of range: {} - {}", a, b ) )?;
+			let text = format_delta( res, &units, locale );
+			let value = delta_json( res, &text );
+			( text, value )
+		},
+		Command::Format { delta, units } => {
+			let res = parse_delta( &delta )?;
+			let text = format_delta( res, &units, locale );
+			let value = delta_json( res, &text );
+			( text, value )
+		},
+	};
+
+	if cli.json {
+		println!( "{}", value );
+	} else {
+		println!( "{}", text );
+	}
+
+	Ok( () )
+}
+
+
+
+
+//=============================================================================
+// Main
+
+
+fn main() -> ExitCode {
+	match run( Cli::parse() ) {
+		Ok( () ) => ExitCode::SUCCESS,
+		Err( e ) => {
+			eprintln!( "error: {}", e );
+			ExitCode::FAILURE
+		},
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_deltas() {
+		assert_eq!( parse_delta( "100 seconds" ), Ok( NormTimeDelta::new_seconds( 100 ) ) );
+		assert_eq!( parse_delta( "-3 normdays" ), Ok( NormTimeDelta::new_days( -3 ) ) );
+		assert_eq!( parse_delta( "1 d 2 h" ), Ok( NormTimeDelta::new_days( 1 ) + NormTimeDelta::new_hours( 2 ) ) );
+		assert_eq!( parse_delta( "PT90S" ), Ok( NormTimeDelta::new_seconds( 90 ) ) );
+		assert!( parse_delta( "2 lightyears" ).is_err() );
+		assert!( parse_delta( "hours" ).is_err() );
+		assert!( parse_delta( "9223372036854775807 normmonths" ).is_err() );
+		assert!( parse_delta( "-9223372036854775807 normyears" ).is_err() );
+	}
+
+	#[test]
+	fn iso_out_of_range() {
+		let latest = NormTime::from_timestamp( i64::MAX ).unwrap();
+		assert_eq!( to_iso( NormTime::from_timestamp( 0 ).unwrap() ), Ok( "1970-01-01T00:00:00".to_string() ) );
+		assert!( to_iso( latest ).is_err() );
+		assert!( to_iso( NormTime::from_timestamp( i64::MIN / 2 ).unwrap() ).is_err() );
+		assert!( time_json( latest ).is_err() );
+	}
+
+	#[test]
+	fn format_deltas() {
+		let delta = NormTimeDelta::new_days( 1 ) + NormTimeDelta::new_hours( 2 );
+		assert_eq!( format_delta( delta, &[], None ), "107200 seconds" );
+		assert_eq!( format_delta( delta, &[ Unit::Day, Unit::Hour ], None ), "1 normday 2 hours" );
+		assert_eq!( format_delta( NormTimeDelta::new_seconds( 0 ) - delta, &[ Unit::Day, Unit::Hour ], None ), "-1 normday -2 hours" );
+		assert_eq!( format_delta( NormTimeDelta::ZERO, &[ Unit::Day ], None ), "0 normdays" );
+		assert_eq!( format_delta( NormTimeDelta::new( -1, 500_000_000 ).unwrap(), &[ Unit::Hour, Unit::Second ], None ), "0 seconds" );
+		assert_eq!( format_delta( NormTimeDelta::ZERO, &[ Unit::Day ], Some( &"de-DE".parse().unwrap() ) ), "0 Normtage" );
+	}
+}
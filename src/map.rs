@@ -0,0 +1,229 @@
+//! A collection of values keyed by time ranges.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use std::ops::Range;
+
+use crate::NormTime;
+
+
+
+
+//=============================================================================
+// Map
+
+
+/// A collection of values keyed by half-open ranges of `NormTime`, supporting queries for all values covering an instant or overlapping a range.
+///
+/// The keys are the standard [`Range<NormTime>`](Range) written as `start..end`, since Normtime has no dedicated range type. Ranges may overlap and may be inserted multiple times. Empty ranges are kept, but never cover an instant or overlap a range. Iteration and queries yield the entries in chronological order, sorted by start and then by end of their ranges. Entries with equal ranges keep the order of insertion.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{NormTime, NormTimeMap};
+///
+/// let day = |x| NormTime::from_ymd_opt( 45, 3, x ).unwrap();
+///
+/// let mut events = NormTimeMap::new();
+/// events.insert( day( 10 )..day( 20 ), "Expedition" );
+/// events.insert( day( 12 )..day( 13 ), "Storm" );
+/// events.insert( day( 25 )..day( 26 ), "Return" );
+///
+/// let now = day( 12 ).and_hms( 8, 0, 0 );
+/// assert_eq!( events.at( now ).map( |( _, x )| *x ).collect::<Vec<_>>(), [ "Expedition", "Storm" ] );
+/// assert_eq!( events.overlapping( day( 15 )..day( 29 ) ).map( |( _, x )| *x ).collect::<Vec<_>>(), [ "Expedition", "Return" ] );
+/// assert_eq!( events.iter().map( |( _, x )| *x ).collect::<Vec<_>>(), [ "Expedition", "Storm", "Return" ] );
+/// ```
+///
+/// The entries are kept in a sorted `Vec`, so queries take O(log n + k) time for k matching entries, while a single [`insert`](Self::insert) takes O(n). To add many entries, use [`collect`](Iterator::collect) or [`extend`](Extend::extend), which sort all entries once.
+#[derive( Clone, PartialEq, Eq, Debug )]
+pub struct NormTimeMap<V> {
+	entries: Vec<( Range<NormTime>, V )>,
+
+	/// The maximum end of the ranges of all entries up to and including the same index. Being sorted, it allows skipping the entries that end before a query.
+	max_end: Vec<NormTime>,
+}
+
+impl<V> NormTimeMap<V> {
+	/// Creates an empty `NormTimeMap`.
+	pub const fn new() -> Self {
+		Self {
+			entries: Vec::new(),
+			max_end: Vec::new(),
+		}
+	}
+
+	/// Returns the number of entries.
+	pub fn len( &self ) -> usize {
+		self.entries.len()
+	}
+
+	/// Returns `true` if the map contains no entries.
+	pub fn is_empty( &self ) -> bool {
+		self.entries.is_empty()
+	}
+
+	/// Inserts `value` for the `range`. Since the following entries are shifted, this takes O(n) time.
+	pub fn insert( &mut self, range: Range<NormTime>, value: V ) {
+		let index = self.entries.partition_point( |( x, _ )| ( x.start, x.end ) <= ( range.start, range.end ) );
+		self.entries.insert( index, ( range, value ) );
+		self.max_end.insert( index, NormTime::default() );
+		self.update_max_end( index );
+	}
+
+	/// Retains only the entries for which `f` returns `true`.
+	pub fn retain<F: FnMut( &Range<NormTime>, &V ) -> bool>( &mut self, mut f: F ) {
+		self.entries.retain( |( x, y )| f( x, y ) );
+		self.max_end.truncate( self.entries.len() );
+		self.update_max_end( 0 );
+	}
+
+	/// Removes all entries.
+	pub fn clear( &mut self ) {
+		self.entries.clear();
+		self.max_end.clear();
+	}
+
+	/// Returns an iterator over all entries in chronological order.
+	pub fn iter( &self ) -> impl Iterator<Item = ( &Range<NormTime>, &V )> {
+		self.entries.iter().map( |( x, y )| ( x, y ) )
+	}
+
+	/// Returns an iterator over the entries whose range contains `time`, in chronological order.
+	pub fn at( &self, time: NormTime ) -> impl Iterator<Item = ( &Range<NormTime>, &V )> {
+		let first = self.max_end.partition_point( |x| *x <= time );
+		let last = self.entries.partition_point( |( x, _ )| x.start <= time );
+
+		self.entries[first..last.max( first )].iter()
+			.filter( move |( x, _ )| x.contains( &time ) )
+			.map( |( x, y )| ( x, y ) )
+	}
+
+	/// Returns an iterator over the entries whose range shares at least one instant with `range`, in chronological order.
+	pub fn overlapping( &self, range: Range<NormTime> ) -> impl Iterator<Item = ( &Range<NormTime>, &V )> {
+		let first = self.max_end.partition_point( |x| *x <= range.start );
+		let last = self.entries.partition_point( |( x, _ )| x.start < range.end );
+
+		self.entries[first..last.max( first )].iter()
+			.filter( move |( x, _ )| x.start < x.end && x.end > range.start && !range.is_empty() )
+			.map( |( x, y )| ( x, y ) )
+	}
+
+	/// Sorts the entries by their ranges and recalculates `max_end`. The sort is stable, keeping entries with equal ranges in the order of insertion.
+	fn sort( &mut self ) {
+		self.entries.sort_by_key( |( x, _ )| ( x.start, x.end ) );
+		self.max_end.resize( self.entries.len(), NormTime::default() );
+		self.update_max_end( 0 );
+	}
+
+	/// Recalculates `max_end` from `index` on.
+	fn update_max_end( &mut self, index: usize ) {
+		for i in index..self.entries.len() {
+			let end = self.entries[i].0.end;
+			self.max_end[i] = match i.checked_sub( 1 ) {
+				Some( prev ) => self.max_end[prev].max( end ),
+				None => end,
+			};
+		}
+	}
+}
+
+impl<V> Default for NormTimeMap<V> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<V> FromIterator<( Range<NormTime>, V )> for NormTimeMap<V> {
+	fn from_iter<I: IntoIterator<Item = ( Range<NormTime>, V )>>( iter: I ) -> Self {
+		let mut res = Self::new();
+		res.extend( iter );
+		res
+	}
+}
+
+/// Appends all entries before sorting once, which takes O(n log n) time instead of O(n) per entry.
+impl<V> Extend<( Range<NormTime>, V )> for NormTimeMap<V> {
+	fn extend<I: IntoIterator<Item = ( Range<NormTime>, V )>>( &mut self, iter: I ) {
+		self.entries.extend( iter );
+		self.sort();
+	}
+}
+
+impl<V> IntoIterator for NormTimeMap<V> {
+	type Item = ( Range<NormTime>, V );
+	type IntoIter = std::vec::IntoIter<( Range<NormTime>, V )>;
+
+	fn into_iter( self ) -> Self::IntoIter {
+		self.entries.into_iter()
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn queries() {
+		let t = NormTime;
+		let map: NormTimeMap<u32> = [
+			( t( 0 )..t( 100 ), 0 ),
+			( t( 10 )..t( 20 ), 1 ),
+			( t( 50 )..t( 50 ), 2 ),
+			( t( 30 )..t( 40 ), 3 ),
+			( t( 10 )..t( 20 ), 4 ),
+			( t( -10 )..t( 5 ), 5 ),
+		].into_iter().collect();
+
+		let values = |x: Vec<( &Range<NormTime>, &u32 )>| x.into_iter().map( |( _, y )| *y ).collect::<Vec<_>>();
+		assert_eq!( values( map.iter().collect() ), [ 5, 0, 1, 4, 3, 2 ] );
+		assert_eq!( values( map.at( t( 4 ) ).collect() ), [ 5, 0 ] );
+		assert_eq!( values( map.at( t( 20 ) ).collect() ), [ 0 ] );
+		assert_eq!( values( map.at( t( 50 ) ).collect() ), [ 0 ] );
+		assert_eq!( values( map.at( t( 100 ) ).collect() ), Vec::<u32>::new() );
+		assert_eq!( values( map.at( t( -20 ) ).collect() ), Vec::<u32>::new() );
+		assert_eq!( values( map.overlapping( t( 19 )..t( 31 ) ).collect() ), [ 0, 1, 4, 3 ] );
+		assert_eq!( values( map.overlapping( t( 20 )..t( 30 ) ).collect() ), [ 0 ] );
+		assert_eq!( values( map.overlapping( t( 20 )..t( 20 ) ).collect() ), Vec::<u32>::new() );
+		assert_eq!( values( map.overlapping( t( 100 )..t( 200 ) ).collect() ), Vec::<u32>::new() );
+
+		let mut map = map;
+		map.retain( |x, _| x.start != t( -10 ) );
+		assert_eq!( values( map.at( t( 4 ) ).collect() ), [ 0 ] );
+		assert_eq!( map.len(), 5 );
+
+		map.clear();
+		assert!( map.is_empty() );
+	}
+
+	#[test]
+	fn extend_keeps_order() {
+		let t = NormTime;
+		let mut extended = NormTimeMap::new();
+		let mut inserted = NormTimeMap::new();
+		let entries = [ ( t( 5 )..t( 8 ), 0 ), ( t( 0 )..t( 10 ), 1 ), ( t( 5 )..t( 8 ), 2 ), ( t( -3 )..t( 1 ), 3 ) ];
+
+		extended.insert( t( 5 )..t( 8 ), 4 );
+		inserted.insert( t( 5 )..t( 8 ), 4 );
+		extended.extend( entries.clone() );
+		for ( range, value ) in entries {
+			inserted.insert( range, value );
+		}
+
+		assert_eq!( extended, inserted );
+		assert_eq!( extended.iter().map( |( _, x )| *x ).collect::<Vec<_>>(), [ 3, 1, 4, 0, 2 ] );
+		assert_eq!( extended.at( t( 7 ) ).map( |( _, x )| *x ).collect::<Vec<_>>(), [ 1, 4, 0, 2 ] );
+	}
+}
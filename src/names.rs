@@ -0,0 +1,196 @@
+//! Names of the parts of the Normtime calendar.
+//!
+//! The names are used instead of numbers by [`NormTimeFormatter`](crate::NormTimeFormatter) and recognized by [`NormTime::parse_with`](crate::NormTime::parse_with), if configured. The default names can be replaced by the host application or, with the **`i18n`** feature enabled, taken from the fluent resources of a language.
+//!
+//! # Example
+//!
+//! ```
+//! use normtime::{NormTime, NormTimeFormatter, ParseOptions};
+//! use normtime::names::MonthNames;
+//!
+//! let names = MonthNames::new( [ "Aurora", "Bloom", "Crest", "Dusk", "Ember", "Frost", "Gale", "Haze", "Ice", "Jade" ] );
+//!
+//! let d = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+//! assert_eq!( names.of( d ), "Dusk" );
+//!
+//! let formatter = NormTimeFormatter::new().clock( false ).month_names( names.clone() );
+//! assert_eq!( formatter.format( d ), "0045-Dusk-12" );
+//!
+//! let options = ParseOptions {
+//!     month_names: Some( names ),
+//!     ..ParseOptions::new()
+//! };
+//! assert_eq!( NormTime::parse_with( "0045-dusk-12", &options ), Ok( d ) );
+//! ```
+
+
+
+
+//=============================================================================
+// Crates
+
+
+#[cfg( feature = "i18n" )] use fluent_templates::Loader;
+#[cfg( feature = "i18n" )] use unic_langid::LanguageIdentifier;
+
+use crate::NormTime;
+use crate::{DUR_NORMYEAR, DUR_NORMMONTH, DUR_NORMWEEK, DUR_NORMDAY};
+#[cfg( feature = "i18n" )] use crate::LOCALES;
+
+
+
+
+//=============================================================================
+// Normmonths
+
+
+/// The names of the 10 normmonths of a normyear. The default names are the latin ordinals `Primus` to `Decimus`.
+#[derive( Clone, PartialEq, Eq, Hash, Debug )]
+pub struct MonthNames( [String; 10] );
+
+impl MonthNames {
+	/// Creates the names from `names`, starting with normmonth 0.
+	pub fn new<S: Into<String>>( names: [S; 10] ) -> Self {
+		Self( names.map( Into::into ) )
+	}
+
+	/// Creates the names defined for `locale` by the fluent resources (keys `normmonth-name-0` to `normmonth-name-9`).
+	///
+	/// This method is only available, if the **`i18n`** feature has been enabled.
+	#[cfg( feature = "i18n" )]
+	pub fn from_locale( locale: &LanguageIdentifier ) -> Self {
+		Self( std::array::from_fn( |i| LOCALES.lookup( locale, &format!( "normmonth-name-{}", i ) ) ) )
+	}
+
+	/// Returns the name of `normmonth`. Returns `None` if `normmonth` is greater than 9.
+	pub fn name( &self, normmonth: u32 ) -> Option<&str> {
+		self.0.get( normmonth as usize ).map( String::as_str )
+	}
+
+	/// Returns the name of the normmonth of `time`.
+	pub fn of( &self, time: NormTime ) -> &str {
+		&self.0[ ( time.0.rem_euclid( DUR_NORMYEAR ) / DUR_NORMMONTH ) as usize ]
+	}
+
+	/// Returns the normmonth called `name`, ignoring case. Returns `None` if no normmonth is called `name`.
+	pub fn parse( &self, name: &str ) -> Option<u32> {
+		let name = name.to_lowercase();
+
+		self.0.iter()
+			.position( |x| x.to_lowercase() == name )
+			.map( |x| x as u32 )
+	}
+}
+
+impl Default for MonthNames {
+	fn default() -> Self {
+		Self::new( [ "Primus", "Secundus", "Tertius", "Quartus", "Quintus", "Sextus", "Septimus", "Octavus", "Nonus", "Decimus" ] )
+	}
+}
+
+
+
+
+//=============================================================================
+// Normdays of the normweek
+
+
+/// The names of the 10 normdays of a normweek. The default names are those of the days of the décade of the French Republican calendar, `Primidi` to `Decadi`.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{NormTime, NormTimeFormatter};
+/// use normtime::names::WeekdayNames;
+///
+/// let d = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+/// assert_eq!( WeekdayNames::default().of( d ), "Tridi" );
+///
+/// let formatter = NormTimeFormatter::new().weekday_names( WeekdayNames::default() );
+/// assert_eq!( formatter.format( d ), "Tridi 0045-03-12N00:00:00" );
+/// ```
+#[derive( Clone, PartialEq, Eq, Hash, Debug )]
+pub struct WeekdayNames( [String; 10] );
+
+impl WeekdayNames {
+	/// Creates the names from `names`, starting with normday 0 of the normweek.
+	pub fn new<S: Into<String>>( names: [S; 10] ) -> Self {
+		Self( names.map( Into::into ) )
+	}
+
+	/// Creates the names defined for `locale` by the fluent resources (keys `weekday-name-0` to `weekday-name-9`).
+	///
+	/// This method is only available, if the **`i18n`** feature has been enabled.
+	#[cfg( feature = "i18n" )]
+	pub fn from_locale( locale: &LanguageIdentifier ) -> Self {
+		Self( std::array::from_fn( |i| LOCALES.lookup( locale, &format!( "weekday-name-{}", i ) ) ) )
+	}
+
+	/// Returns the name of normday `weekday` of the normweek. Returns `None` if `weekday` is greater than 9.
+	pub fn name( &self, weekday: u32 ) -> Option<&str> {
+		self.0.get( weekday as usize ).map( String::as_str )
+	}
+
+	/// Returns the name of the normday of the normweek of `time`.
+	pub fn of( &self, time: NormTime ) -> &str {
+		&self.0[ ( time.0.rem_euclid( DUR_NORMWEEK ) / DUR_NORMDAY ) as usize ]
+	}
+
+	/// Returns the normday of the normweek called `name`, ignoring case. Returns `None` if no normday is called `name`.
+	pub fn parse( &self, name: &str ) -> Option<u32> {
+		let name = name.to_lowercase();
+
+		self.0.iter()
+			.position( |x| x.to_lowercase() == name )
+			.map( |x| x as u32 )
+	}
+}
+
+impl Default for WeekdayNames {
+	fn default() -> Self {
+		Self::new( [ "Primidi", "Duodi", "Tridi", "Quartidi", "Quintidi", "Sextidi", "Septidi", "Octidi", "Nonidi", "Decadi" ] )
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn month_names() {
+		let names = MonthNames::default();
+
+		assert_eq!( names.name( 0 ), Some( "Primus" ) );
+		assert_eq!( names.name( 10 ), None );
+		assert_eq!( names.of( NormTime( -1 ) ), "Decimus" );
+		assert_eq!( names.parse( "SEPTIMUS" ), Some( 6 ) );
+		assert_eq!( names.parse( "Undecimus" ), None );
+	}
+
+	#[test]
+	fn weekday_names() {
+		let names = WeekdayNames::default();
+
+		assert_eq!( names.of( NormTime( 0 ) ), "Primidi" );
+		assert_eq!( names.of( NormTime( -1 ) ), "Decadi" );
+		assert_eq!( names.parse( "decadi" ), Some( 9 ) );
+		assert_eq!( names.name( 10 ), None );
+	}
+
+	#[test]
+	#[cfg( feature = "i18n" )]
+	fn names_locale() {
+		use unic_langid::langid;
+
+		assert_eq!( MonthNames::from_locale( &langid!( "en-US" ) ), MonthNames::default() );
+		assert_eq!( MonthNames::from_locale( &langid!( "de-DE" ) ).name( 2 ), Some( "Tertius" ) );
+		assert_eq!( WeekdayNames::from_locale( &langid!( "en-US" ) ), WeekdayNames::default() );
+	}
+}
@@ -0,0 +1,295 @@
+//! Local Normtimes running offset from central Normtime.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use crate::{NormTime, TimeError};
+use crate::DUR_NORMDAY;
+
+
+
+
+//=============================================================================
+// Offset
+
+
+/// The offset of a local clock from central Normtime in seconds, e.g. of a habitat. Positive offsets are ahead of central Normtime. The offset is less than a normday (100 000 s) in both directions.
+///
+/// An offset is formatted as signed number of seconds, e.g. `+5000`.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormTimeOffset;
+///
+/// let offset = NormTimeOffset::east_opt( 5000 ).unwrap();
+/// assert_eq!( offset.to_string(), "+5000" );
+/// assert_eq!( "-5000".parse(), Ok( NormTimeOffset::east_opt( -5000 ).unwrap() ) );
+/// assert!( NormTimeOffset::east_opt( 100_000 ).is_none() );
+/// ```
+#[derive( Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Default, Debug )]
+pub struct NormTimeOffset( i32 );
+
+impl NormTimeOffset {
+	/// Central Normtime.
+	pub const CENTRAL: Self = Self( 0 );
+
+	/// Creates an offset of `secs` seconds ahead of central Normtime. Returns `None` if the offset is not less than a normday in both directions.
+	pub const fn east_opt( secs: i32 ) -> Option<Self> {
+		if secs as i64 <= -DUR_NORMDAY || secs as i64 >= DUR_NORMDAY {
+			return None;
+		}
+
+		Some( Self( secs ) )
+	}
+
+	/// Returns the seconds the offset is ahead of central Normtime.
+	pub const fn seconds( self ) -> i32 {
+		self.0
+	}
+}
+
+impl fmt::Display for NormTimeOffset {
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result {
+		f.pad( &format!( "{:+}", self.0 ) )
+	}
+}
+
+impl FromStr for NormTimeOffset {
+	type Err = TimeError;
+
+	/// Parses an offset like `+5000` or `-5000`. The sign is mandatory.
+	fn from_str( s: &str ) -> Result<Self, Self::Err> {
+		let error = || TimeError::ParseError( s.to_string() );
+
+		if !s.starts_with( [ '+', '-' ] ) {
+			return Err( error() );
+		}
+
+		Self::east_opt( s.parse()? ).ok_or_else( error )
+	}
+}
+
+
+
+
+//=============================================================================
+// Offset time
+
+
+/// A `NormTime` as shown by a clock running with an offset from central Normtime, analogous to `chrono::DateTime<FixedOffset>`.
+///
+/// Comparisons and hashing consider the represented instant only, so the same instant at different offsets is equal.
+///
+/// An `OffsetNormTime` is formatted as local time followed by the offset, e.g. `0045-03-12N12:00:00+5000`.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{NormTime, NormTimeOffset, OffsetNormTime};
+///
+/// let central = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 12, 0, 0 );
+/// let habitat = OffsetNormTime::new( central, NormTimeOffset::east_opt( 5000 ).unwrap() );
+///
+/// assert_eq!( habitat.local(), NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 13, 23, 20 ) );
+/// assert_eq!( habitat.to_string(), "0045-03-12N13:23:20+5000" );
+/// assert_eq!( "0045-03-12N13:23:20+5000".parse(), Ok( habitat ) );
+/// assert_eq!( habitat, OffsetNormTime::new( central, NormTimeOffset::CENTRAL ) );
+/// ```
+#[derive( Clone, Copy, Debug )]
+pub struct OffsetNormTime {
+	central: NormTime,
+	offset: NormTimeOffset,
+}
+
+impl OffsetNormTime {
+	/// Creates the local time of the instant `central` at `offset`.
+	pub const fn new( central: NormTime, offset: NormTimeOffset ) -> Self {
+		Self { central, offset }
+	}
+
+	/// Creates the instant shown as `local` by a clock at `offset`. Returns `None` if the instant is outside of the range of `NormTime`.
+	pub const fn from_local( local: NormTime, offset: NormTimeOffset ) -> Option<Self> {
+		match local.0.checked_sub( offset.0 as i64 ) {
+			Some( x ) => Some( Self::new( NormTime( x ), offset ) ),
+			None => None,
+		}
+	}
+
+	/// Returns the instant in central Normtime.
+	pub const fn central( self ) -> NormTime {
+		self.central
+	}
+
+	/// Returns the offset of the local clock.
+	pub const fn offset( self ) -> NormTimeOffset {
+		self.offset
+	}
+
+	/// Returns the time shown by the local clock. Times outside of the range of `NormTime` are saturated.
+	pub const fn local( self ) -> NormTime {
+		NormTime( self.central.0.saturating_add( self.offset.0 as i64 ) )
+	}
+
+	/// Returns the same instant as shown by a clock at `offset`.
+	pub const fn with_offset( self, offset: NormTimeOffset ) -> Self {
+		Self::new( self.central, offset )
+	}
+}
+
+impl PartialEq for OffsetNormTime {
+	fn eq( &self, other: &Self ) -> bool {
+		self.central == other.central
+	}
+}
+
+impl Eq for OffsetNormTime {}
+
+impl PartialOrd for OffsetNormTime {
+	fn partial_cmp( &self, other: &Self ) -> Option<Ordering> {
+		Some( self.cmp( other ) )
+	}
+}
+
+impl Ord for OffsetNormTime {
+	fn cmp( &self, other: &Self ) -> Ordering {
+		self.central.cmp( &other.central )
+	}
+}
+
+impl Hash for OffsetNormTime {
+	fn hash<H: Hasher>( &self, state: &mut H ) {
+		self.central.hash( state );
+	}
+}
+
+impl fmt::Display for OffsetNormTime {
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result {
+		f.pad( &format!( "{}{}", self.local(), self.offset ) )
+	}
+}
+
+/// Converting `OffsetNormTime` to the `NormTime` of the instant in central Normtime.
+impl From<OffsetNormTime> for NormTime {
+	fn from( item: OffsetNormTime ) -> Self {
+		item.central
+	}
+}
+
+impl FromStr for OffsetNormTime {
+	type Err = TimeError;
+
+	/// Parses a local time with the offset of its clock like `0045-03-12N13:23:20+5000`. The local time is parsed like a `NormTime` and must have a clock.
+	fn from_str( s: &str ) -> Result<Self, Self::Err> {
+		let error = || TimeError::ParseError( s.to_string() );
+
+		// The offset can only be found after the separator, since the date may have a sign.
+		let clock_start = s.find( 'N' ).ok_or_else( error )?;
+		let offset_start = s[ clock_start.. ].find( [ '+', '-' ] ).ok_or_else( error )? + clock_start;
+
+		let local: NormTime = s[ ..offset_start ].parse()?;
+		let offset: NormTimeOffset = s[ offset_start.. ].parse()?;
+
+		Self::from_local( local, offset ).ok_or_else( error )
+	}
+}
+
+
+#[cfg( feature = "serde" )]
+mod offsetnormtime_serde {
+	use super::OffsetNormTime;
+
+	use std::fmt;
+
+	impl serde::Serialize for OffsetNormTime {
+		fn serialize<S>( &self, serializer: S ) -> Result<S::Ok, S::Error>
+		where
+			S: serde::Serializer,
+		{
+			serializer.collect_str( self )
+		}
+	}
+
+	struct OffsetNormTimeVisitor;
+
+	impl<'de> serde::de::Visitor<'de> for OffsetNormTimeVisitor {
+		type Value = OffsetNormTime;
+
+		fn expecting( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+			formatter.write_str( "a formatted date string with offset" )
+		}
+
+		fn visit_str<E>( self, value: &str ) -> Result<Self::Value, E>
+		where
+			E: serde::de::Error,
+		{
+			value.parse().map_err( E::custom )
+		}
+	}
+
+	impl<'de> serde::Deserialize<'de> for OffsetNormTime {
+		fn deserialize<D>( deserializer: D ) -> Result<Self, D::Error>
+		where
+			D: serde::Deserializer<'de>,
+		{
+			deserializer.deserialize_str( OffsetNormTimeVisitor )
+		}
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	#[cfg( feature = "serde" )]
+	use serde_test::{Token, assert_tokens};
+
+	#[test]
+	fn parse_offset_normtime() {
+		let west = NormTimeOffset::east_opt( -5000 ).unwrap();
+		let t = OffsetNormTime::from_local( NormTime::from_ymd_opt( -1, 9, 29 ).unwrap(), west ).unwrap();
+		assert_eq!( t.central(), NormTime::from_ymd_opt( -1, 9, 29 ).unwrap().and_hms( 1, 23, 20 ) );
+		assert_eq!( t.to_string(), "-0001-09-29N00:00:00-5000" );
+		assert_eq!( t.to_string().parse::<OffsetNormTime>().map( |x| x.offset() ), Ok( west ) );
+
+		assert!( "0045-03-12N13:23:20".parse::<OffsetNormTime>().is_err() );
+		assert!( "0045-03-12N13:23:20+100000".parse::<OffsetNormTime>().is_err() );
+		assert!( "0045-03-12N13:23:20 5000".parse::<OffsetNormTime>().is_err() );
+		assert!( "5000".parse::<NormTimeOffset>().is_err() );
+	}
+
+	#[test]
+	fn compare_instants() {
+		let t = NormTime( 0 );
+		let a = OffsetNormTime::new( t, NormTimeOffset::east_opt( 1 ).unwrap() );
+		let b = OffsetNormTime::new( NormTime( 1 ), NormTimeOffset::east_opt( -1 ).unwrap() );
+
+		assert!( a < b );
+		assert!( a.local() > b.local() );
+		assert_eq!( a.with_offset( NormTimeOffset::CENTRAL ).local(), t );
+		assert_eq!( OffsetNormTime::new( NormTime( i64::MAX ), NormTimeOffset::east_opt( 1 ).unwrap() ).local(), NormTime( i64::MAX ) );
+	}
+
+	#[test]
+	#[cfg( feature = "serde" )]
+	fn test_serialize_deserialize() {
+		let t = OffsetNormTime::new( NormTime( 0 ), NormTimeOffset::east_opt( 5000 ).unwrap() );
+		assert_tokens( &t, &[ Token::Str( "0000-00-00N01:23:20+5000" ) ] );
+	}
+}
@@ -0,0 +1,354 @@
+//! Durations expressed in calendar components instead of seconds.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use std::fmt;
+use std::ops::{Add, Neg, Sub};
+use std::str::FromStr;
+
+use crate::{DUR_HOUR, DUR_MINUTE};
+use crate::{ConversionError, NormBreakdown, NormTime, NormTimeDelta, Unit};
+
+
+
+
+//=============================================================================
+// Period
+
+
+/// A duration held as separate normyears, normmonths, normweeks, normdays and seconds instead of being collapsed into seconds like [`NormTimeDelta`].
+///
+/// "1 normmonth" stays "1 normmonth" and is not equal to "30 normdays", although both have the same length. Comparison is structural, component by component. Use [`NormPeriod::to_delta`] to compare absolute durations.
+///
+/// `NormPeriod` is written as the non-zero components followed by their unit symbols, e.g. `"1y 2m 3d"`. Parsing accepts the same format and additionally the symbols `h` and `min`, which are added to the seconds. With the **`serde`** feature enabled, this string is also used for serialization. See [`serde::period_struct`](crate::serde::period_struct) for a struct representation.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{NormPeriod, NormTimeDelta};
+///
+/// let period = NormPeriod { years: 1, months: 2, days: 3, ..NormPeriod::ZERO };
+/// assert_eq!( period.to_string(), "1y 2m 3d" );
+/// assert_eq!( "1y 2m 3d".parse::<NormPeriod>().unwrap(), period );
+///
+/// assert_ne!( NormPeriod::new_months( 1 ), NormPeriod::new_days( 30 ) );
+/// assert_eq!( NormPeriod::new_months( 1 ).to_delta(), NormPeriod::new_days( 30 ).to_delta() );
+/// ```
+#[derive( Clone, Copy, PartialEq, Eq, Hash, Default, Debug )]
+pub struct NormPeriod {
+	/// The normyears.
+	pub years: i64,
+
+	/// The normmonths.
+	pub months: i64,
+
+	/// The normweeks.
+	pub weeks: i64,
+
+	/// The normdays.
+	pub days: i64,
+
+	/// The seconds, including hours and minutes.
+	pub seconds: i64,
+}
+
+impl NormPeriod {
+	/// A period without any component.
+	pub const ZERO: Self = Self::new( 0, 0, 0, 0, 0 );
+
+	/// Creates a new `NormPeriod` from all of its components.
+	pub const fn new( years: i64, months: i64, weeks: i64, days: i64, seconds: i64 ) -> Self {
+		Self { years, months, weeks, days, seconds }
+	}
+
+	/// Creates a new `NormPeriod` of `years` normyears.
+	pub const fn new_years( years: i64 ) -> Self {
+		Self { years, ..Self::ZERO }
+	}
+
+	/// Creates a new `NormPeriod` of `months` normmonths.
+	pub const fn new_months( months: i64 ) -> Self {
+		Self { months, ..Self::ZERO }
+	}
+
+	/// Creates a new `NormPeriod` of `weeks` normweeks.
+	pub const fn new_weeks( weeks: i64 ) -> Self {
+		Self { weeks, ..Self::ZERO }
+	}
+
+	/// Creates a new `NormPeriod` of `days` normdays.
+	pub const fn new_days( days: i64 ) -> Self {
+		Self { days, ..Self::ZERO }
+	}
+
+	/// Creates a new `NormPeriod` of `seconds` seconds.
+	pub const fn new_seconds( seconds: i64 ) -> Self {
+		Self { seconds, ..Self::ZERO }
+	}
+
+	/// Returns the normalized period leading from `a` to `b`, i.e. `a + NormPeriod::between( a, b ) == b` as long as none of the steps of the addition overflows. The period consists of normyears, normmonths within the normyear, normdays within the normmonth and seconds within the normday, all sharing the sign of `b - a`. Normweeks are not used.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormPeriod, NormTime};
+	///
+	/// let a = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+	/// let b = NormTime::from_ymd_opt( 47, 6, 17 ).unwrap().and_hms( 8, 0, 0 );
+	///
+	/// let period = NormPeriod::between( a, b );
+	/// assert_eq!( period, NormPeriod::new( 2, 3, 0, 5, 28_800 ) );
+	/// assert_eq!( a + period, b );
+	/// assert_eq!( NormPeriod::between( b, a ), -period );
+	/// ```
+	pub const fn between( a: NormTime, b: NormTime ) -> Self {
+		Self::from_breakdown( b.since( a ) )
+	}
+
+	/// Returns the period of the components of `breakdown`, adding hours and minutes to the seconds.
+	const fn from_breakdown( breakdown: NormBreakdown ) -> Self {
+		Self {
+			years: breakdown.years,
+			months: breakdown.months,
+			weeks: 0,
+			days: breakdown.days,
+			seconds: breakdown.hours * DUR_HOUR + breakdown.minutes * DUR_MINUTE + breakdown.seconds,
+		}
+	}
+
+	/// Returns `true` if all components are zero.
+	pub const fn is_zero( &self ) -> bool {
+		self.years == 0 && self.months == 0 && self.weeks == 0 && self.days == 0 && self.seconds == 0
+	}
+
+	/// Returns the components together with their units, ordered from largest to smallest.
+	pub const fn components( &self ) -> [( i64, Unit ); 5] {
+		[
+			( self.years, Unit::Year ),
+			( self.months, Unit::Month ),
+			( self.weeks, Unit::Week ),
+			( self.days, Unit::Day ),
+			( self.seconds, Unit::Second ),
+		]
+	}
+
+	/// Returns the absolute duration of `self`. Returns `None` if the duration cannot be represented by a `NormTimeDelta`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormPeriod, NormTimeDelta};
+	///
+	/// assert_eq!( NormPeriod::new( 1, 0, 0, 2, 0 ).to_delta(), Some( NormTimeDelta::new_years( 1 ) + NormTimeDelta::new_days( 2 ) ) );
+	/// assert_eq!( NormPeriod::new_years( i64::MAX ).to_delta(), None );
+	/// ```
+	pub fn to_delta( &self ) -> Option<NormTimeDelta> {
+		let secs = self.components().into_iter()
+			.try_fold( 0_i64, |acc, ( x, unit )| acc.checked_add( x.checked_mul( unit.seconds() )? ) )?;
+
+		NormTimeDelta::new( secs, 0 )
+	}
+
+	/// Adds `rhs` to `self` component by component. Returns `None` on overflow.
+	pub const fn checked_add( &self, rhs: &Self ) -> Option<Self> {
+		match (
+			self.years.checked_add( rhs.years ),
+			self.months.checked_add( rhs.months ),
+			self.weeks.checked_add( rhs.weeks ),
+			self.days.checked_add( rhs.days ),
+			self.seconds.checked_add( rhs.seconds ),
+		) {
+			( Some( years ), Some( months ), Some( weeks ), Some( days ), Some( seconds ) ) => Some( Self { years, months, weeks, days, seconds } ),
+			_ => None,
+		}
+	}
+
+	/// Subtracts `rhs` from `self` component by component. Returns `None` on overflow.
+	pub const fn checked_sub( &self, rhs: &Self ) -> Option<Self> {
+		match (
+			self.years.checked_sub( rhs.years ),
+			self.months.checked_sub( rhs.months ),
+			self.weeks.checked_sub( rhs.weeks ),
+			self.days.checked_sub( rhs.days ),
+			self.seconds.checked_sub( rhs.seconds ),
+		) {
+			( Some( years ), Some( months ), Some( weeks ), Some( days ), Some( seconds ) ) => Some( Self { years, months, weeks, days, seconds } ),
+			_ => None,
+		}
+	}
+}
+
+impl From<NormBreakdown> for NormPeriod {
+	fn from( value: NormBreakdown ) -> Self {
+		Self::from_breakdown( value )
+	}
+}
+
+impl Add for NormPeriod {
+	type Output = Self;
+
+	fn add( self, rhs: Self ) -> Self::Output {
+		self.checked_add( &rhs ).expect( "Overflow in `NormPeriod + NormPeriod`" )
+	}
+}
+
+impl Sub for NormPeriod {
+	type Output = Self;
+
+	fn sub( self, rhs: Self ) -> Self::Output {
+		self.checked_sub( &rhs ).expect( "Overflow in `NormPeriod - NormPeriod`" )
+	}
+}
+
+impl Neg for NormPeriod {
+	type Output = Self;
+
+	fn neg( self ) -> Self::Output {
+		Self::ZERO - self
+	}
+}
+
+forward_ref_binop!( impl Add, add for NormPeriod, NormPeriod );
+forward_ref_binop!( impl Sub, sub for NormPeriod, NormPeriod );
+
+/// Writes the non-zero components followed by their unit symbols, e.g. `"1y -2d"`. A period of zero is written as `"0s"`.
+impl fmt::Display for NormPeriod {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		if self.is_zero() {
+			return f.pad( "0s" );
+		}
+
+		let res = self.components().into_iter()
+			.filter( |( x, _ )| *x != 0 )
+			.map( |( x, unit )| format!( "{}{}", x, unit.as_sym() ) )
+			.collect::<Vec<_>>()
+			.join( " " );
+
+		f.pad( &res )
+	}
+}
+
+/// Parses the output of `Display`, e.g. `"1y 2m 3d"`. Each component is a possibly negative integer directly followed by a unit symbol (`y`, `m`, `w`, `d`, `h`, `min` or `s`), components are separated by whitespace. Hours and minutes are added to the seconds, repeated units are summed up.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormPeriod;
+///
+/// assert_eq!( "2w -1d 8h".parse::<NormPeriod>().unwrap(), NormPeriod::new( 0, 0, 2, -1, 28_800 ) );
+/// assert!( "2 w".parse::<NormPeriod>().is_err() );
+/// assert!( "".parse::<NormPeriod>().is_err() );
+/// ```
+impl FromStr for NormPeriod {
+	type Err = ConversionError;
+
+	fn from_str( s: &str ) -> Result<Self, Self::Err> {
+		let error = || ConversionError::PeriodParseError( s.to_string() );
+
+		let mut res = Self::ZERO;
+		let mut found = false;
+		for elem in s.split_whitespace() {
+			let pos = elem.find( |c: char| !c.is_ascii_digit() && c != '-' ).ok_or_else( error )?;
+			let number = elem[..pos].parse::<i64>().map_err( |_| error() )?;
+			let unit = Unit::from_sym( &elem[pos..] ).ok_or_else( error )?;
+
+			let component = match unit {
+				Unit::Year => Self::new_years( number ),
+				Unit::Month => Self::new_months( number ),
+				Unit::Week => Self::new_weeks( number ),
+				Unit::Day => Self::new_days( number ),
+				Unit::Hour | Unit::Minute | Unit::Second => Self::new_seconds( number.checked_mul( unit.seconds() ).ok_or_else( error )? ),
+			};
+			res = res.checked_add( &component ).ok_or_else( error )?;
+			found = true;
+		}
+
+		if !found {
+			return Err( error() );
+		}
+
+		Ok( res )
+	}
+}
+
+
+
+#[cfg( feature = "serde" )]
+mod period_serde {
+	use super::NormPeriod;
+
+	impl serde::Serialize for NormPeriod {
+		fn serialize<S>( &self, serializer: S ) -> Result<S::Ok, S::Error>
+		where
+			S: serde::Serializer,
+		{
+			crate::serde::period_string::serialize( self, serializer )
+		}
+	}
+
+	impl<'de> serde::Deserialize<'de> for NormPeriod {
+		fn deserialize<D>( deserializer: D ) -> Result<Self, D::Error>
+		where
+			D: serde::Deserializer<'de>,
+		{
+			crate::serde::period_string::deserialize( deserializer )
+		}
+	}
+}
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn string_round_trip() {
+		for period in [
+			NormPeriod::ZERO,
+			NormPeriod::new( 1, 2, 3, 4, 5 ),
+			NormPeriod::new( -1, 0, 0, 0, 100_000 ),
+			NormPeriod::new_seconds( i64::MIN ),
+		] {
+			assert_eq!( period.to_string().parse::<NormPeriod>().unwrap(), period );
+		}
+
+		assert_eq!( NormPeriod::new( -1, 0, 0, 2, 0 ).to_string(), "-1y 2d" );
+		assert_eq!( format!( "{:>6}|", NormPeriod::new_days( 3 ) ), "    3d|" );
+		assert_eq!( "1d 1d".parse::<NormPeriod>().unwrap(), NormPeriod::new_days( 2 ) );
+		assert_eq!( "1h 1min".parse::<NormPeriod>().unwrap(), NormPeriod::new_seconds( 3_660 ) );
+		assert!( "1x".parse::<NormPeriod>().is_err() );
+		assert!( "d".parse::<NormPeriod>().is_err() );
+		assert!( "9223372036854775807h".parse::<NormPeriod>().is_err() );
+	}
+
+	#[test]
+	fn between() {
+		let ( a, b ) = ( NormTime( i64::MIN / 2 ), NormTime( i64::MAX / 2 ) );
+		assert_eq!( a.checked_add_period( &NormPeriod::between( a, b ) ), Some( b ) );
+		assert_eq!( b.checked_add_period( &NormPeriod::between( b, a ) ), Some( a ) );
+		assert_eq!( NormPeriod::between( a, a ), NormPeriod::ZERO );
+		assert_eq!( NormPeriod::between( NormTime( 0 ), NormTime( 99_999 ) ), NormPeriod::new_seconds( 99_999 ) );
+	}
+
+	#[test]
+	#[allow( clippy::op_ref )]
+	fn arithmetic() {
+		let a = NormPeriod::new( 1, 2, 3, 4, 5 );
+		assert_eq!( a + a, NormPeriod::new( 2, 4, 6, 8, 10 ) );
+		assert_eq!( a - a, NormPeriod::ZERO );
+		assert_eq!( -a, NormPeriod::new( -1, -2, -3, -4, -5 ) );
+		assert_eq!( &a + &a, a + a );
+		assert_eq!( NormPeriod::new_years( i64::MAX ).checked_add( &NormPeriod::new_years( 1 ) ), None );
+		assert_eq!( a.to_delta(), Some( NormTimeDelta::new_seconds( 30_000_000 + 6_000_000 + 3_000_000 + 400_000 + 5 ) ) );
+	}
+}
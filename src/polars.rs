@@ -0,0 +1,94 @@
+//! Conversion of Normtime data into and from Polars series.
+//!
+//! `NormTime`s are stored as `Int64` Unix timestamps, so the series can be combined with any other timestamp column.
+//!
+//! This module is only available, if the **`polars`** feature has been enabled.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use polars::prelude::{Expr, PolarsResult, Series, NamedFrom, lit};
+
+use crate::{NormTime, NORMTIME_OFFSET, DUR_NORMYEAR, DUR_NORMMONTH};
+
+
+
+
+//=============================================================================
+// Series
+
+
+/// Returns an `Int64` series called `name` holding the Unix timestamps of `items`.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormTime;
+/// use normtime::polars::{normtimes_to_series, series_to_normtimes};
+///
+/// let times = [ NormTime::from_ymd_opt( 1, 2, 3 ).unwrap(), NormTime::from_ymd_opt( -1, 0, 0 ).unwrap() ];
+/// let series = normtimes_to_series( "time", &times );
+///
+/// assert_eq!( series.len(), 2 );
+/// assert_eq!( series_to_normtimes( &series ).unwrap(), vec![ Some( times[0] ), Some( times[1] ) ] );
+/// ```
+pub fn normtimes_to_series( name: &str, items: &[NormTime] ) -> Series {
+	let timestamps: Vec<i64> = items.iter()
+		.map( |x| x.timestamp() )
+		.collect();
+
+	Series::new( name.into(), timestamps )
+}
+
+/// Returns the `NormTime`s stored as Unix timestamps in `series`. Null entries and the earliest timestamps, which overflow when shifted to the zero time of Normtime (see [`NormTime::from_timestamp`]), are returned as `None`.
+///
+/// Returns an error if `series` is not of type `Int64`.
+pub fn series_to_normtimes( series: &Series ) -> PolarsResult<Vec<Option<NormTime>>> {
+	let res = series.i64()?.iter()
+		.map( |x| x.and_then( NormTime::from_timestamp ) )
+		.collect();
+
+	Ok( res )
+}
+
+
+
+
+//=============================================================================
+// Expressions
+
+
+/// Returns an expression extracting the normyear from `expr`, which has to evaluate to Unix timestamps.
+///
+/// # Example
+///
+/// ```
+/// use polars::prelude::{DataFrame, IntoLazy, col};
+/// use normtime::NormTime;
+/// use normtime::polars::{normtimes_to_series, normyear, normmonth};
+///
+/// let times = [ NormTime::from_ymd_opt( 12, 3, 4 ).unwrap(), NormTime::from_ymd_opt( -1, 9, 29 ).unwrap() ];
+/// let df = DataFrame::new( vec![ normtimes_to_series( "time", &times ).into() ] ).unwrap();
+///
+/// let res = df.lazy()
+///     .select( [ normyear( col( "time" ) ).alias( "year" ), normmonth( col( "time" ) ).alias( "month" ) ] )
+///     .collect()
+///     .unwrap();
+///
+/// assert_eq!( res.column( "year" ).unwrap().i64().unwrap().to_vec(), vec![ Some( 12 ), Some( -1 ) ] );
+/// assert_eq!( res.column( "month" ).unwrap().i64().unwrap().to_vec(), vec![ Some( 3 ), Some( 9 ) ] );
+/// ```
+pub fn normyear( expr: Expr ) -> Expr {
+	( expr - lit( NORMTIME_OFFSET ) ).floor_div( lit( DUR_NORMYEAR ) )
+}
+
+/// Returns an expression extracting the normmonth (0 to 9) from `expr`, which has to evaluate to Unix timestamps.
+pub fn normmonth( expr: Expr ) -> Expr {
+	let months = ( expr.clone() - lit( NORMTIME_OFFSET ) ).floor_div( lit( DUR_NORMMONTH ) );
+
+	months - normyear( expr ) * lit( DUR_NORMYEAR / DUR_NORMMONTH )
+}
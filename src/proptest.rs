@@ -0,0 +1,126 @@
+//! Strategies generating valid `NormTime`s, `NormTimeDelta`s and `Unit`s for property testing with `proptest`.
+//!
+//! All types implement `Arbitrary`, so `any::<NormTime>()` generates values from the whole range of the respective type. Values between two bounds are generated by [`normtime_between`] and [`normtimedelta_between`].
+//!
+//! # Example
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use normtime::{NormTime, NormTimeDelta};
+//! use normtime::proptest::normtimedelta_between;
+//!
+//! proptest!( |( time in any::<NormTime>(), delta in normtimedelta_between( NormTimeDelta::ZERO, NormTimeDelta::new_days( 1 ) ) )| {
+//!     prop_assume!( time <= NormTime::from_ymd_opt( 1000, 0, 0 ).unwrap() );
+//!     prop_assert!( time + delta >= time );
+//! } );
+//! ```
+//!
+//! This module is only available, if the **`proptest`** feature has been enabled.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use proptest::prelude::*;
+use proptest::strategy::BoxedStrategy;
+
+use crate::{NormTime, NormTimeDelta, Unit, NORMTIME_OFFSET};
+
+
+
+
+//=============================================================================
+// Strategies
+
+
+/// Returns a strategy generating `NormTime`s between `start` and `stop` (inclusive). Values shrink towards `start`.
+pub fn normtime_between( start: NormTime, stop: NormTime ) -> BoxedStrategy<NormTime> {
+	( start.0..=stop.0 ).prop_map( NormTime ).boxed()
+}
+
+/// Returns a strategy generating `NormTimeDelta`s with nanosecond resolution between `low` and `high` (inclusive).
+pub fn normtimedelta_between( low: NormTimeDelta, high: NormTimeDelta ) -> BoxedStrategy<NormTimeDelta> {
+	( low.total_nanos()..=high.total_nanos() ).prop_map( NormTimeDelta::from_total_nanos ).boxed()
+}
+
+
+
+
+//=============================================================================
+// Arbitrary
+
+
+/// Generates `NormTime`s from the whole range, whose Unix timestamp can be represented by an `i64`.
+impl Arbitrary for NormTime {
+	type Parameters = ();
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with( _args: Self::Parameters ) -> Self::Strategy {
+		( i64::MIN..=i64::MAX - NORMTIME_OFFSET ).prop_map( NormTime ).boxed()
+	}
+}
+
+/// Generates `NormTimeDelta`s from the whole range of `-i64::MAX` to `i64::MAX` milliseconds.
+impl Arbitrary for NormTimeDelta {
+	type Parameters = ();
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with( _args: Self::Parameters ) -> Self::Strategy {
+		normtimedelta_between( NormTimeDelta::MIN, NormTimeDelta::MAX )
+	}
+}
+
+impl Arbitrary for Unit {
+	type Parameters = ();
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with( _args: Self::Parameters ) -> Self::Strategy {
+		prop_oneof![
+			Just( Unit::Year ),
+			Just( Unit::Month ),
+			Just( Unit::Week ),
+			Just( Unit::Day ),
+			Just( Unit::Hour ),
+			Just( Unit::Minute ),
+			Just( Unit::Second ),
+		].boxed()
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	proptest! {
+		#[test]
+		fn valid_normtime( time in any::<NormTime>() ) {
+			prop_assert!( time.0 <= i64::MAX - NORMTIME_OFFSET );
+		}
+
+		#[test]
+		fn valid_normtimedelta( delta in any::<NormTimeDelta>() ) {
+			prop_assert!( NormTimeDelta::new( delta.secs, delta.nanos as u32 ).is_some() );
+		}
+
+		#[test]
+		fn bounded( time in normtime_between( NormTime( -5 ), NormTime( 5 ) ), delta in normtimedelta_between( NormTimeDelta::new( -1, 500 ).unwrap(), NormTimeDelta::new_seconds( 1 ) ) ) {
+			prop_assert!( time >= NormTime( -5 ) && time <= NormTime( 5 ) );
+			prop_assert!( delta >= NormTimeDelta::new( -1, 500 ).unwrap() && delta <= NormTimeDelta::new_seconds( 1 ) );
+		}
+
+		#[test]
+		fn unit_roundtrip( unit in any::<Unit>() ) {
+			prop_assert_eq!( unit.to_string().parse::<Unit>().unwrap(), unit );
+		}
+	}
+}
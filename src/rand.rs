@@ -0,0 +1,180 @@
+//! Sampling of random `NormTime`s and `NormTimeDelta`s using `rand`.
+//!
+//! `StandardUniform` samples from the whole range of the respective type. To sample uniformly between two bounds, use `Rng::random_range()` with a range of `NormTime`s or `NormTimeDelta`s. Since Normtime has no dedicated range type, the bounds are given as the standard ranges `start..stop` and `start..=stop`.
+//!
+//! # Example
+//!
+//! ```
+//! use rand::Rng;
+//! use normtime::{NormTime, NormTimeDelta};
+//!
+//! let mut rng = rand::rng();
+//!
+//! let start = NormTime::from_ymd_opt( 45, 0, 0 ).unwrap();
+//! let stop = NormTime::from_ymd_opt( 46, 0, 0 ).unwrap();
+//! let event: NormTime = rng.random_range( start..stop );
+//! assert!( event >= start && event < stop );
+//!
+//! let delay: NormTimeDelta = rng.random_range( NormTimeDelta::ZERO..=NormTimeDelta::new_hours( 2 ) );
+//! assert!( delay <= NormTimeDelta::new_hours( 2 ) );
+//! ```
+//!
+//! This module is only available, if the **`rand`** feature has been enabled.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use rand::Rng;
+use rand::distr::{Distribution, StandardUniform};
+use rand::distr::uniform::{Error, SampleBorrow, SampleUniform, UniformInt, UniformSampler};
+
+use crate::{NormTime, NormTimeDelta, NORMTIME_OFFSET};
+
+
+
+
+//=============================================================================
+// NormTime
+
+
+/// Samples a `NormTime` from the whole range, whose Unix timestamp can be represented by an `i64`.
+impl Distribution<NormTime> for StandardUniform {
+	fn sample<R: Rng + ?Sized>( &self, rng: &mut R ) -> NormTime {
+		NormTime( rng.random_range( i64::MIN..=i64::MAX - NORMTIME_OFFSET ) )
+	}
+}
+
+/// The back-end sampling `NormTime`s uniformly between two bounds.
+#[derive( Clone, Copy, PartialEq, Debug )]
+pub struct UniformNormTime( UniformInt<i64> );
+
+impl UniformSampler for UniformNormTime {
+	type X = NormTime;
+
+	fn new<B1, B2>( low: B1, high: B2 ) -> Result<Self, Error>
+	where
+		B1: SampleBorrow<Self::X> + Sized,
+		B2: SampleBorrow<Self::X> + Sized,
+	{
+		UniformInt::new( low.borrow().0, high.borrow().0 ).map( Self )
+	}
+
+	fn new_inclusive<B1, B2>( low: B1, high: B2 ) -> Result<Self, Error>
+	where
+		B1: SampleBorrow<Self::X> + Sized,
+		B2: SampleBorrow<Self::X> + Sized,
+	{
+		UniformInt::new_inclusive( low.borrow().0, high.borrow().0 ).map( Self )
+	}
+
+	fn sample<R: Rng + ?Sized>( &self, rng: &mut R ) -> Self::X {
+		NormTime( self.0.sample( rng ) )
+	}
+}
+
+impl SampleUniform for NormTime {
+	type Sampler = UniformNormTime;
+}
+
+
+
+
+//=============================================================================
+// NormTimeDelta
+
+
+/// Samples a `NormTimeDelta` with nanosecond resolution from the whole range of `-i64::MAX` to `i64::MAX` milliseconds.
+impl Distribution<NormTimeDelta> for StandardUniform {
+	fn sample<R: Rng + ?Sized>( &self, rng: &mut R ) -> NormTimeDelta {
+		let nanos = rng.random_range( NormTimeDelta::MIN.total_nanos()..=NormTimeDelta::MAX.total_nanos() );
+
+		NormTimeDelta::from_total_nanos( nanos )
+	}
+}
+
+/// The back-end sampling `NormTimeDelta`s uniformly between two bounds with nanosecond resolution.
+#[derive( Clone, Copy, PartialEq, Debug )]
+pub struct UniformNormTimeDelta( UniformInt<i128> );
+
+impl UniformSampler for UniformNormTimeDelta {
+	type X = NormTimeDelta;
+
+	fn new<B1, B2>( low: B1, high: B2 ) -> Result<Self, Error>
+	where
+		B1: SampleBorrow<Self::X> + Sized,
+		B2: SampleBorrow<Self::X> + Sized,
+	{
+		UniformInt::new( low.borrow().total_nanos(), high.borrow().total_nanos() ).map( Self )
+	}
+
+	fn new_inclusive<B1, B2>( low: B1, high: B2 ) -> Result<Self, Error>
+	where
+		B1: SampleBorrow<Self::X> + Sized,
+		B2: SampleBorrow<Self::X> + Sized,
+	{
+		UniformInt::new_inclusive( low.borrow().total_nanos(), high.borrow().total_nanos() ).map( Self )
+	}
+
+	fn sample<R: Rng + ?Sized>( &self, rng: &mut R ) -> Self::X {
+		NormTimeDelta::from_total_nanos( self.0.sample( rng ) )
+	}
+}
+
+impl SampleUniform for NormTimeDelta {
+	type Sampler = UniformNormTimeDelta;
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	use rand::SeedableRng;
+	use rand::rngs::StdRng;
+
+	#[test]
+	fn sample_normtime() {
+		let mut rng = StdRng::seed_from_u64( 42 );
+
+		let start = NormTime::from_ymd_opt( 45, 0, 0 ).unwrap();
+		let stop = NormTime::from_ymd_opt( 45, 0, 1 ).unwrap();
+		for _ in 0..100 {
+			let time: NormTime = rng.random_range( start..stop );
+			assert!( time >= start && time < stop );
+
+			let time: NormTime = rng.random();
+			assert!( time.timestamp() >= i64::MIN + NORMTIME_OFFSET );
+		}
+
+		assert_eq!( rng.random_range( start..=start ), start );
+	}
+
+	#[test]
+	fn sample_normtimedelta() {
+		let mut rng = StdRng::seed_from_u64( 42 );
+
+		let low = NormTimeDelta::new( -1, 500 ).unwrap();
+		let high = NormTimeDelta::new( 1, 0 ).unwrap();
+		for _ in 0..100 {
+			let delta: NormTimeDelta = rng.random_range( low..high );
+			assert!( delta >= low && delta < high );
+
+			let delta: NormTimeDelta = rng.random();
+			assert!( delta >= NormTimeDelta::MIN && delta <= NormTimeDelta::MAX );
+			assert!( NormTimeDelta::new( delta.secs, delta.nanos as u32 ).is_some() );
+		}
+
+		assert_eq!( NormTimeDelta::from_total_nanos( -1 ).total_nanos(), -1 );
+		assert_eq!( NormTimeDelta::from_total_nanos( NormTimeDelta::MIN.total_nanos() ), NormTimeDelta::MIN );
+	}
+}
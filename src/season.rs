@@ -0,0 +1,261 @@
+//! Dividing the normyear into seasons and normquarters.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+#[cfg( feature = "i18n" )] use fluent_templates::Loader;
+#[cfg( feature = "i18n" )] use unic_langid::LanguageIdentifier;
+
+use crate::{NormTime, NormTimeDelta};
+use crate::{DUR_NORMYEAR, DUR_NORMMONTH};
+#[cfg( feature = "i18n" )] use crate::LOCALES;
+
+
+
+
+//=============================================================================
+// Division
+
+
+/// A division of the normyear into consecutive seasons of whole normmonths. The first season starts with the normyear.
+///
+/// The default division consists of 5 seasons of 2 normmonths each.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{NormSeasons, NormTime};
+///
+/// // A long winter and a short summer.
+/// let seasons = NormSeasons::new( &[ 4, 2, 1, 3 ] ).unwrap();
+///
+/// let d = NormTime::from_ymd_opt( 45, 6, 12 ).unwrap();
+/// let season = seasons.season( d );
+/// assert_eq!( season.index(), 2 );
+/// assert_eq!( season.start(), NormTime::from_ymd_opt( 45, 6, 0 ).unwrap() );
+/// assert_eq!( season.end(), NormTime::from_ymd_opt( 45, 7, 0 ).unwrap() );
+///
+/// assert_eq!( seasons.of_year( 45 ).count(), 4 );
+/// assert!( NormSeasons::new( &[ 4, 4 ] ).is_none() );
+/// ```
+#[derive( Clone, PartialEq, Eq, Hash, Debug )]
+pub struct NormSeasons {
+	/// The normmonth each season starts with, followed by the number of normmonths of a normyear.
+	bounds: Vec<u32>,
+}
+
+impl NormSeasons {
+	/// The number of normmonths of a normyear.
+	const MONTHS: u32 = ( DUR_NORMYEAR / DUR_NORMMONTH ) as u32;
+
+	/// Creates a division into seasons with the numbers of normmonths given by `lengths`. Returns `None` if one of the lengths is 0 or the lengths do not add up to the 10 normmonths of a normyear.
+	pub fn new( lengths: &[u32] ) -> Option<Self> {
+		let mut bounds: Vec<u32> = vec![ 0 ];
+
+		for &len in lengths {
+			if len == 0 {
+				return None;
+			}
+			bounds.push( bounds.last()?.checked_add( len )? );
+		}
+
+		if bounds.last() != Some( &Self::MONTHS ) {
+			return None;
+		}
+
+		Some( Self { bounds } )
+	}
+
+	/// Returns the number of seasons per normyear.
+	pub fn count( &self ) -> usize {
+		self.bounds.len() - 1
+	}
+
+	/// Returns the season `time` is part of.
+	pub fn season( &self, time: NormTime ) -> NormSeason {
+		let month = ( time.0.rem_euclid( DUR_NORMYEAR ) / DUR_NORMMONTH ) as u32;
+		let index = self.bounds.partition_point( |&x| x <= month ) - 1;
+
+		self.nth( time.0.div_euclid( DUR_NORMYEAR ), index )
+	}
+
+	/// Returns an iterator over the seasons of `normyear` in chronological order.
+	pub fn of_year( &self, normyear: i64 ) -> impl Iterator<Item = NormSeason> + '_ {
+		( 0..self.count() ).map( move |i| self.nth( normyear, i ) )
+	}
+
+	/// Returns the season `index` of `normyear`. Instants outside of the range of `NormTime` are saturated.
+	fn nth( &self, normyear: i64, index: usize ) -> NormSeason {
+		let year_start = normyear.saturating_mul( DUR_NORMYEAR );
+		let bound = |i: usize| NormTime( year_start.saturating_add( i64::from( self.bounds[ i ] ) * DUR_NORMMONTH ) );
+
+		NormSeason {
+			index: index as u32,
+			start: bound( index ),
+			end: bound( index + 1 ),
+		}
+	}
+}
+
+impl Default for NormSeasons {
+	fn default() -> Self {
+		Self { bounds: vec![ 0, 2, 4, 6, 8, 10 ] }
+	}
+}
+
+
+
+/// Divisions of the normyear into normquarters for financial-style reporting. The normquarters are [`NormSeason`]s of the corresponding [`NormSeasons`].
+///
+/// # Example
+///
+/// ```
+/// use normtime::{NormQuarters, NormTime};
+///
+/// let d = NormTime::from_ymd_opt( 45, 6, 12 ).unwrap();
+/// assert_eq!( NormQuarters::Fifths.quarter( d ).index(), 3 );
+/// assert_eq!( NormQuarters::Halves.quarter( d ).index(), 1 );
+/// assert_eq!( NormQuarters::Halves.quarter( d ).start(), NormTime::from_ymd_opt( 45, 5, 0 ).unwrap() );
+///
+/// let ends: Vec<NormTime> = NormQuarters::Halves.of_year( 45 ).map( |x| x.end() ).collect();
+/// assert_eq!( ends, [ NormTime::from_ymd_opt( 45, 5, 0 ).unwrap(), NormTime::from_ymd_opt( 46, 0, 0 ).unwrap() ] );
+/// ```
+#[derive( Clone, Copy, PartialEq, Eq, Hash, Default, Debug )]
+pub enum NormQuarters {
+	/// 5 normquarters of 2 normmonths each.
+	#[default]
+	Fifths,
+
+	/// 2 halves of 5 normmonths each.
+	Halves,
+}
+
+impl NormQuarters {
+	/// Returns the division of the normyear into normquarters as `NormSeasons`.
+	pub fn seasons( self ) -> NormSeasons {
+		match self {
+			Self::Fifths => NormSeasons::default(),
+			Self::Halves => NormSeasons { bounds: vec![ 0, 5, 10 ] },
+		}
+	}
+
+	/// Returns the normquarter `time` is part of.
+	pub fn quarter( self, time: NormTime ) -> NormSeason {
+		self.seasons().season( time )
+	}
+
+	/// Returns an iterator over the normquarters of `normyear` in chronological order.
+	pub fn of_year( self, normyear: i64 ) -> impl Iterator<Item = NormSeason> {
+		let seasons = self.seasons();
+
+		( 0..seasons.count() ).map( move |i| seasons.nth( normyear, i ) )
+	}
+}
+
+
+
+
+//=============================================================================
+// Season
+
+
+/// A season or normquarter of a particular normyear. See [`NormSeasons`] and [`NormQuarters`].
+#[derive( Clone, Copy, PartialEq, Eq, Hash, Debug )]
+pub struct NormSeason {
+	index: u32,
+	start: NormTime,
+	end: NormTime,
+}
+
+impl NormSeason {
+	/// Returns the position of the season within the normyear, starting with 0.
+	pub fn index( self ) -> u32 {
+		self.index
+	}
+
+	/// Returns the first instant of the season.
+	pub fn start( self ) -> NormTime {
+		self.start
+	}
+
+	/// Returns the first instant after the season, which is the start of the next season.
+	pub fn end( self ) -> NormTime {
+		self.end
+	}
+
+	/// Returns the length of the season.
+	pub fn duration( self ) -> NormTimeDelta {
+		self.end - self.start
+	}
+
+	/// Returns `true` if `time` is part of the season.
+	pub fn contains( self, time: NormTime ) -> bool {
+		( self.start..self.end ).contains( &time )
+	}
+
+	/// Returns the name of the season in the language `locale`. The fluent resources define names for the seasons of the default division (keys `season-name-0` to `season-name-4`).
+	///
+	/// This method is only available, if the **`i18n`** feature has been enabled.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use unic_langid::langid;
+	/// use normtime::NormTime;
+	///
+	/// let season = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().season();
+	/// assert_eq!( season.name_locale( &langid!( "en-US" ) ), "Bloom" );
+	/// assert_eq!( season.name_locale( &langid!( "de-DE" ) ), "Blüte" );
+	/// ```
+	#[cfg( feature = "i18n" )]
+	pub fn name_locale( self, locale: &LanguageIdentifier ) -> String {
+		LOCALES.lookup( locale, &format!( "season-name-{}", self.index ) )
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn default_seasons() {
+		let seasons = NormSeasons::default();
+		assert_eq!( Some( &seasons ), NormSeasons::new( &[ 2; 5 ] ).as_ref() );
+
+		let season = seasons.season( NormTime( -1 ) );
+		assert_eq!( season.index(), 4 );
+		assert_eq!( season.start(), NormTime::from_ymd_opt( -1, 8, 0 ).unwrap() );
+		assert_eq!( season.end(), NormTime( 0 ) );
+		assert_eq!( season.duration(), NormTimeDelta::new_seconds( 2 * DUR_NORMMONTH ) );
+		assert!( season.contains( NormTime( -1 ) ) );
+		assert!( !season.contains( NormTime( 0 ) ) );
+
+		assert!( seasons.of_year( 3 ).zip( seasons.of_year( 3 ).skip( 1 ) ).all( |( x, y )| x.end() == y.start() ) );
+	}
+
+	#[test]
+	fn seasons_saturate() {
+		let season = NormSeasons::default().season( NormTime( i64::MAX ) );
+		assert_eq!( season.end(), NormTime( i64::MAX ) );
+		assert!( NormSeasons::new( &[ 10, 0 ] ).is_none() );
+		assert!( NormSeasons::new( &[ u32::MAX, 11 ] ).is_none() );
+	}
+
+	#[test]
+	fn quarters() {
+		assert_eq!( NormQuarters::Halves.seasons(), NormSeasons::new( &[ 5, 5 ] ).unwrap() );
+		assert_eq!( NormQuarters::default().of_year( 45 ).count(), 5 );
+		assert_eq!( NormQuarters::Fifths.quarter( NormTime( -1 ) ).end(), NormTime( 0 ) );
+	}
+}
@@ -0,0 +1,613 @@
+//! Alternative `serde` representations to be used with `#[serde( with = "…" )]`.
+//!
+//! This module is only available, if the **`serde`** feature has been enabled.
+
+
+
+
+//=============================================================================
+// Modules
+
+
+/// Serializes a `NormTimeDelta` as struct `{ "secs": …, "nanos": … }` preserving the full precision.
+///
+/// `nanos` is always within 0 and 999'999'999 and is added to `secs`, so -1.5 seconds are represented as `{ "secs": -2, "nanos": 500000000 }`.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormTimeDelta;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive( Serialize, Deserialize, PartialEq, Debug )]
+/// struct Leg {
+///     #[serde( with = "normtime::serde::secs_nanos" )]
+///     duration: NormTimeDelta,
+/// }
+///
+/// let leg = Leg { duration: NormTimeDelta::new( 100, 250 ).unwrap() };
+/// let json = serde_json::to_string( &leg ).unwrap();
+/// assert_eq!( json, r#"{"duration":{"secs":100,"nanos":250}}"# );
+/// assert_eq!( serde_json::from_str::<Leg>( &json ).unwrap(), leg );
+/// ```
+pub mod secs_nanos {
+	use serde::{Deserialize, Deserializer, Serializer};
+	use serde::de::Error;
+	use serde::ser::SerializeStruct;
+
+	use crate::NormTimeDelta;
+
+	#[derive( Deserialize )]
+	#[serde( rename = "NormTimeDelta" )]
+	struct SecsNanos {
+		secs: i64,
+		nanos: u32,
+	}
+
+	/// Serializes `delta` as struct of `secs` and `nanos`.
+	pub fn serialize<S>( delta: &NormTimeDelta, serializer: S ) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let mut state = serializer.serialize_struct( "NormTimeDelta", 2 )?;
+		state.serialize_field( "secs", &delta.secs )?;
+		state.serialize_field( "nanos", &( delta.nanos as u32 ) )?;
+		state.end()
+	}
+
+	/// Deserializes a `NormTimeDelta` from a struct of `secs` and `nanos`. Fails if `nanos` is not below 1'000'000'000 or the duration exceeds the range of `NormTimeDelta`.
+	pub fn deserialize<'de, D>( deserializer: D ) -> Result<NormTimeDelta, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let SecsNanos { secs, nanos } = SecsNanos::deserialize( deserializer )?;
+
+		NormTimeDelta::new( secs, nanos )
+			.ok_or_else( || D::Error::custom( format!( "duration out of range: {} s {} ns", secs, nanos ) ) )
+	}
+}
+
+
+
+/// Serializes a `NormTime` as RFC 3339 string of the instant it represents in UTC, e.g. `"2068-01-01T00:00:01Z"`, for systems that do not know about Normtime.
+///
+/// Deserializing accepts any RFC 3339 string, including those with a time zone offset. Fractions of a second are truncated.
+///
+/// This module is only available, if the **`chrono`** feature has been enabled as well.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormTime;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive( Serialize, Deserialize, PartialEq, Debug )]
+/// struct Event {
+///     #[serde( with = "normtime::serde::rfc3339" )]
+///     time: NormTime,
+/// }
+///
+/// let event = Event { time: NormTime::from_ymd_opt( 0, 0, 0 ).unwrap().and_hms( 0, 0, 1 ) };
+/// let json = serde_json::to_string( &event ).unwrap();
+/// assert_eq!( json, r#"{"time":"2068-01-01T00:00:01Z"}"# );
+/// assert_eq!( serde_json::from_str::<Event>( &json ).unwrap(), event );
+///
+/// let json = r#"{"time":"2068-01-01T02:00:01.5+02:00"}"#;
+/// assert_eq!( serde_json::from_str::<Event>( json ).unwrap(), event );
+/// ```
+#[cfg( feature = "chrono" )]
+pub mod rfc3339 {
+	use std::fmt;
+
+	use serde::{Deserializer, Serializer};
+	use serde::de::{Error, Visitor};
+	use serde::ser::Error as _;
+
+	use crate::NormTime;
+
+	/// Serializes `time` as RFC 3339 string. Fails if the instant is outside of the range supported by `chrono`.
+	pub fn serialize<S>( time: &NormTime, serializer: S ) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let text = time.to_rfc3339()
+			.ok_or_else( || S::Error::custom( format!( "NormTime out of range for RFC 3339: {}", time ) ) )?;
+
+		serializer.serialize_str( &text )
+	}
+
+	struct Rfc3339Visitor;
+
+	impl<'de> Visitor<'de> for Rfc3339Visitor {
+		type Value = NormTime;
+
+		fn expecting( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+			formatter.write_str( "an RFC 3339 date string" )
+		}
+
+		fn visit_str<E>( self, value: &str ) -> Result<Self::Value, E>
+		where
+			E: Error,
+		{
+			NormTime::from_rfc3339( value ).map_err( E::custom )
+		}
+	}
+
+	/// Deserializes a `NormTime` from an RFC 3339 string.
+	pub fn deserialize<'de, D>( deserializer: D ) -> Result<NormTime, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_str( Rfc3339Visitor )
+	}
+}
+
+
+/// Serializes only the date part of a `NormTime`, e.g. `"0045-03-12"`. The clock is dropped, so a deserialized `NormTime` always starts at `00:00:00` of the normday.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormTime;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive( Serialize, Deserialize, PartialEq, Debug )]
+/// struct Birthday {
+///     #[serde( with = "normtime::serde::date" )]
+///     date: NormTime,
+/// }
+///
+/// let birthday = Birthday { date: NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 ) };
+/// let json = serde_json::to_string( &birthday ).unwrap();
+/// assert_eq!( json, r#"{"date":"0045-03-12"}"# );
+/// assert_eq!( serde_json::from_str::<Birthday>( &json ).unwrap().date, NormTime::from_ymd_opt( 45, 3, 12 ).unwrap() );
+/// ```
+pub mod date {
+	use serde::{Deserialize, Deserializer, Serializer};
+	use serde::de::Error;
+
+	use crate::NormTime;
+
+	/// Serializes the date of `time`.
+	pub fn serialize<S>( time: &NormTime, serializer: S ) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.collect_str( &time.display_date() )
+	}
+
+	/// Deserializes a `NormTime` from a date string. A clock is not accepted.
+	pub fn deserialize<'de, D>( deserializer: D ) -> Result<NormTime, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let text = String::deserialize( deserializer )?;
+
+		if text.contains( 'N' ) {
+			return Err( D::Error::custom( format!( "expected a date without clock: {}", text ) ) );
+		}
+
+		text.parse().map_err( D::Error::custom )
+	}
+}
+
+
+/// Serializes only the clock part of a `NormTime`, e.g. `"08:00:00"`. The date is dropped, so a deserialized `NormTime` always lies on 0000-00-00.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormTime;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive( Serialize, Deserialize, PartialEq, Debug )]
+/// struct Alarm {
+///     #[serde( with = "normtime::serde::clock" )]
+///     time: NormTime,
+/// }
+///
+/// let alarm = Alarm { time: NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 ) };
+/// let json = serde_json::to_string( &alarm ).unwrap();
+/// assert_eq!( json, r#"{"time":"08:00:00"}"# );
+/// assert_eq!( serde_json::from_str::<Alarm>( &json ).unwrap().time, NormTime::from_ymd_opt( 0, 0, 0 ).unwrap().and_hms( 8, 0, 0 ) );
+/// ```
+pub mod clock {
+	use serde::{Deserialize, Deserializer, Serializer};
+	use serde::de::Error;
+
+	use crate::{NormTime, ParseOptions};
+
+	/// Serializes the clock of `time`.
+	pub fn serialize<S>( time: &NormTime, serializer: S ) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.collect_str( &time.display_clock() )
+	}
+
+	/// Deserializes a `NormTime` on 0000-00-00 from a clock string. The clock must not exceed the duration of a normday.
+	pub fn deserialize<'de, D>( deserializer: D ) -> Result<NormTime, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let text = String::deserialize( deserializer )?;
+
+		NormTime::parse_with( &format!( "0000-00-00N{}", text ), &ParseOptions { strict: true, ..Default::default() } )
+			.map_err( D::Error::custom )
+	}
+}
+
+
+/// Deserializes a `NormTimeDelta` leniently from integers (seconds), floats (fractional seconds) or human strings like `"2 normdays"` or `"1day 3h"`, so durations in configuration files like TOML or YAML can be written readably.
+///
+/// Strings are parsed like `.parse()` and, failing that, like [`humantime::parse_duration`](crate::humantime::parse_duration). Serializing writes the humantime format, e.g. `"1day 3h"`, which preserves nanoseconds.
+///
+/// **Note:** Deserializing relies on `Deserializer::deserialize_any` to find out whether a number or a string is given. Therefore, this representation only works with self-describing formats like JSON, TOML or YAML, but not with formats like `bincode` or `postcard`.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormTimeDelta;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive( Serialize, Deserialize, PartialEq, Debug )]
+/// struct Config {
+///     #[serde( with = "normtime::serde::lenient" )]
+///     timeout: NormTimeDelta,
+/// }
+///
+/// let parse = |x| serde_json::from_str::<Config>( x ).unwrap().timeout;
+/// assert_eq!( parse( r#"{"timeout":90}"# ), NormTimeDelta::new_seconds( 90 ) );
+/// assert_eq!( parse( r#"{"timeout":1.5}"# ), NormTimeDelta::new( 1, 500_000_000 ).unwrap() );
+/// assert_eq!( parse( r#"{"timeout":"2 normdays"}"# ), NormTimeDelta::new_days( 2 ) );
+/// assert_eq!( parse( r#"{"timeout":"1day 3h"}"# ), NormTimeDelta::new_days( 1 ) + NormTimeDelta::new_hours( 3 ) );
+///
+/// let config = Config { timeout: NormTimeDelta::new_days( 1 ) + NormTimeDelta::new_hours( 3 ) };
+/// assert_eq!( serde_json::to_string( &config ).unwrap(), r#"{"timeout":"1day 3h"}"# );
+/// ```
+pub mod lenient {
+	use std::fmt;
+
+	use serde::{Deserializer, Serializer};
+	use serde::de::{Error, Visitor};
+
+	use crate::NormTimeDelta;
+	use crate::humantime::{format_duration, parse_duration};
+
+	/// Serializes `delta` in the humantime format.
+	pub fn serialize<S>( delta: &NormTimeDelta, serializer: S ) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.serialize_str( &format_duration( *delta ) )
+	}
+
+	struct LenientVisitor;
+
+	impl<'de> Visitor<'de> for LenientVisitor {
+		type Value = NormTimeDelta;
+
+		fn expecting( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+			formatter.write_str( "a number of seconds or a duration string like \"2 normdays\"" )
+		}
+
+		fn visit_i64<E>( self, value: i64 ) -> Result<Self::Value, E>
+		where
+			E: Error,
+		{
+			NormTimeDelta::new( value, 0 )
+				.ok_or_else( || E::custom( format!( "duration out of range: {} s", value ) ) )
+		}
+
+		fn visit_u64<E>( self, value: u64 ) -> Result<Self::Value, E>
+		where
+			E: Error,
+		{
+			i64::try_from( value ).ok()
+				.and_then( |x| NormTimeDelta::new( x, 0 ) )
+				.ok_or_else( || E::custom( format!( "duration out of range: {} s", value ) ) )
+		}
+
+		fn visit_f64<E>( self, value: f64 ) -> Result<Self::Value, E>
+		where
+			E: Error,
+		{
+			let nanos = ( value * 1e9 ).round();
+			if !nanos.is_finite() || nanos < NormTimeDelta::MIN.total_nanos() as f64 || nanos > NormTimeDelta::MAX.total_nanos() as f64 {
+				return Err( E::custom( format!( "duration out of range: {} s", value ) ) );
+			}
+
+			Ok( NormTimeDelta::from_total_nanos( nanos as i128 ) )
+		}
+
+		fn visit_str<E>( self, value: &str ) -> Result<Self::Value, E>
+		where
+			E: Error,
+		{
+			value.parse::<NormTimeDelta>()
+				.or_else( |_| parse_duration( value ) )
+				.map_err( E::custom )
+		}
+	}
+
+	/// Deserializes a `NormTimeDelta` from a number of seconds or a duration string.
+	pub fn deserialize<'de, D>( deserializer: D ) -> Result<NormTimeDelta, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_any( LenientVisitor )
+	}
+}
+
+
+/// Serializes a `NormPeriod` as human readable string like its `Display` representation, e.g. `"1y 2m 3d"`. This is also the default representation of `NormPeriod`.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormPeriod;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive( Serialize, Deserialize, PartialEq, Debug )]
+/// struct Rule {
+///     #[serde( with = "normtime::serde::period_string" )]
+///     every: NormPeriod,
+/// }
+///
+/// let rule = Rule { every: NormPeriod::new( 1, 2, 0, 3, 0 ) };
+/// let json = serde_json::to_string( &rule ).unwrap();
+/// assert_eq!( json, r#"{"every":"1y 2m 3d"}"# );
+/// assert_eq!( serde_json::from_str::<Rule>( &json ).unwrap(), rule );
+/// ```
+pub mod period_string {
+	use serde::{Deserialize, Deserializer, Serializer};
+	use serde::de::Error;
+
+	use crate::NormPeriod;
+
+	/// Serializes `period` as string.
+	pub fn serialize<S>( period: &NormPeriod, serializer: S ) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.collect_str( period )
+	}
+
+	/// Deserializes a `NormPeriod` from a string like `"1y 2m 3d"`.
+	pub fn deserialize<'de, D>( deserializer: D ) -> Result<NormPeriod, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let text = String::deserialize( deserializer )?;
+
+		text.parse().map_err( D::Error::custom )
+	}
+}
+
+
+/// Serializes a `NormPeriod` as struct `{ "years": …, "months": …, "weeks": …, "days": …, "seconds": … }`.
+///
+/// When deserializing, missing components are considered to be zero, so only the relevant ones have to be written.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormPeriod;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive( Serialize, Deserialize, PartialEq, Debug )]
+/// struct Rule {
+///     #[serde( with = "normtime::serde::period_struct" )]
+///     every: NormPeriod,
+/// }
+///
+/// let rule = Rule { every: NormPeriod::new_months( 1 ) };
+/// let json = serde_json::to_string( &rule ).unwrap();
+/// assert_eq!( json, r#"{"every":{"years":0,"months":1,"weeks":0,"days":0,"seconds":0}}"# );
+/// assert_eq!( serde_json::from_str::<Rule>( r#"{"every":{"months":1}}"# ).unwrap(), rule );
+/// ```
+pub mod period_struct {
+	use serde::{Deserialize, Deserializer, Serializer};
+	use serde::ser::SerializeStruct;
+
+	use crate::NormPeriod;
+
+	#[derive( Deserialize, Default )]
+	#[serde( rename = "NormPeriod", default, deny_unknown_fields )]
+	struct Components {
+		years: i64,
+		months: i64,
+		weeks: i64,
+		days: i64,
+		seconds: i64,
+	}
+
+	/// Serializes `period` as struct of its components.
+	pub fn serialize<S>( period: &NormPeriod, serializer: S ) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let mut state = serializer.serialize_struct( "NormPeriod", 5 )?;
+		state.serialize_field( "years", &period.years )?;
+		state.serialize_field( "months", &period.months )?;
+		state.serialize_field( "weeks", &period.weeks )?;
+		state.serialize_field( "days", &period.days )?;
+		state.serialize_field( "seconds", &period.seconds )?;
+		state.end()
+	}
+
+	/// Deserializes a `NormPeriod` from a struct of its components.
+	pub fn deserialize<'de, D>( deserializer: D ) -> Result<NormPeriod, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let Components { years, months, weeks, days, seconds } = Components::deserialize( deserializer )?;
+
+		Ok( NormPeriod::new( years, months, weeks, days, seconds ) )
+	}
+}
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use serde::{Deserialize, Serialize};
+	use serde_test::{Token, assert_tokens, assert_ser_tokens, assert_de_tokens, assert_de_tokens_error};
+
+	use crate::{NormPeriod, NormTime, NormTimeDelta};
+
+	#[derive( Serialize, Deserialize, PartialEq, Debug )]
+	struct Wrapper(
+		#[serde( with = "super::secs_nanos" )]
+		NormTimeDelta,
+	);
+
+	#[test]
+	fn secs_nanos() {
+		assert_tokens( &Wrapper( NormTimeDelta::new( -2, 500_000_000 ).unwrap() ), &[
+			Token::NewtypeStruct { name: "Wrapper" },
+			Token::Struct { name: "NormTimeDelta", len: 2 },
+			Token::Str( "secs" ),
+			Token::I64( -2 ),
+			Token::Str( "nanos" ),
+			Token::U32( 500_000_000 ),
+			Token::StructEnd,
+		] );
+
+		assert_de_tokens_error::<Wrapper>( &[
+			Token::NewtypeStruct { name: "Wrapper" },
+			Token::Struct { name: "NormTimeDelta", len: 2 },
+			Token::Str( "secs" ),
+			Token::I64( 0 ),
+			Token::Str( "nanos" ),
+			Token::U32( 1_000_000_000 ),
+			Token::StructEnd,
+		], "duration out of range: 0 s 1000000000 ns" );
+	}
+
+	#[test]
+	#[cfg( feature = "chrono" )]
+	fn rfc3339() {
+		#[derive( Serialize, Deserialize, PartialEq, Debug )]
+		struct Wrapper(
+			#[serde( with = "super::rfc3339" )]
+			NormTime,
+		);
+
+		assert_tokens( &Wrapper( NormTime::from_ymd_opt( -1, 0, 0 ).unwrap() ), &[
+			Token::NewtypeStruct { name: "Wrapper" },
+			Token::Str( "2067-01-18T18:40:00Z" ),
+		] );
+
+		assert_de_tokens_error::<Wrapper>( &[
+			Token::NewtypeStruct { name: "Wrapper" },
+			Token::Str( "0045-03-12N08:00:00" ),
+		], "Could not parse into NormTime: 0045-03-12N08:00:00" );
+	}
+
+	#[test]
+	fn lenient() {
+		#[derive( Serialize, Deserialize, PartialEq, Debug )]
+		struct Lenient(
+			#[serde( with = "super::lenient" )]
+			NormTimeDelta,
+		);
+
+		assert_tokens( &Lenient( NormTimeDelta::new( -2, 500_000_000 ).unwrap() ), &[
+			Token::NewtypeStruct { name: "Lenient" },
+			Token::Str( "-1s 500ms" ),
+		] );
+		assert_de_tokens( &Lenient( NormTimeDelta::new_seconds( -3 ) ), &[
+			Token::NewtypeStruct { name: "Lenient" },
+			Token::I8( -3 ),
+		] );
+		assert_de_tokens( &Lenient( NormTimeDelta::new( -1, 750_000_000 ).unwrap() ), &[
+			Token::NewtypeStruct { name: "Lenient" },
+			Token::F64( -0.25 ),
+		] );
+
+		assert_de_tokens_error::<Lenient>( &[
+			Token::NewtypeStruct { name: "Lenient" },
+			Token::U64( u64::MAX ),
+		], "duration out of range: 18446744073709551615 s" );
+		assert_de_tokens_error::<Lenient>( &[
+			Token::NewtypeStruct { name: "Lenient" },
+			Token::F64( f64::NAN ),
+		], "duration out of range: NaN s" );
+	}
+
+	#[test]
+	fn period() {
+		#[derive( Serialize, Deserialize, PartialEq, Debug )]
+		struct Wrapper(
+			#[serde( with = "super::period_string" )]
+			NormPeriod,
+			#[serde( with = "super::period_struct" )]
+			NormPeriod,
+		);
+
+		let period = NormPeriod::new( 1, -2, 0, 3, 4 );
+		assert_tokens( &Wrapper( period, period ), &[
+			Token::TupleStruct { name: "Wrapper", len: 2 },
+			Token::Str( "1y -2m 3d 4s" ),
+			Token::Struct { name: "NormPeriod", len: 5 },
+			Token::Str( "years" ),
+			Token::I64( 1 ),
+			Token::Str( "months" ),
+			Token::I64( -2 ),
+			Token::Str( "weeks" ),
+			Token::I64( 0 ),
+			Token::Str( "days" ),
+			Token::I64( 3 ),
+			Token::Str( "seconds" ),
+			Token::I64( 4 ),
+			Token::StructEnd,
+			Token::TupleStructEnd,
+		] );
+
+		assert_de_tokens_error::<Wrapper>( &[
+			Token::TupleStruct { name: "Wrapper", len: 2 },
+			Token::Str( "1 y" ),
+		], "Cannot parse into `NormPeriod`: 1 y" );
+		assert_de_tokens_error::<Wrapper>( &[
+			Token::TupleStruct { name: "Wrapper", len: 2 },
+			Token::Str( "1y" ),
+			Token::Struct { name: "NormPeriod", len: 1 },
+			Token::Str( "hours" ),
+		], "unknown field `hours`, expected one of `years`, `months`, `weeks`, `days`, `seconds`" );
+	}
+
+	#[test]
+	fn date_and_clock() {
+		#[derive( Serialize, Deserialize, PartialEq, Debug )]
+		struct Wrapper(
+			#[serde( with = "super::date" )]
+			NormTime,
+			#[serde( with = "super::clock" )]
+			NormTime,
+		);
+
+		let time = NormTime::from_ymd_opt( -1, 2, 3 ).unwrap().and_hms( 4, 5, 6 );
+		let tokens = [
+			Token::TupleStruct { name: "Wrapper", len: 2 },
+			Token::Str( "-0001-02-03" ),
+			Token::Str( "04:05:06" ),
+			Token::TupleStructEnd,
+		];
+		assert_ser_tokens( &Wrapper( time, time ), &tokens );
+		assert_de_tokens(
+			&Wrapper( NormTime::from_ymd_opt( -1, 2, 3 ).unwrap(), NormTime::from_ymd_opt( 0, 0, 0 ).unwrap().and_hms( 4, 5, 6 ) ),
+			&tokens
+		);
+
+		assert_de_tokens_error::<Wrapper>( &[
+			Token::TupleStruct { name: "Wrapper", len: 2 },
+			Token::Str( "-0001-02-03" ),
+			Token::Str( "28:00:00" ),
+		], "Could not parse into NormTime: hour 28 is out of range 0–27" );
+	}
+}
@@ -0,0 +1,196 @@
+//! Dividing the normday into shifts.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use std::fmt;
+
+use crate::{NormTime, NormTimeDelta};
+use crate::{DUR_NORMDAY, DUR_HOUR, DUR_MINUTE};
+
+
+
+
+//=============================================================================
+// Division
+
+
+/// A division of the normday into shifts of equal length. The first shift starts with the normday.
+///
+/// The default division consists of 4 shifts of 25 ks each.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{NormShifts, NormTime};
+///
+/// let shifts = NormShifts::new( 5 ).unwrap();
+///
+/// let d = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+/// let shift = shifts.shift( d );
+/// assert_eq!( shift.index(), 1 );
+/// assert_eq!( shift.to_string(), "05:33:20–11:06:40" );
+///
+/// let rota: Vec<String> = shifts.of_day( d ).map( |x| x.to_string() ).collect();
+/// assert_eq!( rota.len(), 5 );
+/// assert_eq!( rota[ 4 ], "22:13:20–27:46:40" );
+///
+/// // 100 ks cannot be divided into 3 shifts of whole seconds.
+/// assert!( NormShifts::new( 3 ).is_none() );
+/// ```
+#[derive( Clone, Copy, PartialEq, Eq, Hash, Debug )]
+pub struct NormShifts {
+	count: u32,
+}
+
+impl NormShifts {
+	/// Creates a division of the normday into `count` shifts. Returns `None` if `count` is 0 or the 100 ks of a normday cannot be divided into `count` shifts of whole seconds.
+	pub const fn new( count: u32 ) -> Option<Self> {
+		if count == 0 || DUR_NORMDAY % count as i64 != 0 {
+			return None;
+		}
+
+		Some( Self { count } )
+	}
+
+	/// Returns the number of shifts per normday.
+	pub const fn count( self ) -> u32 {
+		self.count
+	}
+
+	/// Returns the length of each shift.
+	pub const fn duration( self ) -> NormTimeDelta {
+		NormTimeDelta::new_seconds( DUR_NORMDAY / self.count as i64 )
+	}
+
+	/// Returns the shift `time` is part of.
+	pub fn shift( self, time: NormTime ) -> NormShift {
+		let index = time.0.rem_euclid( DUR_NORMDAY ) / self.duration().seconds();
+
+		self.nth( time.0.div_euclid( DUR_NORMDAY ), index as u32 )
+	}
+
+	/// Returns an iterator over the shifts of the normday `time` is part of, in chronological order.
+	pub fn of_day( self, time: NormTime ) -> impl Iterator<Item = NormShift> {
+		let day = time.0.div_euclid( DUR_NORMDAY );
+
+		( 0..self.count ).map( move |i| self.nth( day, i ) )
+	}
+
+	/// Returns the shift `index` of normday `day` counted from 0000-00-00. Instants outside of the range of `NormTime` are saturated.
+	fn nth( self, day: i64, index: u32 ) -> NormShift {
+		let len = self.duration().seconds();
+		let start = day.saturating_mul( DUR_NORMDAY ).saturating_add( i64::from( index ) * len );
+
+		NormShift {
+			index,
+			start: NormTime( start ),
+			end: NormTime( start.saturating_add( len ) ),
+		}
+	}
+}
+
+impl Default for NormShifts {
+	fn default() -> Self {
+		Self { count: 4 }
+	}
+}
+
+
+
+
+//=============================================================================
+// Shift
+
+
+/// A shift of a particular normday. See [`NormShifts`].
+///
+/// A shift is displayed as the clocks of its start and end, e.g. `06:56:40–13:53:20`. The end of the last shift of a normday is displayed as `27:46:40` instead of `00:00:00`.
+#[derive( Clone, Copy, PartialEq, Eq, Hash, Debug )]
+pub struct NormShift {
+	index: u32,
+	start: NormTime,
+	end: NormTime,
+}
+
+impl NormShift {
+	/// Returns the position of the shift within the normday, starting with 0.
+	pub fn index( self ) -> u32 {
+		self.index
+	}
+
+	/// Returns the first instant of the shift.
+	pub fn start( self ) -> NormTime {
+		self.start
+	}
+
+	/// Returns the first instant after the shift, which is the start of the next shift.
+	pub fn end( self ) -> NormTime {
+		self.end
+	}
+
+	/// Returns the length of the shift.
+	pub fn duration( self ) -> NormTimeDelta {
+		self.end - self.start
+	}
+
+	/// Returns `true` if `time` is part of the shift.
+	pub fn contains( self, time: NormTime ) -> bool {
+		( self.start..self.end ).contains( &time )
+	}
+}
+
+impl fmt::Display for NormShift {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		let clock = |x: i64| format!( "{:0>2}:{:0>2}:{:0>2}", x / DUR_HOUR, x % DUR_HOUR / DUR_MINUTE, x % DUR_MINUTE );
+		let start = self.start.0.rem_euclid( DUR_NORMDAY );
+		let end = start + ( self.end.0 - self.start.0 );
+
+		f.pad( &format!( "{}–{}", clock( start ), clock( end ) ) )
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn default_shifts() {
+		let shifts = NormShifts::default();
+		assert_eq!( shifts.duration(), NormTimeDelta::new_seconds( 25_000 ) );
+
+		let shift = shifts.shift( NormTime( -1 ) );
+		assert_eq!( shift.index(), 3 );
+		assert_eq!( shift.start(), NormTime( -25_000 ) );
+		assert_eq!( shift.end(), NormTime( 0 ) );
+		assert!( shift.contains( NormTime( -25_000 ) ) );
+		assert!( !shift.contains( NormTime( 0 ) ) );
+		assert_eq!( shift.to_string(), "20:50:00–27:46:40" );
+
+		assert!( shifts.of_day( NormTime( 0 ) ).zip( shifts.of_day( NormTime( 0 ) ).skip( 1 ) ).all( |( x, y )| x.end() == y.start() ) );
+	}
+
+	#[test]
+	fn shifts_saturate() {
+		let shift = NormShifts::default().shift( NormTime( i64::MAX ) );
+		assert_eq!( shift.end(), NormTime( i64::MAX ) );
+		assert!( shift.contains( NormTime( i64::MAX - 1 ) ) );
+
+		let shift = NormShifts::default().shift( NormTime( i64::MIN ) );
+		assert_eq!( shift.start(), NormTime( i64::MIN ) );
+		assert!( shift.contains( NormTime( i64::MIN ) ) );
+		assert_eq!( NormShifts::default().of_day( NormTime( i64::MIN ) ).count(), 4 );
+		assert_eq!( NormShifts::new( 0 ), None );
+	}
+}
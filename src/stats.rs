@@ -0,0 +1,134 @@
+//! Aggregate statistics over collections of `NormTimeDelta`s.
+//!
+//! The durations are accumulated with nanosecond precision in an `i128`, so neither the total nor the mean overflow while iterating, no matter how long the durations are.
+//!
+//! # Example
+//!
+//! ```
+//! use normtime::NormTimeDelta;
+//! use normtime::stats;
+//!
+//! let shifts = [ NormTimeDelta::new_hours( 8 ), NormTimeDelta::new_hours( 6 ), NormTimeDelta::new_hours( 10 ) ];
+//!
+//! let summary = stats::summary( &shifts ).unwrap();
+//! assert_eq!( summary.count, 3 );
+//! assert_eq!( summary.total, Some( NormTimeDelta::new_hours( 24 ) ) );
+//! assert_eq!( summary.mean, NormTimeDelta::new_hours( 8 ) );
+//! assert_eq!( summary.min, NormTimeDelta::new_hours( 6 ) );
+//! assert_eq!( summary.max, NormTimeDelta::new_hours( 10 ) );
+//!
+//! assert_eq!( stats::mean( &shifts ), Some( NormTimeDelta::new_hours( 8 ) ) );
+//! ```
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use std::borrow::Borrow;
+
+use crate::NormTimeDelta;
+
+
+
+
+//=============================================================================
+// Summary
+
+
+/// Statistics of a non-empty collection of `NormTimeDelta`s.
+#[derive( Clone, Copy, PartialEq, Eq, Debug )]
+pub struct Summary {
+	/// The number of durations.
+	pub count: usize,
+
+	/// The sum of all durations. `None`, if the sum exceeds the range of `NormTimeDelta`.
+	pub total: Option<NormTimeDelta>,
+
+	/// The arithmetic mean of all durations, rounded down to the nanosecond.
+	pub mean: NormTimeDelta,
+
+	/// The shortest duration.
+	pub min: NormTimeDelta,
+
+	/// The longest duration.
+	pub max: NormTimeDelta,
+}
+
+
+/// Returns count, total, mean, minimum and maximum of the durations in `iter` computed in a single pass. Returns `None` if `iter` is empty.
+pub fn summary<I>( iter: I ) -> Option<Summary>
+where
+	I: IntoIterator,
+	I::Item: Borrow<NormTimeDelta>,
+{
+	let mut iter = iter.into_iter();
+	let first = *iter.next()?.borrow();
+
+	let mut count = 1;
+	let mut nanos = first.total_nanos();
+	let ( mut min, mut max ) = ( first, first );
+
+	for item in iter {
+		let item = *item.borrow();
+		count += 1;
+		nanos += item.total_nanos();
+		min = min.min( item );
+		max = max.max( item );
+	}
+
+	let total = if ( NormTimeDelta::MIN.total_nanos()..=NormTimeDelta::MAX.total_nanos() ).contains( &nanos ) {
+		Some( NormTimeDelta::from_total_nanos( nanos ) )
+	} else {
+		None
+	};
+
+	Some( Summary {
+		count,
+		total,
+		// The mean of durations within the range of `NormTimeDelta` is always within the range as well.
+		mean: NormTimeDelta::from_total_nanos( nanos.div_euclid( count as i128 ) ),
+		min,
+		max,
+	} )
+}
+
+
+/// Returns the arithmetic mean of the durations in `iter`, rounded down to the nanosecond. Returns `None` if `iter` is empty.
+pub fn mean<I>( iter: I ) -> Option<NormTimeDelta>
+where
+	I: IntoIterator,
+	I::Item: Borrow<NormTimeDelta>,
+{
+	summary( iter ).map( |x| x.mean )
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn summary_empty() {
+		assert_eq!( summary( Vec::<NormTimeDelta>::new() ), None );
+		assert_eq!( mean( Vec::<NormTimeDelta>::new() ), None );
+	}
+
+	#[test]
+	fn summary_overflow() {
+		let summary = summary( [ NormTimeDelta::MAX; 3 ] ).unwrap();
+		assert_eq!( summary.total, None );
+		assert_eq!( summary.mean, NormTimeDelta::MAX );
+
+		assert_eq!( mean( [ NormTimeDelta::MIN, NormTimeDelta::MAX ] ), Some( NormTimeDelta::ZERO ) );
+		assert_eq!( mean( [ NormTimeDelta::new_seconds( -1 ), NormTimeDelta::ZERO ] ), Some( NormTimeDelta::new( -1, 500_000_000 ).unwrap() ) );
+	}
+}
@@ -0,0 +1,231 @@
+//! Measuring elapsed time in Normtime units.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use std::time::{Duration, Instant};
+
+use crate::{NormTimeDelta, Unit};
+
+
+
+
+//=============================================================================
+// Clocks
+
+
+/// The source of the current instant used by a [`NormStopwatch`].
+///
+/// Every closure returning an `Instant` is a clock, so tests can inject a clock they control.
+pub trait StopwatchClock {
+	/// Returns the current instant.
+	fn now( &self ) -> Instant;
+}
+
+/// The monotonic clock of the operating system.
+#[derive( Clone, Copy, PartialEq, Eq, Default, Debug )]
+pub struct SystemClock;
+
+impl StopwatchClock for SystemClock {
+	fn now( &self ) -> Instant {
+		Instant::now()
+	}
+}
+
+impl<F: Fn() -> Instant> StopwatchClock for F {
+	fn now( &self ) -> Instant {
+		self()
+	}
+}
+
+
+
+
+//=============================================================================
+// Stopwatch
+
+
+/// Returns `duration` as `NormTimeDelta`, saturating at the maximum of `NormTimeDelta`.
+fn to_delta( duration: Duration ) -> NormTimeDelta {
+	i64::try_from( duration.as_secs() ).ok()
+		.and_then( |x| NormTimeDelta::new( x, duration.subsec_nanos() ) )
+		.unwrap_or( NormTimeDelta::MAX )
+}
+
+/// A stopwatch measuring the elapsed time as `NormTimeDelta`. The stopwatch can be paused and resumed and records laps.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{NormStopwatch, NormTimeDelta};
+///
+/// let mut watch = NormStopwatch::start();
+/// let lap = watch.lap();
+/// watch.pause();
+/// let elapsed = watch.elapsed();
+///
+/// assert!( lap <= elapsed );
+/// assert_eq!( watch.laps(), &[ lap ] );
+/// assert_eq!( watch.elapsed(), elapsed );
+/// ```
+#[derive( Clone, Debug )]
+pub struct NormStopwatch<C: StopwatchClock = SystemClock> {
+	clock: C,
+	started: Option<Instant>,
+	accumulated: Duration,
+	lap_start: Duration,
+	laps: Vec<NormTimeDelta>,
+}
+
+impl NormStopwatch {
+	/// Creates a new running `NormStopwatch` using the clock of the operating system.
+	pub fn start() -> Self {
+		Self::with_clock( SystemClock )
+	}
+}
+
+impl<C: StopwatchClock> NormStopwatch<C> {
+	/// Creates a new running `NormStopwatch` using `clock` to determine the current instant.
+	pub fn with_clock( clock: C ) -> Self {
+		let started = Some( clock.now() );
+
+		Self {
+			clock,
+			started,
+			accumulated: Duration::ZERO,
+			lap_start: Duration::ZERO,
+			laps: Vec::new(),
+		}
+	}
+
+	/// Returns the elapsed time while running, which is not necessarily representable as `NormTimeDelta`.
+	fn elapsed_duration( &self ) -> Duration {
+		match self.started {
+			Some( x ) => self.accumulated + self.clock.now().saturating_duration_since( x ),
+			None => self.accumulated,
+		}
+	}
+
+	/// Returns the time, the stopwatch has been running. Paused periods are not included.
+	pub fn elapsed( &self ) -> NormTimeDelta {
+		to_delta( self.elapsed_duration() )
+	}
+
+	/// Returns `true` if the stopwatch is running, `false` if it has been paused.
+	pub fn is_running( &self ) -> bool {
+		self.started.is_some()
+	}
+
+	/// Pauses the stopwatch. Pausing a paused stopwatch has no effect.
+	pub fn pause( &mut self ) {
+		self.accumulated = self.elapsed_duration();
+		self.started = None;
+	}
+
+	/// Resumes a paused stopwatch. Resuming a running stopwatch has no effect.
+	pub fn resume( &mut self ) {
+		if self.started.is_none() {
+			self.started = Some( self.clock.now() );
+		}
+	}
+
+	/// Records a lap and returns its duration, which is the time since the previous lap or since the start of the stopwatch.
+	pub fn lap( &mut self ) -> NormTimeDelta {
+		let now = self.elapsed_duration();
+		let res = to_delta( now - self.lap_start );
+
+		self.lap_start = now;
+		self.laps.push( res );
+
+		res
+	}
+
+	/// Returns the durations of all recorded laps.
+	pub fn laps( &self ) -> &[NormTimeDelta] {
+		&self.laps
+	}
+
+	/// Resets the elapsed time to zero and removes all laps. A running stopwatch continues to run.
+	pub fn reset( &mut self ) {
+		self.accumulated = Duration::ZERO;
+		self.lap_start = Duration::ZERO;
+		self.laps.clear();
+
+		if self.started.is_some() {
+			self.started = Some( self.clock.now() );
+		}
+	}
+
+	/// Returns the recorded laps, one per line, using `units`. See [`NormTimeDelta::to_string_unit`].
+	///
+	/// # Example
+	///
+	/// ```
+	/// use std::cell::Cell;
+	/// use std::time::{Duration, Instant};
+	/// use normtime::{NormStopwatch, Unit};
+	///
+	/// let base = Instant::now();
+	/// let offset = Cell::new( Duration::ZERO );
+	/// let mut watch = NormStopwatch::with_clock( || base + offset.get() );
+	///
+	/// offset.set( Duration::from_secs( 3700 ) );
+	/// watch.lap();
+	/// offset.set( Duration::from_secs( 3760 ) );
+	/// watch.lap();
+	///
+	/// assert_eq!( watch.to_string_laps( &[ Unit::Hour, Unit::Minute ] ), "1: 1 hour 1 minute\n2: 1 minute" );
+	/// ```
+	pub fn to_string_laps( &self, units: &[Unit] ) -> String {
+		self.laps.iter()
+			.enumerate()
+			.map( |( i, x )| format!( "{}: {}", i + 1, x.to_string_unit( units ) ) )
+			.collect::<Vec<String>>()
+			.join( "\n" )
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	use std::cell::Cell;
+
+	#[test]
+	fn stopwatch() {
+		let base = Instant::now();
+		let offset = Cell::new( Duration::ZERO );
+		let mut watch = NormStopwatch::with_clock( || base + offset.get() );
+
+		offset.set( Duration::from_millis( 1500 ) );
+		assert_eq!( watch.elapsed(), NormTimeDelta::new( 1, 500_000_000 ).unwrap() );
+		assert_eq!( watch.lap(), NormTimeDelta::new( 1, 500_000_000 ).unwrap() );
+
+		// Paused periods are not counted.
+		watch.pause();
+		assert!( !watch.is_running() );
+		offset.set( Duration::from_secs( 100 ) );
+		assert_eq!( watch.elapsed(), NormTimeDelta::new( 1, 500_000_000 ).unwrap() );
+		watch.resume();
+		offset.set( Duration::from_secs( 102 ) );
+		assert_eq!( watch.elapsed(), NormTimeDelta::new( 3, 500_000_000 ).unwrap() );
+		assert_eq!( watch.lap(), NormTimeDelta::new_seconds( 2 ) );
+		assert_eq!( watch.laps().len(), 2 );
+
+		watch.reset();
+		assert!( watch.is_running() );
+		assert_eq!( watch.elapsed(), NormTimeDelta::ZERO );
+		assert!( watch.laps().is_empty() );
+	}
+}
@@ -7,15 +7,23 @@
 // Crates
 
 
+#[cfg( feature = "chrono" )] use std::cmp::Ordering;
+#[cfg( feature = "i18n" )] use std::collections::HashMap;
 use std::fmt;
-use std::ops::{Add, Sub};
+use std::ops::{Add, RangeBounds, Sub};
 use std::str::FromStr;
+#[cfg( not( all( feature = "wasm", target_arch = "wasm32" ) ) )] use std::time::{SystemTime, UNIX_EPOCH};
 
-use chrono::{NaiveDate, NaiveTime, NaiveDateTime, TimeDelta, DateTime};
+#[cfg( feature = "chrono" )] use chrono::{NaiveDate, NaiveTime, NaiveDateTime, DateTime, SecondsFormat};
+#[cfg( feature = "i18n" )] use fluent_templates::{Loader, fluent_bundle::FluentValue};
 use thiserror::Error;
+#[cfg( feature = "i18n" )] use unic_langid::LanguageIdentifier;
 
-use crate::{NORMTIME_OFFSET, DUR_NORMDAY, DUR_NORMMONTH, DUR_NORMYEAR};
-use crate::NormTimeDelta;
+use crate::{NORMTIME_OFFSET, DUR_NORMDAY, DUR_NORMWEEK, DUR_NORMMONTH, DUR_NORMYEAR, DUR_HOUR, DUR_MINUTE};
+use crate::{NormBreakdown, NormDate, NormPeriod, NormQuarters, NormSeason, NormSeasons, NormShift, NormShifts, NormTimeDelta, NormTimeOfDay, Unit};
+use crate::names::{MonthNames, WeekdayNames};
+#[cfg( feature = "i18n" )] use crate::DisplayLocale;
+#[cfg( feature = "i18n" )] use crate::LOCALES;
 
 
 
@@ -24,11 +32,22 @@ use crate::NormTimeDelta;
 // Errors
 
 
+/// Errors occurring when parsing a `NormTime`.
 #[derive( Error, PartialEq, Debug )]
 pub enum TimeError {
 	#[error( "Could not parse into NormTime: {0}" )]
 	ParseError( String ),
 
+	#[error( "Could not parse into NormTime: {field} {value} is out of range 0–{max}" )]
+	OutOfRange {
+		field: &'static str,
+		value: i64,
+		max: i64,
+	},
+
+	#[error( "Could not parse into NormTime: {0} is out of the range of NormTime" )]
+	Overflow( String ),
+
 	#[error( transparent )]
 	ParseIntError( #[from] std::num::ParseIntError ),
 }
@@ -37,6 +56,107 @@ pub enum TimeError {
 
 
 
+//=============================================================================
+// Options
+
+
+/// Options controlling how [`NormTime::parse_with`] parses strings.
+///
+/// The default options accept exactly the strings accepted by `.parse()`.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{NormTime, ParseOptions};
+///
+/// let options = ParseOptions {
+///     strict: true,
+///     alternative_separators: true,
+///     ..ParseOptions::new()
+/// };
+///
+/// assert_eq!( NormTime::parse_with( "0045-03-12T08:00:00", &options ), "0045-03-12N08:00:00".parse() );
+/// assert!( NormTime::parse_with( "0045-03-30T08:00:00", &options ).is_err() );
+/// ```
+#[derive( Clone, PartialEq, Debug )]
+pub struct ParseOptions {
+	/// Reject fields exceeding their range instead of rolling them into the next greater unit. See [`NormTime::parse_strict`].
+	pub strict: bool,
+
+	/// Accept strings without clock part like `0045-03-12`, representing the start of the normday.
+	pub allow_missing_clock: bool,
+
+	/// Accept the era suffixes ` NE` and ` BNE` as written by the alternate format (`{:#}`). normyears before the norm era are written without sign. To parse the alternate format, `alternative_separators` has to be enabled as well.
+	pub allow_era: bool,
+
+	/// Accept `T` and a space as separator between date and clock in addition to `N`.
+	pub alternative_separators: bool,
+
+	/// Accept ordinal dates like `0045-123` (normday of the normyear) and week dates like `0045-W12-3` (normweek of the normyear and normday of the normweek).
+	pub allow_ordinal_week: bool,
+
+	/// Accept the names of normmonths, ignoring case, in addition to their numbers, e.g. `0045-Quartus-12`.
+	pub month_names: Option<MonthNames>,
+
+	/// Accept the name of the normday of the normweek, ignoring case, in front of the date, e.g. `Tridi 0045-03-12`. A name not matching the date is rejected.
+	pub weekday_names: Option<WeekdayNames>,
+}
+
+impl ParseOptions {
+	/// Creates the default `ParseOptions`, accepting exactly the strings accepted by `.parse()`.
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+impl Default for ParseOptions {
+	fn default() -> Self {
+		Self {
+			strict: false,
+			allow_missing_clock: true,
+			allow_era: false,
+			alternative_separators: false,
+			allow_ordinal_week: false,
+			month_names: None,
+			weekday_names: None,
+		}
+	}
+}
+
+
+/// Canonical string representations of a `NormTime`. See [`NormTime::to_string_format`] and [`NormTime::parse_format`].
+#[derive( Clone, Copy, PartialEq, Eq, Hash, Debug )]
+pub enum NormTimeFormat {
+	/// Date and clock like `Display`, e.g. `0045-03-12N08:00:00`.
+	DateTime,
+
+	/// The date, e.g. `0045-03-12`.
+	Date,
+
+	/// The clock, e.g. `08:00:00`.
+	Time,
+
+	/// The normyear and the normday of the normyear, e.g. `0045-102`.
+	Ordinal,
+
+	/// The normyear, the normweek of the normyear and the normday of the normweek, e.g. `0045-W10-2`.
+	WeekDate,
+
+	/// Date and clock without separators within date and clock, e.g. `00450312N080000`.
+	Compact,
+
+	/// Fixed-width date and clock whose lexicographic order matches the chronological order, e.g. `1000000000045-03-12T080000`. The normyear is offset by 10¹², so normyears before the norm era need no sign. Suitable as sortable key.
+	Sortable,
+}
+
+impl NormTimeFormat {
+	/// The offset of the normyear in the [`Sortable`](Self::Sortable) representation. Exceeds the normyear of any `NormTime`.
+	const SORTABLE_YEAR_OFFSET: i64 = 1_000_000_000_000;
+}
+
+
+
+
 //=============================================================================
 // Time
 
@@ -48,17 +168,52 @@ pub enum TimeError {
 /// 1 normweek := 1 Ms (ca. 12 earth days)
 /// 1 normmonth := 3 Ms (ca. 35 earth days)
 /// 1 normyear := 30 Ms (ca. 1 earth year, ca. 347 earth days)
-#[derive( Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Default )]
-pub struct NormTime( i64 );
+///
+/// `NormTime` has the same memory layout as an `i64` holding the seconds since 0000-00-00N00:00:00. With the **`bytemuck`** or **`zerocopy`** feature enabled, slices of `NormTime` can be cast to bytes and back.
+#[derive( Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Default )]
+#[cfg_attr( feature = "rkyv", derive( rkyv::Archive, rkyv::Serialize, rkyv::Deserialize ) )]
+#[cfg_attr( feature = "rkyv", rkyv( compare( PartialEq ), derive( Debug ) ) )]
+#[cfg_attr( feature = "diesel", derive( diesel::expression::AsExpression, diesel::deserialize::FromSqlRow ) )]
+#[cfg_attr( feature = "diesel", diesel( sql_type = diesel::sql_types::BigInt, sql_type = diesel::sql_types::Timestamp ) )]
+#[cfg_attr( feature = "zerocopy", derive( zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout ) )]
+#[repr( transparent )]
+pub struct NormTime( pub(super) i64 );
 
 impl NormTime {
 	/// Create a new `NormTime` from Unix `timestamp`.
 	///
-	/// Returns `None` if the number of seconds would be out of range for a `chrono::NaiveDateTime` (more than ca. 262,000 years away from the zero time).
-	pub fn from_timestamp( secs: i64 ) -> Option<Self> {
-		let dtime = DateTime::from_timestamp( secs, 0 )?.naive_utc();
+	/// Returns `None` only if the number of seconds would overflow when shifted to the zero time of Normtime, i.e. for the timestamps in the first ca. 98 years after `i64::MIN`. All other timestamps are accepted, even if they are out of the range of `chrono`, so converting the result to `chrono` types may still fail. Up to version 0.4.2, this returned `None` for all timestamps out of the range of `chrono::NaiveDateTime`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// assert_eq!( NormTime::from_timestamp( 3_092_601_600 ), NormTime::from_ymd_opt( 0, 0, 0 ) );
+	/// assert!( NormTime::from_timestamp( i64::MAX ).is_some() );
+	/// assert!( NormTime::from_timestamp( i64::MIN ).is_none() );
+	/// ```
+	pub const fn from_timestamp( secs: i64 ) -> Option<Self> {
+		match secs.checked_sub( NORMTIME_OFFSET ) {
+			Some( x ) => Some( Self( x ) ),
+			None => None,
+		}
+	}
+
+	/// Returns the current time.
+	///
+	/// On `wasm32` targets with the **`wasm`** feature enabled, the time is taken from the browser's `Date.now()`.
+	pub fn now() -> Self {
+		#[cfg( all( feature = "wasm", target_arch = "wasm32" ) )]
+		let secs = ( js_sys::Date::now() / 1000.0 ).floor() as i64;
+
+		#[cfg( not( all( feature = "wasm", target_arch = "wasm32" ) ) )]
+		let secs = match SystemTime::now().duration_since( UNIX_EPOCH ) {
+			Ok( dur ) => dur.as_secs() as i64,
+			Err( err ) => -( err.duration().as_secs_f64().ceil() as i64 ),
+		};
 
-		Some( Self::from( dtime ) )
+		Self( secs - NORMTIME_OFFSET )
 	}
 
 	/// Create a new `NormTime` from `normyear`, `normmonth` and `normday`. `from_ymd( 0, 0, 0 )` represent the 0000-00-00N00:00:00 or the 1st of January 2068 in the common era calendar.
@@ -67,7 +222,17 @@ impl NormTime {
 	/// * `normyear` The year in the Normtime calendar.
 	/// * `normmonth` The month in the Normtime calendar. 0 is a valid normmonth. But since a normyear has exactly 10 normmonths, this function returns `None` if this argument is grater than 9.
 	/// * `normday` The day in the Normtime calendar. 0 is a valid normday. But since a normmonth has exactly 30 normdays, this function returns `None` if this argument is grater than 29.
-	pub fn from_ymd_opt( normyear: i32, normmonth: u32, normday: u32 ) -> Option<Self> {
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// const EPOCH_EVENT: NormTime = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+	/// assert_eq!( EPOCH_EVENT.to_string(), "0045-03-12N08:00:00" );
+	/// assert!( NormTime::from_ymd_opt( 45, 10, 12 ).is_none() );
+	/// ```
+	pub const fn from_ymd_opt( normyear: i32, normmonth: u32, normday: u32 ) -> Option<Self> {
 		if normday > 29 || normmonth > 9 {
 			return None;
 		}
@@ -79,20 +244,585 @@ impl NormTime {
 		Some( Self( seconds ) )
 	}
 
+	/// Create a new `NormTime` from `normyear`, `normmonth`, `normday`, `hour`, `min` and `sec`. Unlike `from_ymd_opt( … ).and_hms( … )`, every field is validated: Returns `None` if `normmonth` is greater than 9, `normday` greater than 29, `min` or `sec` greater than 59 or if the clock is not within the normday, i.e. later than 27:46:39.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// assert_eq!( NormTime::from_ymd_hms_opt( 45, 3, 12, 8, 0, 0 ), Some( NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 ) ) );
+	/// assert!( NormTime::from_ymd_hms_opt( 45, 3, 12, 27, 46, 39 ).is_some() );
+	/// assert!( NormTime::from_ymd_hms_opt( 45, 3, 12, 27, 46, 40 ).is_none() );
+	/// assert!( NormTime::from_ymd_hms_opt( 45, 3, 12, 8, 60, 0 ).is_none() );
+	/// assert!( NormTime::from_ymd_hms_opt( 45, 3, 30, 8, 0, 0 ).is_none() );
+	/// ```
+	pub const fn from_ymd_hms_opt( normyear: i32, normmonth: u32, normday: u32, hour: u32, min: u32, sec: u32 ) -> Option<Self> {
+		let Some( date ) = Self::from_ymd_opt( normyear, normmonth, normday ) else {
+			return None;
+		};
+
+		date.and_hms_opt( hour, min, sec )
+	}
+
+	/// Create a new `NormTime` from `normyear`, the normweek of the normyear `normweek` and the normday of the normweek `weekday`, as written by [`NormTimeFormat::WeekDate`]. Since a normyear has exactly 30 normweeks of 10 normdays each, this function returns `None` if `normweek` is greater than 29 or `weekday` is greater than 9.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTime, NormTimeFormat};
+	///
+	/// let t = NormTime::from_week_date( 45, 10, 2 ).unwrap();
+	/// assert_eq!( t, NormTime::from_ymd_opt( 45, 3, 12 ).unwrap() );
+	/// assert_eq!( t.to_string_format( NormTimeFormat::WeekDate ), "0045-W10-2" );
+	/// assert!( NormTime::from_week_date( 45, 30, 0 ).is_none() );
+	/// ```
+	pub const fn from_week_date( normyear: i32, normweek: u32, weekday: u32 ) -> Option<Self> {
+		if normweek as i64 >= DUR_NORMYEAR / DUR_NORMWEEK || weekday as i64 >= DUR_NORMWEEK / DUR_NORMDAY {
+			return None;
+		}
+
+		Some( Self( DUR_NORMYEAR * normyear as i64 + DUR_NORMWEEK * normweek as i64 + DUR_NORMDAY * weekday as i64 ) )
+	}
+
+	/// Create a new `NormTime` from `normyear` and the normday of the normyear `ordinal`, as written by [`NormTimeFormat::Ordinal`]. Since a normyear has exactly 300 normdays, this function returns `None` if `ordinal` is greater than 299.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTime, NormTimeFormat};
+	///
+	/// let t = NormTime::from_ordinal_date( 45, 102 ).unwrap();
+	/// assert_eq!( t, NormTime::from_ymd_opt( 45, 3, 12 ).unwrap() );
+	/// assert_eq!( t.to_string_format( NormTimeFormat::Ordinal ), "0045-102" );
+	/// assert!( NormTime::from_ordinal_date( 45, 300 ).is_none() );
+	/// ```
+	pub const fn from_ordinal_date( normyear: i32, ordinal: u32 ) -> Option<Self> {
+		if ordinal as i64 >= DUR_NORMYEAR / DUR_NORMDAY {
+			return None;
+		}
+
+		Some( Self( DUR_NORMYEAR * normyear as i64 + DUR_NORMDAY * ordinal as i64 ) )
+	}
+
+	/// Returns the normday of `self` without the clock. See [`NormDate::and_time`] for the reverse.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormDate, NormTime};
+	///
+	/// let t = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+	/// assert_eq!( t.date(), NormDate::from_ymd_opt( 45, 3, 12 ).unwrap() );
+	/// assert_eq!( t.date().and_time( t.time_of_day() ), t );
+	/// ```
+	pub const fn date( self ) -> NormDate {
+		NormDate( self.0.div_euclid( DUR_NORMDAY ) )
+	}
+
+	/// Returns the clock of `self` without the normday.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTime, NormTimeOfDay};
+	///
+	/// let t = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+	/// assert_eq!( t.time_of_day(), NormTimeOfDay::from_hms_opt( 8, 0, 0 ).unwrap() );
+	/// ```
+	pub const fn time_of_day( self ) -> NormTimeOfDay {
+		NormTimeOfDay( self.0.rem_euclid( DUR_NORMDAY ) as u32 )
+	}
+
 	/// Create a new `NormTime` from `self`, adding `hour`, `min` and `sec` to it.
 	///
-	/// This is not fully identical to the earth wall clock time. `sec` and `min` greater than 60 are allowed as are `hour` greater than 24.
-	pub fn and_hms( self, hour: u32, min: u32, sec: u32 ) -> Self {
-		let tdelta = TimeDelta::hours( hour as i64 ) + TimeDelta::minutes( min as i64 ) + TimeDelta::seconds( sec as i64 );
+	/// This is not fully identical to the earth wall clock time. `sec` and `min` greater than 60 are allowed as are `hour` greater than 24. Use [`NormTime::and_hms_opt`] to reject those.
+	pub const fn and_hms( self, hour: u32, min: u32, sec: u32 ) -> Self {
+		Self( self.0 + DUR_HOUR * hour as i64 + DUR_MINUTE * min as i64 + sec as i64 )
+	}
+
+	/// Create a new `NormTime` from `self`, adding `hour`, `min` and `sec` to it like [`NormTime::and_hms`] does. Returns `None` if `min` or `sec` are greater than 59, if the clock exceeds the duration of a normday (27:46:39) or if the result overflows.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let d = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+	/// assert_eq!( d.and_hms_opt( 8, 0, 0 ), Some( d.and_hms( 8, 0, 0 ) ) );
+	/// assert!( d.and_hms_opt( 27, 46, 40 ).is_none() );
+	/// assert!( d.and_hms_opt( 99_999, 0, 0 ).is_none() );
+	/// ```
+	pub const fn and_hms_opt( self, hour: u32, min: u32, sec: u32 ) -> Option<Self> {
+		let clock = match NormTimeOfDay::from_hms_opt( hour, min, sec ) {
+			Some( x ) if ( x.0 as i64 ) < DUR_NORMDAY => x.0 as i64,
+			_ => return None,
+		};
+
+		match self.0.checked_add( clock ) {
+			Some( x ) => Some( Self( x ) ),
+			None => None,
+		}
+	}
+
+	/// Parses `s` like `.parse()` does, but rejects fields exceeding their range instead of rolling them into the next greater unit. normmonths have to be within 0 and 9, normdays within 0 and 29, minutes and seconds within 0 and 59 and the clock must not exceed the duration of a normday (27:46:39).
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	/// use normtime::TimeError;
+	///
+	/// assert_eq!( NormTime::parse_strict( "0001-09-29N27:46:39" ), "0001-09-29N27:46:39".parse() );
+	/// assert_eq!( "0001-00-30".parse::<NormTime>(), "0001-01-00".parse() );
+	/// assert_eq!(
+	///     NormTime::parse_strict( "0001-00-30" ),
+	///     Err( TimeError::OutOfRange { field: "normday", value: 30, max: 29 } )
+	/// );
+	/// assert_eq!(
+	///     NormTime::parse_strict( "0001-99-99N99:99:99" ).unwrap_err().to_string(),
+	///     "Could not parse into NormTime: normmonth 99 is out of range 0–9"
+	/// );
+	/// ```
+	pub fn parse_strict( s: &str ) -> Result<Self, TimeError> {
+		Self::parse_with( s, &ParseOptions { strict: true, ..ParseOptions::new() } )
+	}
+
+	/// Parses `s` according to `options`. This allows different input channels to share one parser while accepting different formats.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTime, ParseOptions};
+	///
+	/// let options = ParseOptions {
+	///     allow_era: true,
+	///     alternative_separators: true,
+	///     allow_ordinal_week: true,
+	///     ..ParseOptions::new()
+	/// };
+	///
+	/// let time = NormTime::from_ymd_opt( -5, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+	/// assert_eq!( NormTime::parse_with( &format!( "{:#}", time ), &options ), Ok( time ) );
+	/// assert_eq!( NormTime::parse_with( "-0005-102 08:00:00", &options ), Ok( time ) );
+	/// assert_eq!( NormTime::parse_with( "-0005-W10-2T08:00:00", &options ), Ok( time ) );
+	///
+	/// let options = ParseOptions { allow_missing_clock: false, ..ParseOptions::new() };
+	/// assert!( NormTime::parse_with( "0045-03-12", &options ).is_err() );
+	/// ```
+	pub fn parse_with( s: &str, options: &ParseOptions ) -> Result<Self, TimeError> {
+		parse_normtime( s, options )
+	}
+
+	/// Parses `s` given in the representation `style`. Only the canonical representation as returned by [`NormTime::to_string_format`] is accepted. Representations without date refer to 0000-00-00, those without clock to the start of the normday.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTime, NormTimeFormat};
+	///
+	/// let d = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+	/// assert_eq!( NormTime::parse_format( "00450312N080000", NormTimeFormat::Compact ), Ok( d ) );
+	/// assert_eq!( NormTime::parse_format( "0045-W10-2", NormTimeFormat::WeekDate ), Ok( NormTime::from_ymd_opt( 45, 3, 12 ).unwrap() ) );
+	/// assert!( NormTime::parse_format( "0045-03-12N08:00:00", NormTimeFormat::Date ).is_err() );
+	/// ```
+	pub fn parse_format( s: &str, style: NormTimeFormat ) -> Result<Self, TimeError> {
+		let error = || TimeError::ParseError( s.to_string() );
+		let options = ParseOptions {
+			strict: true,
+			allow_ordinal_week: true,
+			..ParseOptions::new()
+		};
+
+		let res = match style {
+			NormTimeFormat::Time => parse_normtime( &format!( "0000-00-00N{}", s ), &options )?,
+			NormTimeFormat::Compact => {
+				let ( date, clock ) = s.split_once( 'N' ).ok_or_else( error )?;
+				if !date.is_ascii() || !clock.is_ascii() || date.len() < 8 || clock.len() != 6 {
+					return Err( error() );
+				}
+
+				let ( year, monthday ) = date.split_at( date.len() - 4 );
+				let text = format!(
+					"{}-{}-{}N{}:{}:{}",
+					year, &monthday[..2], &monthday[2..],
+					&clock[..2], &clock[2..4], &clock[4..]
+				);
+				parse_normtime( &text, &options )?
+			},
+			NormTimeFormat::Sortable => {
+				if !s.is_ascii() || s.len() != 26 || !s[..13].bytes().all( |x| x.is_ascii_digit() ) {
+					return Err( error() );
+				}
+
+				let year = s[..13].parse::<i64>().map_err( |_| error() )? - NormTimeFormat::SORTABLE_YEAR_OFFSET;
+				let text = format!( "{}{}N{}:{}:{}", year, &s[13..19], &s[20..22], &s[22..24], &s[24..] );
+				parse_normtime( &text, &options )?
+			},
+			_ => parse_normtime( s, &options )?,
+		};
 
-		Self( self.0 + tdelta.num_seconds() )
+		// Leading signs, missing zero-padding and the like are not canonical.
+		if res.to_string_format( style ) != s {
+			return Err( error() );
+		}
+
+		Ok( res )
+	}
+
+	/// Parses the canonical representation `YYYY-MM-DDNhh:mm:ss` or `YYYY-MM-DD` as written by `Display` in const contexts. Fields exceeding their range are rejected like by [`NormTime::parse_strict`]. Returns `None` if `s` is not canonical or if the result overflows. See also the [`normtime!`](crate::normtime) macro.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// const LANDING: NormTime = match NormTime::parse_const( "0045-03-12N08:00:00" ) {
+	///     Some( x ) => x,
+	///     None => panic!( "invalid landing time" ),
+	/// };
+	/// assert_eq!( LANDING, NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 ) );
+	///
+	/// assert_eq!( NormTime::parse_const( "-0005-03-12" ), NormTime::from_ymd_opt( -5, 3, 12 ) );
+	/// assert_eq!( NormTime::parse_const( "0045-03-12T08:00:00" ), None );
+	/// assert_eq!( NormTime::parse_const( "0045-03-30" ), None );
+	/// ```
+	pub const fn parse_const( s: &str ) -> Option<Self> {
+		parse_normtime_const( s )
 	}
 
 	/// Returns the Unix timestamp representing `self`.
-	pub fn timestamp( &self ) -> i64 {
+	pub const fn timestamp( &self ) -> i64 {
 		NORMTIME_OFFSET + self.0
 	}
 
+	/// Returns the point in time halfway between `a` and `b`, rounded towards the earlier one. This never overflows.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let a = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+	/// let b = NormTime::from_ymd_opt( 45, 3, 14 ).unwrap();
+	/// assert_eq!( NormTime::midpoint( a, b ), NormTime::from_ymd_opt( 45, 3, 13 ).unwrap() );
+	/// assert_eq!( NormTime::midpoint( b, a ), NormTime::from_ymd_opt( 45, 3, 13 ).unwrap() );
+	/// ```
+	pub const fn midpoint( a: Self, b: Self ) -> Self {
+		Self( ( ( a.0 as i128 + b.0 as i128 ).div_euclid( 2 ) ) as i64 )
+	}
+
+	/// Linearly interpolates between `a` (`t = 0.0`) and `b` (`t = 1.0`), rounded to the nearest second. Values of `t` outside of 0 and 1 extrapolate. The result saturates at the bounds of `NormTime` instead of overflowing and a `t` of `NaN` returns `a`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let a = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+	/// let b = NormTime::from_ymd_opt( 45, 3, 16 ).unwrap();
+	/// assert_eq!( NormTime::lerp( a, b, 0.0 ), a );
+	/// assert_eq!( NormTime::lerp( a, b, 0.25 ), NormTime::from_ymd_opt( 45, 3, 13 ).unwrap() );
+	/// assert_eq!( NormTime::lerp( a, b, 1.0 ), b );
+	/// assert_eq!( NormTime::lerp( a, b, -0.5 ), NormTime::from_ymd_opt( 45, 3, 10 ).unwrap() );
+	/// ```
+	pub fn lerp( a: Self, b: Self, t: f64 ) -> Self {
+		let span = ( b.0 as i128 - a.0 as i128 ) as f64;
+		// Casting a float to an integer saturates and turns `NaN` into 0.
+		let res = a.0 as i128 + ( span * t ).round() as i128;
+
+		Self( res.clamp( i64::MIN as i128, i64::MAX as i128 ) as i64 )
+	}
+
+	/// Returns the absolute duration between `self` and `other`. Since not every duration between two `NormTime`s can be represented by a `NormTimeDelta`, the result saturates at the largest possible `NormTimeDelta` instead of panicking.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTime, NormTimeDelta};
+	///
+	/// let a = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+	/// let b = NormTime::from_ymd_opt( 45, 3, 14 ).unwrap();
+	/// assert_eq!( a.abs_diff( b ), NormTimeDelta::new_days( 2 ) );
+	/// assert_eq!( b.abs_diff( a ), NormTimeDelta::new_days( 2 ) );
+	/// ```
+	pub const fn abs_diff( self, other: Self ) -> NormTimeDelta {
+		let secs = self.0.abs_diff( other.0 );
+
+		if secs > NormTimeDelta::MAX.secs as u64 {
+			return NormTimeDelta::MAX;
+		}
+
+		NormTimeDelta::new_seconds( secs as i64 )
+	}
+
+	/// Returns the difference between `self` and the earlier `other` broken down into calendar components, e.g. "2 normyears, 3 normmonths, 5 normdays". If `other` is later than `self`, all components are negative. Unlike `self - other`, this never overflows.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let birth = NormTime::from_ymd_opt( 12, 7, 24 ).unwrap();
+	/// let now = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+	/// assert_eq!( now.since( birth ).to_string(), "32 normyears, 5 normmonths, 18 normdays" );
+	/// assert_eq!( now.since( now ).to_string(), "0 seconds" );
+	/// ```
+	pub const fn since( self, other: Self ) -> NormBreakdown {
+		NormBreakdown::from_seconds( self.0 as i128 - other.0 as i128 )
+	}
+
+	/// Restricts `self` to the window between `min` and `max`. Returns `min` if `self` is earlier and `max` if `self` is later than the window.
+	///
+	/// **Note:** Panics if `min` is later than `max`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let opening = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+	/// let closing = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 20, 0, 0 );
+	///
+	/// let early = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 6, 0, 0 );
+	/// assert_eq!( early.clamp( opening, closing ), opening );
+	///
+	/// let noon = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 12, 0, 0 );
+	/// assert_eq!( noon.clamp( opening, closing ), noon );
+	/// ```
+	pub const fn clamp( self, min: Self, max: Self ) -> Self {
+		assert!( min.0 <= max.0, "`min` must not be later than `max`" );
+
+		if self.0 < min.0 {
+			min
+		} else if self.0 > max.0 {
+			max
+		} else {
+			self
+		}
+	}
+
+	/// Returns `true` if `self` lies between `a` and `b`, including both of them. The order of `a` and `b` does not matter.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let opening = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+	/// let closing = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 20, 0, 0 );
+	///
+	/// assert!( opening.is_between( opening, closing ) );
+	/// assert!( closing.is_between( opening, closing ) );
+	/// assert!( closing.is_between( closing, opening ) );
+	/// assert!( !NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().is_between( opening, closing ) );
+	/// ```
+	pub const fn is_between( self, a: Self, b: Self ) -> bool {
+		if a.0 <= b.0 {
+			a.0 <= self.0 && self.0 <= b.0
+		} else {
+			b.0 <= self.0 && self.0 <= a.0
+		}
+	}
+
+	/// Returns `true` if `self` is contained in `range`. Since Normtime has no dedicated range type, `range` may be any of the standard ranges of `NormTime`s. Their bounds are respected, so `start..end` excludes `end` while `start..=end` includes it.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let opening = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+	/// let closing = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 20, 0, 0 );
+	///
+	/// assert!( opening.is_within( &( opening..closing ) ) );
+	/// assert!( !closing.is_within( &( opening..closing ) ) );
+	/// assert!( closing.is_within( &( opening..=closing ) ) );
+	/// assert!( closing.is_within( &( opening.. ) ) );
+	/// ```
+	pub fn is_within<R: RangeBounds<Self>>( self, range: &R ) -> bool {
+		range.contains( &self )
+	}
+
+	/// Adds `delta` to `self` like `+` does, but returns the earliest or latest representable `NormTime` instead of overflowing.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTime, NormTimeDelta};
+	///
+	/// let t = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+	/// assert_eq!( t.saturating_add_delta( NormTimeDelta::new_days( 2 ) ), NormTime::from_ymd_opt( 45, 3, 14 ).unwrap() );
+	///
+	/// let late = NormTime::from_timestamp( i64::MAX ).unwrap();
+	/// assert_eq!( late.saturating_add_delta( NormTimeDelta::new_years( 1_000 ) ), late.saturating_add_delta( NormTimeDelta::new_years( 2_000 ) ) );
+	/// ```
+	pub const fn saturating_add_delta( self, delta: NormTimeDelta ) -> Self {
+		Self( self.0.saturating_add( delta.secs ) )
+	}
+
+	/// Subtracts `delta` from `self`, returning the earliest or latest representable `NormTime` instead of overflowing. Like `+` does, fractions of a second round towards the earlier instant.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTime, NormTimeDelta};
+	///
+	/// let t = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+	/// assert_eq!( t.saturating_sub_delta( NormTimeDelta::new_days( 2 ) ), NormTime::from_ymd_opt( 45, 3, 10 ).unwrap() );
+	/// assert_eq!( t.saturating_sub_delta( NormTimeDelta::new( 0, 500_000_000 ).unwrap() ), NormTime::from_ymd_opt( 45, 3, 11 ).unwrap().and_hms( 27, 46, 39 ) );
+	/// ```
+	pub const fn saturating_sub_delta( self, delta: NormTimeDelta ) -> Self {
+		let secs = self.0 as i128 - delta.secs as i128 - ( delta.nanos > 0 ) as i128;
+
+		if secs < i64::MIN as i128 {
+			Self( i64::MIN )
+		} else if secs > i64::MAX as i128 {
+			Self( i64::MAX )
+		} else {
+			Self( secs as i64 )
+		}
+	}
+
+	/// Adds the components of `period` to `self` one after another, from normyears to seconds. Returns `None` if any step overflows.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormPeriod, NormTime};
+	///
+	/// let t = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+	/// assert_eq!( t.checked_add_period( &NormPeriod::new_months( 1 ) ), NormTime::from_ymd_opt( 45, 4, 12 ) );
+	/// assert_eq!( t.checked_add_period( &NormPeriod::new_years( i64::MAX ) ), None );
+	/// ```
+	pub const fn checked_add_period( &self, period: &NormPeriod ) -> Option<Self> {
+		let components = period.components();
+
+		let mut secs = self.0;
+		let mut i = 0;
+		while i < components.len() {
+			let ( count, unit ) = components[i];
+			secs = match count.checked_mul( unit.seconds() ) {
+				Some( x ) => match secs.checked_add( x ) {
+					Some( y ) => y,
+					None => return None,
+				},
+				None => return None,
+			};
+			i += 1;
+		}
+
+		Some( Self( secs ) )
+	}
+
+	/// Subtracts the components of `period` from `self` one after another, from normyears to seconds. Returns `None` if any step overflows.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormPeriod, NormTime};
+	///
+	/// let t = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+	/// assert_eq!( t.checked_sub_period( &NormPeriod::new( 1, 1, 0, 1, 0 ) ), NormTime::from_ymd_opt( 44, 2, 11 ) );
+	/// ```
+	pub const fn checked_sub_period( &self, period: &NormPeriod ) -> Option<Self> {
+		let components = period.components();
+
+		let mut secs = self.0;
+		let mut i = 0;
+		while i < components.len() {
+			let ( count, unit ) = components[i];
+			secs = match count.checked_mul( unit.seconds() ) {
+				Some( x ) => match secs.checked_sub( x ) {
+					Some( y ) => y,
+					None => return None,
+				},
+				None => return None,
+			};
+			i += 1;
+		}
+
+		Some( Self( secs ) )
+	}
+
+	/// Returns `self` moved forward by exactly one `unit`, e.g. the same clock of the following normday for `Unit::Day`. Since all units of Normtime have a fixed length, this is equal to adding the duration of one `unit`.
+	///
+	/// **Note:** Panics on overflow.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTime, Unit};
+	///
+	/// let t = NormTime::from_ymd_opt( 45, 9, 29 ).unwrap().and_hms( 8, 0, 0 );
+	/// assert_eq!( t.succ( Unit::Day ), NormTime::from_ymd_opt( 46, 0, 0 ).unwrap().and_hms( 8, 0, 0 ) );
+	/// assert_eq!( t.succ( Unit::Month ), NormTime::from_ymd_opt( 46, 0, 29 ).unwrap().and_hms( 8, 0, 0 ) );
+	/// assert_eq!( t.succ( Unit::Week ).pred( Unit::Week ), t );
+	/// ```
+	pub const fn succ( self, unit: Unit ) -> Self {
+		match self.0.checked_add( unit.seconds() ) {
+			Some( x ) => Self( x ),
+			None => panic!( "Overflow in `NormTime::succ`" ),
+		}
+	}
+
+	/// Returns `self` moved backward by exactly one `unit`, e.g. the same clock of the preceding normday for `Unit::Day`. This is the reverse of [`NormTime::succ`].
+	///
+	/// **Note:** Panics on overflow.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTime, Unit};
+	///
+	/// let t = NormTime::from_ymd_opt( 46, 0, 0 ).unwrap().and_hms( 8, 0, 0 );
+	/// assert_eq!( t.pred( Unit::Day ), NormTime::from_ymd_opt( 45, 9, 29 ).unwrap().and_hms( 8, 0, 0 ) );
+	/// assert_eq!( t.pred( Unit::Year ), NormTime::from_ymd_opt( 45, 0, 0 ).unwrap().and_hms( 8, 0, 0 ) );
+	/// ```
+	pub const fn pred( self, unit: Unit ) -> Self {
+		match self.0.checked_sub( unit.seconds() ) {
+			Some( x ) => Self( x ),
+			None => panic!( "Overflow in `NormTime::pred`" ),
+		}
+	}
+
+	/// Returns `true` if `self` and `other` are within the same `unit`, e.g. within the same normday for `Unit::Day`. The units are counted from 0000-00-00N00:00:00, so normweeks start at normdays divisible by 10.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTime, Unit};
+	///
+	/// let a = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+	/// let b = NormTime::from_ymd_opt( 45, 3, 19 ).unwrap();
+	/// assert!( a.same( Unit::Week, b ) );
+	/// assert!( !a.same( Unit::Day, b ) );
+	/// assert!( !a.same( Unit::Week, b.succ( Unit::Day ) ) );
+	/// ```
+	pub const fn same( self, unit: Unit, other: Self ) -> bool {
+		self.0.div_euclid( unit.seconds() ) == other.0.div_euclid( unit.seconds() )
+	}
+
+	/// Returns `true` if `self` and `other` are within the same normday. See [`NormTime::same`].
+	pub const fn same_day( self, other: Self ) -> bool {
+		self.same( Unit::Day, other )
+	}
+
+	/// Returns `true` if `self` and `other` are within the same normweek. See [`NormTime::same`].
+	pub const fn same_week( self, other: Self ) -> bool {
+		self.same( Unit::Week, other )
+	}
+
+	/// Returns `true` if `self` and `other` are within the same normmonth. See [`NormTime::same`].
+	pub const fn same_month( self, other: Self ) -> bool {
+		self.same( Unit::Month, other )
+	}
+
+	/// Returns `true` if `self` and `other` are within the same normyear. See [`NormTime::same`].
+	pub const fn same_year( self, other: Self ) -> bool {
+		self.same( Unit::Year, other )
+	}
+
 	/// Return the string part of `self` as `String`.
 	pub fn to_string_year( self ) -> String {
 		let year = self.0.div_euclid( DUR_NORMYEAR );
@@ -119,36 +849,423 @@ impl NormTime {
 		}
 	}
 
-	/// Return the date part of `self` as LaTeX command.
-	#[cfg( feature = "tex" )]
-	pub fn to_latex_date( self ) -> String {
-		let mut date_txt = self.to_string_date();
-
-		if date_txt.starts_with( '-' ) {
-			date_txt = date_txt.replacen( '-', "−", 1 );
-		}
-
-		format!( r"{}\,\uz{{}}", date_txt )
+	/// Returns an object displaying only the date part of `self`, e.g. `0045-03-12`. Width, fill and alignment are respected and the alternate form (`{:#}`) appends the era like `Display` does.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let d = NormTime::from_ymd_opt( -5, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+	/// assert_eq!( d.display_date().to_string(), "-0005-03-12" );
+	/// assert_eq!( format!( "{:#}", d.display_date() ), "0005-03-12 BNE" );
+	/// assert_eq!( format!( "{:>12}|", d.display_date() ), " -0005-03-12|" );
+	/// ```
+	pub fn display_date( self ) -> impl fmt::Display {
+		DisplayPart::Date( self )
 	}
 
-	/// Return the clock part of `self` as `String`.
-	pub fn to_string_clock( self ) -> String {
-		let subday = self.0.rem_euclid( DUR_NORMDAY );
-		let hour = subday.div_euclid( 3600 );
-		let subhour = subday.rem_euclid( 3600 );
-		let minute = subhour.div_euclid( 60 );
-		let seconds = subday.rem_euclid( 60 );
-
-		format!( "{:0>2}:{:0>2}:{:0>2}", hour, minute, seconds )
+	/// Returns an object displaying only the clock part of `self`, e.g. `08:00:00`. Width, fill and alignment are respected.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let d = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+	/// assert_eq!( d.display_clock().to_string(), "08:00:00" );
+	/// assert_eq!( format!( "{:<10}|", d.display_clock() ), "08:00:00  |" );
+	/// ```
+	pub fn display_clock( self ) -> impl fmt::Display {
+		DisplayPart::Clock( self )
 	}
-}
 
-impl PartialEq<NaiveDateTime> for NormTime {
-	fn eq( &self, other: &NaiveDateTime ) -> bool {
+	/// Returns `self` formatted in the representation `style`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTime, NormTimeFormat};
+	///
+	/// let d = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+	/// assert_eq!( d.to_string_format( NormTimeFormat::DateTime ), "0045-03-12N08:00:00" );
+	/// assert_eq!( d.to_string_format( NormTimeFormat::Date ), "0045-03-12" );
+	/// assert_eq!( d.to_string_format( NormTimeFormat::Time ), "08:00:00" );
+	/// assert_eq!( d.to_string_format( NormTimeFormat::Ordinal ), "0045-102" );
+	/// assert_eq!( d.to_string_format( NormTimeFormat::WeekDate ), "0045-W10-2" );
+	/// assert_eq!( d.to_string_format( NormTimeFormat::Compact ), "00450312N080000" );
+	/// assert_eq!( d.to_string_format( NormTimeFormat::Sortable ), "1000000000045-03-12T080000" );
+	/// ```
+	pub fn to_string_format( self, style: NormTimeFormat ) -> String {
+		let subyear = self.0.rem_euclid( DUR_NORMYEAR );
+
+		match style {
+			NormTimeFormat::DateTime => self.to_string(),
+			NormTimeFormat::Date => self.to_string_date(),
+			NormTimeFormat::Time => self.to_string_clock(),
+			NormTimeFormat::Ordinal => format!( "{}-{:0>3}", self.to_string_year(), subyear / DUR_NORMDAY ),
+			NormTimeFormat::WeekDate => format!(
+				"{}-W{:0>2}-{}",
+				self.to_string_year(),
+				subyear / DUR_NORMWEEK,
+				subyear % DUR_NORMWEEK / DUR_NORMDAY
+			),
+			NormTimeFormat::Compact => format!(
+				"{}{:0>2}{:0>2}N{}",
+				self.to_string_year(),
+				subyear / DUR_NORMMONTH,
+				subyear % DUR_NORMMONTH / DUR_NORMDAY,
+				self.to_string_clock().replace( ':', "" )
+			),
+			NormTimeFormat::Sortable => format!(
+				"{:0>13}-{:0>2}-{:0>2}T{}",
+				self.0.div_euclid( DUR_NORMYEAR ) + NormTimeFormat::SORTABLE_YEAR_OFFSET,
+				subyear / DUR_NORMMONTH,
+				subyear % DUR_NORMMONTH / DUR_NORMDAY,
+				self.to_string_clock().replace( ':', "" )
+			),
+		}
+	}
+
+	/// Returns the date part of `self` formatted according to the date pattern of `locale`. The pattern defines the order of normyear, normmonth and normday, the separators and the name of the era.
+	///
+	/// This method is only available, if the **`i18n`** feature has been enabled.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use unic_langid::langid;
+	/// use normtime::NormTime;
+	///
+	/// let d = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+	/// assert_eq!( d.to_string_pattern_locale( &langid!( "en-US" ) ), "0045-03-12 NE" );
+	/// assert_eq!( d.to_string_pattern_locale( &langid!( "de-DE" ) ), "12.03.0045 NÄ" );
+	///
+	/// let d = NormTime::from_ymd_opt( -5, 3, 12 ).unwrap();
+	/// assert_eq!( d.to_string_pattern_locale( &langid!( "de-DE" ) ), "12.03.0005 vNÄ" );
+	/// ```
+	#[cfg( feature = "i18n" )]
+	pub fn to_string_pattern_locale( self, locale: &LanguageIdentifier ) -> String {
+		let year = self.0.div_euclid( DUR_NORMYEAR );
+		let subyear = self.0.rem_euclid( DUR_NORMYEAR );
+		let era = if year < 0 { "era-before-norm" } else { "era-norm" };
+
+		let args = HashMap::from( [
+			( "year", FluentValue::from( format!( "{:0>4}", year.unsigned_abs() ) ) ),
+			( "month", FluentValue::from( format!( "{:0>2}", subyear / DUR_NORMMONTH ) ) ),
+			( "day", FluentValue::from( format!( "{:0>2}", subyear % DUR_NORMMONTH / DUR_NORMDAY ) ) ),
+			( "era", FluentValue::from( LOCALES.lookup( locale, era ) ) ),
+		] );
+
+		LOCALES.lookup_with_args( locale, "date-pattern", &args )
+	}
+
+	/// Returns the name of the normmonth of `self` in the language `locale`. See [`MonthNames`] for names defined by the application.
+	///
+	/// This method is only available, if the **`i18n`** feature has been enabled.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use unic_langid::langid;
+	/// use normtime::NormTime;
+	///
+	/// let d = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+	/// assert_eq!( d.month_name( &langid!( "en-US" ) ), "Quartus" );
+	/// ```
+	#[cfg( feature = "i18n" )]
+	pub fn month_name( self, locale: &LanguageIdentifier ) -> String {
+		LOCALES.lookup( locale, &format!( "normmonth-name-{}", self.0.rem_euclid( DUR_NORMYEAR ) / DUR_NORMMONTH ) )
+	}
+
+	/// Returns the name of the normday of the normweek of `self` in the language `locale`. See [`WeekdayNames`] for names defined by the application.
+	///
+	/// This method is only available, if the **`i18n`** feature has been enabled.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use unic_langid::langid;
+	/// use normtime::NormTime;
+	///
+	/// let d = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+	/// assert_eq!( d.weekday_name( &langid!( "en-US" ) ), "Tridi" );
+	/// ```
+	#[cfg( feature = "i18n" )]
+	pub fn weekday_name( self, locale: &LanguageIdentifier ) -> String {
+		LOCALES.lookup( locale, &format!( "weekday-name-{}", self.0.rem_euclid( DUR_NORMWEEK ) / DUR_NORMDAY ) )
+	}
+
+	/// Returns the season of the default division of the normyear into 5 seasons of 2 normmonths `self` is part of. Use [`NormSeasons`] for other divisions.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let season = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().season();
+	/// assert_eq!( season.index(), 1 );
+	/// assert_eq!( season.start(), NormTime::from_ymd_opt( 45, 2, 0 ).unwrap() );
+	/// ```
+	pub fn season( self ) -> NormSeason {
+		NormSeasons::default().season( self )
+	}
+
+	/// Returns the normquarter `self` is part of, counting 5 normquarters of 2 normmonths per normyear starting with 0. Use [`NormQuarters`] for the boundaries or other divisions.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// assert_eq!( NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().quarter(), 1 );
+	/// assert_eq!( NormTime::from_ymd_opt( 45, 9, 29 ).unwrap().quarter(), 4 );
+	/// ```
+	pub fn quarter( self ) -> u32 {
+		NormQuarters::Fifths.quarter( self ).index()
+	}
+
+	/// Returns the shift of the default division of the normday into 4 shifts of 25 ks `self` is part of. Use [`NormShifts`] for other divisions.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let d = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+	/// assert_eq!( d.shift().index(), 1 );
+	/// assert_eq!( d.shift().to_string(), "06:56:40–13:53:20" );
+	/// ```
+	pub fn shift( self ) -> NormShift {
+		NormShifts::default().shift( self )
+	}
+
+	/// Returns the start of the shift `self` is part of. See [`NormTime::shift`].
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let d = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+	/// assert_eq!( d.and_hms( 8, 0, 0 ).shift_start(), d.and_hms( 6, 56, 40 ) );
+	/// ```
+	pub fn shift_start( self ) -> Self {
+		self.shift().start()
+	}
+
+	/// Returns the first instant after the shift `self` is part of. See [`NormTime::shift`].
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let d = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+	/// assert_eq!( d.and_hms( 8, 0, 0 ).shift_end(), d.and_hms( 13, 53, 20 ) );
+	/// ```
+	pub fn shift_end( self ) -> Self {
+		self.shift().end()
+	}
+
+	/// Return the date part of `self` as LaTeX command.
+	#[cfg( feature = "tex" )]
+	pub fn to_latex_date( self ) -> String {
+		let mut date_txt = self.to_string_date();
+
+		if date_txt.starts_with( '-' ) {
+			date_txt = date_txt.replacen( '-', "−", 1 );
+		}
+
+		format!( r"{}\,\uz{{}}", date_txt )
+	}
+
+	/// Return the clock part of `self` as `String`.
+	pub fn to_string_clock( self ) -> String {
+		let subday = self.0.rem_euclid( DUR_NORMDAY );
+		let hour = subday.div_euclid( 3600 );
+		let subhour = subday.rem_euclid( 3600 );
+		let minute = subhour.div_euclid( 60 );
+		let seconds = subday.rem_euclid( 60 );
+
+		format!( "{:0>2}:{:0>2}:{:0>2}", hour, minute, seconds )
+	}
+
+	/// Returns the instant represented by `self` as RFC 3339 string in UTC, e.g. `2068-01-01T00:00:01Z`. Returns `None` if the instant is outside of the range supported by `chrono`.
+	///
+	/// This method is only available, if the **`chrono`** feature has been enabled.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let ntime = NormTime::from_ymd_opt( 0, 0, 0 ).unwrap().and_hms( 0, 0, 1 );
+	/// assert_eq!( ntime.to_rfc3339(), Some( "2068-01-01T00:00:01Z".to_string() ) );
+	/// ```
+	#[cfg( feature = "chrono" )]
+	pub fn to_rfc3339( &self ) -> Option<String> {
+		DateTime::from_timestamp( self.0.checked_add( NORMTIME_OFFSET )?, 0 )
+			.map( |x| x.to_rfc3339_opts( SecondsFormat::Secs, true ) )
+	}
+
+	/// Returns the instant represented by `self` as civil UTC timestamp, e.g. `2068-01-01 00:00:01 UTC`, for displaying Normtime and earth time side by side. Returns `None` if the instant is outside of the range supported by `chrono`.
+	///
+	/// This method is only available, if the **`chrono`** feature has been enabled.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let ntime = NormTime::from_ymd_opt( 0, 0, 0 ).unwrap().and_hms( 0, 0, 1 );
+	/// assert_eq!( ntime.to_utc_string(), Some( "2068-01-01 00:00:01 UTC".to_string() ) );
+	/// ```
+	#[cfg( feature = "chrono" )]
+	pub fn to_utc_string( &self ) -> Option<String> {
+		DateTime::from_timestamp( self.0.checked_add( NORMTIME_OFFSET )?, 0 )
+			.map( |x| x.format( "%Y-%m-%d %H:%M:%S UTC" ).to_string() )
+	}
+
+	/// Parses an RFC 3339 string like `2068-01-01T00:00:01Z` into the `NormTime` representing the same instant. Time zone offsets are respected, fractions of a second are truncated.
+	///
+	/// This method is only available, if the **`chrono`** feature has been enabled.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let ntime = NormTime::from_ymd_opt( 0, 0, 0 ).unwrap().and_hms( 0, 0, 1 );
+	/// assert_eq!( NormTime::from_rfc3339( "2068-01-01T00:00:01Z" ), Ok( ntime ) );
+	/// assert_eq!( NormTime::from_rfc3339( "2068-01-01T02:00:01.5+02:00" ), Ok( ntime ) );
+	/// assert!( NormTime::from_rfc3339( "0000-00-00N00:00:01" ).is_err() );
+	/// ```
+	#[cfg( feature = "chrono" )]
+	pub fn from_rfc3339( s: &str ) -> Result<Self, TimeError> {
+		let time = DateTime::parse_from_rfc3339( s ).map_err( |_| TimeError::ParseError( s.to_string() ) )?;
+
+		Ok( Self::from( time.naive_utc() ) )
+	}
+
+	/// Returns `self` as compact base-36 token of lowercase letters and digits, e.g. `mhuf9c`, suitable for URLs and filenames. Instants before 0000-00-00N00:00:00 are prefixed with `-`. See [`NormTime::from_base36`] for the reverse.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let d = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+	/// assert_eq!( d.to_base36(), "mhuf9c" );
+	/// assert_eq!( NormTime::from_base36( &d.to_base36() ), Ok( d ) );
+	/// ```
+	pub fn to_base36( self ) -> String {
+		const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+		let mut value = self.0.unsigned_abs();
+		let mut res = Vec::new();
+		loop {
+			res.push( DIGITS[ ( value % 36 ) as usize ] );
+			value /= 36;
+			if value == 0 {
+				break;
+			}
+		}
+		if self.0 < 0 {
+			res.push( b'-' );
+		}
+		res.reverse();
+
+		String::from_utf8( res ).expect( "base-36 digits are ASCII" )
+	}
+
+	/// Parses a base-36 token as returned by [`NormTime::to_base36`]. Letters are accepted in upper and lower case, so tokens survive case-insensitive file systems and URLs.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let d = NormTime::from_ymd_opt( -5, 3, 12 ).unwrap();
+	/// assert_eq!( NormTime::from_base36( &d.to_base36().to_uppercase() ), Ok( d ) );
+	/// assert!( NormTime::from_base36( "+1dp5kw" ).is_err() );
+	/// assert!( NormTime::from_base36( "1dp5_kw" ).is_err() );
+	/// ```
+	pub fn from_base36( s: &str ) -> Result<Self, TimeError> {
+		if s.starts_with( '+' ) {
+			return Err( TimeError::ParseError( s.to_string() ) );
+		}
+
+		Ok( Self( i64::from_str_radix( s, 36 )? ) )
+	}
+
+	/// Returns `self` as 8 bytes in big-endian order with flipped sign bit, so the lexicographic order of the bytes matches the chronological order. Suitable as key in ordered key-value stores like LMDB or RocksDB.
+	///
+	/// **Note:** The bytes differ from `timestamp().to_be_bytes()` and the memory layout cast by **`bytemuck`** or **`zerocopy`**.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTime;
+	///
+	/// let early = NormTime::from_ymd_opt( -5, 3, 12 ).unwrap();
+	/// let late = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+	/// assert!( early.to_be_bytes() < late.to_be_bytes() );
+	/// assert_eq!( NormTime::from_be_bytes( late.to_be_bytes() ), late );
+	/// ```
+	pub const fn to_be_bytes( self ) -> [u8; 8] {
+		( self.0 as u64 ^ ( 1 << 63 ) ).to_be_bytes()
+	}
+
+	/// Creates a `NormTime` from the bytes returned by [`NormTime::to_be_bytes`].
+	pub const fn from_be_bytes( bytes: [u8; 8] ) -> Self {
+		Self( ( u64::from_be_bytes( bytes ) ^ ( 1 << 63 ) ) as i64 )
+	}
+}
+
+#[cfg( feature = "chrono" )]
+impl PartialEq<NaiveDateTime> for NormTime {
+	fn eq( &self, other: &NaiveDateTime ) -> bool {
 		( self.0 + NORMTIME_OFFSET ).eq( &other.and_utc().timestamp() )
 	}
 }
 
+#[cfg( feature = "chrono" )]
+impl PartialEq<NormTime> for NaiveDateTime {
+	fn eq( &self, other: &NormTime ) -> bool {
+		other.eq( self )
+	}
+}
+
+/// Comparing `NormTime` with `chrono::NaiveDateTime`. Consistent with `PartialEq`, fractions of a second of the `NaiveDateTime` are ignored.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormTime;
+/// use chrono::NaiveDate;
+///
+/// let deadline = NaiveDate::from_ymd_opt( 2068, 1, 1 ).unwrap().and_hms_opt( 0, 0, 1 ).unwrap();
+///
+/// assert!( NormTime::from_ymd_opt( 0, 0, 0 ).unwrap() < deadline );
+/// assert!( deadline < NormTime::from_ymd_opt( 0, 0, 0 ).unwrap().and_hms( 0, 0, 2 ) );
+/// assert!( deadline <= NormTime::from_ymd_opt( 0, 0, 0 ).unwrap().and_hms( 0, 0, 1 ) );
+/// ```
+#[cfg( feature = "chrono" )]
+impl PartialOrd<NaiveDateTime> for NormTime {
+	fn partial_cmp( &self, other: &NaiveDateTime ) -> Option<Ordering> {
+		// The range of `NaiveDateTime` is small enough for the subtraction to never overflow.
+		self.0.partial_cmp( &( other.and_utc().timestamp() - NORMTIME_OFFSET ) )
+	}
+}
+
+#[cfg( feature = "chrono" )]
+impl PartialOrd<NormTime> for NaiveDateTime {
+	fn partial_cmp( &self, other: &NormTime ) -> Option<Ordering> {
+		other.partial_cmp( self ).map( Ordering::reverse )
+	}
+}
+
 impl Add<NormTimeDelta> for NormTime {
 	type Output = Self;
 
@@ -165,6 +1282,81 @@ impl Sub for NormTime {
 	}
 }
 
+/// Adding a `NormPeriod` component by component. See [`NormTime::checked_add_period`].
+///
+/// # Example
+///
+/// ```
+/// use normtime::{NormPeriod, NormTime};
+///
+/// let due = NormTime::from_ymd_opt( 45, 9, 12 ).unwrap();
+/// assert_eq!( due + NormPeriod::new_months( 1 ), NormTime::from_ymd_opt( 46, 0, 12 ).unwrap() );
+/// assert_eq!( due - NormPeriod::new_months( 1 ), NormTime::from_ymd_opt( 45, 8, 12 ).unwrap() );
+/// ```
+impl Add<NormPeriod> for NormTime {
+	type Output = Self;
+
+	fn add( self, other: NormPeriod ) -> Self::Output {
+		self.checked_add_period( &other ).expect( "Overflow in `NormTime + NormPeriod`" )
+	}
+}
+
+impl Sub<NormPeriod> for NormTime {
+	type Output = Self;
+
+	fn sub( self, other: NormPeriod ) -> Self::Output {
+		self.checked_sub_period( &other ).expect( "Overflow in `NormTime - NormPeriod`" )
+	}
+}
+
+forward_ref_binop!( impl Add, add for NormTime, NormTimeDelta );
+forward_ref_binop!( impl Sub, sub for NormTime, NormTime );
+forward_ref_binop!( impl Add, add for NormTime, NormPeriod );
+forward_ref_binop!( impl Sub, sub for NormTime, NormPeriod );
+
+/// Subtracting a `chrono::NaiveDateTime` from a `NormTime` without converting first. Fractions of a second of the `NaiveDateTime` are preserved.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{NormTime, NormTimeDelta};
+/// use chrono::NaiveDate;
+///
+/// let ndt = NaiveDate::from_ymd_opt( 2068, 1, 1 ).unwrap().and_hms_milli_opt( 0, 0, 0, 500 ).unwrap();
+/// let ntime = NormTime::from_ymd_opt( 0, 0, 1 ).unwrap();
+///
+/// assert_eq!( ntime - ndt, NormTimeDelta::new( 99_999, 500_000_000 ).unwrap() );
+/// assert_eq!( ndt - ntime, NormTimeDelta::new( -100_000, 500_000_000 ).unwrap() );
+/// ```
+#[cfg( feature = "chrono" )]
+impl Sub<NaiveDateTime> for NormTime {
+	type Output = NormTimeDelta;
+
+	fn sub( self, other: NaiveDateTime ) -> Self::Output {
+		let other = other.and_utc();
+		let secs = self.0 - ( other.timestamp() - NORMTIME_OFFSET );
+
+		let res = match other.timestamp_subsec_nanos() {
+			0 => NormTimeDelta::new( secs, 0 ),
+			nanos => NormTimeDelta::new( secs - 1, 1_000_000_000 - nanos ),
+		};
+
+		res.expect( "Overflow in `NormTime - NaiveDateTime`" )
+	}
+}
+
+#[cfg( feature = "chrono" )]
+impl Sub<NormTime> for NaiveDateTime {
+	type Output = NormTimeDelta;
+
+	fn sub( self, other: NormTime ) -> Self::Output {
+		let this = self.and_utc();
+		let secs = ( this.timestamp() - NORMTIME_OFFSET ) - other.0;
+
+		NormTimeDelta::new( secs, this.timestamp_subsec_nanos() ).expect( "Overflow in `NaiveDateTime - NormTime`" )
+	}
+}
+
 impl fmt::Debug for NormTime {
 	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
 		write!( f, "{}N{}", self.to_string_date(), self.to_string_clock() )
@@ -185,9 +1377,110 @@ impl fmt::Debug for NormTime {
 ///
 /// assert_eq!( "+12345-6-7N8:9:10".parse::<NormTime>().unwrap().to_string(), "12345-06-07N08:09:10" );
 /// ```
+///
+/// Width, fill and alignment are respected:
+///
+/// ```
+/// use normtime::NormTime;
+///
+/// let d = NormTime::from_ymd_opt( 0, 1, 1).unwrap();
+/// assert_eq!( format!( "{:>21}|", d ), "  0000-01-01N00:00:00|" );
+/// assert_eq!( format!( "{:*^23}", d ), "**0000-01-01N00:00:00**" );
+/// ```
+///
+/// The alternate form (`{:#}`) separates date and clock by a space and appends the era. Normyears before 0000 are labeled "BNE" (before norm era) and displayed without their sign, all others are labeled "NE" (norm era).
+///
+/// ```
+/// use normtime::NormTime;
+///
+/// let d = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+/// assert_eq!( format!( "{:#}", d ), "0045-03-12 08:00:00 NE" );
+///
+/// let d = NormTime::from_ymd_opt( -5, 3, 12 ).unwrap();
+/// assert_eq!( format!( "{:#}", d ), "0005-03-12 00:00:00 BNE" );
+/// ```
 impl fmt::Display for NormTime {
 	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result {
-		fmt::Debug::fmt( self, f )
+		let date = self.to_string_date();
+		let clock = self.to_string_clock();
+
+		if !f.alternate() {
+			return f.pad( &format!( "{}N{}", date, clock ) );
+		}
+
+		match date.strip_prefix( '-' ) {
+			Some( x ) => f.pad( &format!( "{} {} BNE", x, clock ) ),
+			None => f.pad( &format!( "{} {} NE", date, clock ) ),
+		}
+	}
+}
+
+/// Displaying only a part of a `NormTime`. See [`NormTime::display_date`] and [`NormTime::display_clock`].
+enum DisplayPart {
+	Date( NormTime ),
+	Clock( NormTime ),
+}
+
+impl fmt::Display for DisplayPart {
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result {
+		match self {
+			Self::Date( x ) => {
+				let date = x.to_string_date();
+
+				if !f.alternate() {
+					return f.pad( &date );
+				}
+
+				match date.strip_prefix( '-' ) {
+					Some( x ) => f.pad( &format!( "{} BNE", x ) ),
+					None => f.pad( &format!( "{} NE", date ) ),
+				}
+			},
+			Self::Clock( x ) => f.pad( &x.to_string_clock() ),
+		}
+	}
+}
+
+/// Formats the date part according to the date pattern of `locale` (see [`NormTime::to_string_pattern_locale`]) followed by the clock.
+///
+/// # Example
+///
+/// ```
+/// use unic_langid::langid;
+/// use normtime::{DisplayLocale, NormTime};
+///
+/// let d = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+/// assert_eq!( d.to_string_locale( &langid!( "en-US" ) ), "0045-03-12 NE 08:00:00" );
+/// assert_eq!( d.to_string_locale( &langid!( "de-DE" ) ), "12.03.0045 NÄ 08:00:00" );
+/// ```
+#[cfg( feature = "i18n" )]
+impl DisplayLocale for NormTime {
+	fn to_string_locale( &self, locale: &LanguageIdentifier ) -> String {
+		format!( "{} {}", self.to_string_pattern_locale( locale ), self.to_string_clock() )
+	}
+}
+
+#[cfg( feature = "defmt" )]
+impl defmt::Format for NormTime {
+	/// Formats `self` like `Display` does, e.g. `0123-04-05N06:07:08`, without using `core::fmt`.
+	fn format( &self, f: defmt::Formatter ) {
+		let year = self.0.div_euclid( DUR_NORMYEAR );
+		let subyear = self.0.rem_euclid( DUR_NORMYEAR );
+		let month = subyear.div_euclid( DUR_NORMMONTH ) as u8;
+		let submonth = subyear.rem_euclid( DUR_NORMMONTH );
+		let day = submonth.div_euclid( DUR_NORMDAY ) as u8;
+		let subday = self.0.rem_euclid( DUR_NORMDAY );
+		let hour = subday.div_euclid( 3600 ) as u8;
+		let minute = subday.rem_euclid( 3600 ).div_euclid( 60 ) as u8;
+		let second = subday.rem_euclid( 60 ) as u8;
+
+		let sign = if year < 0 { "-" } else { "" };
+
+		defmt::write!(
+			f,
+			"{=str}{=u64:04}-{=u8:02}-{=u8:02}N{=u8:02}:{=u8:02}:{=u8:02}",
+			sign, year.unsigned_abs(), month, day, hour, minute, second
+		)
 	}
 }
 
@@ -204,6 +1497,7 @@ impl fmt::Display for NormTime {
 ///     NormTime::from_ymd_opt( 0, 0, 0 ).unwrap().and_hms( 0, 0, 1 )
 /// );
 /// ```
+#[cfg( feature = "chrono" )]
 impl From<NaiveDateTime> for NormTime {
 	fn from( item: NaiveDateTime ) -> Self {
 		Self( item.and_utc().timestamp() - NORMTIME_OFFSET )
@@ -223,6 +1517,7 @@ impl From<NaiveDateTime> for NormTime {
 ///     NormTime::from_ymd_opt( 0, 0, 0 ).unwrap().and_hms( 0, 0, 0 )
 /// );
 /// ```
+#[cfg( feature = "chrono" )]
 impl From<NaiveDate> for NormTime {
 	fn from( item: NaiveDate ) -> Self {
 		Self::from( item.and_time( NaiveTime::from_num_seconds_from_midnight_opt( 0, 0 ).unwrap() ) )
@@ -242,6 +1537,7 @@ impl From<NaiveDate> for NormTime {
 ///     NaiveDate::from_ymd_opt( 2068, 1, 1 ).unwrap().and_hms_opt( 0, 0, 1 ).unwrap()
 /// );
 /// ```
+#[cfg( feature = "chrono" )]
 impl From<NormTime> for NaiveDateTime {
 	fn from( item: NormTime ) -> Self {
 		DateTime::from_timestamp( item.timestamp(), 0 ).unwrap().naive_utc()
@@ -266,68 +1562,238 @@ impl From<NormTime> for NaiveDateTime {
 ///     NaiveDate::from_ymd_opt( 2068, 1, 1 ).unwrap()
 /// );
 /// ```
+#[cfg( feature = "chrono" )]
 impl From<NormTime> for NaiveDate {
 	fn from( item: NormTime ) -> Self {
 		NaiveDateTime::from( item ).date()
 	}
 }
 
-/// Parsing a `str` into a `NormTime`. The string must be formatted as `YYYY-M-DD` or `YYYY-M-DDNhh:mm:ss`.
-/// * `YYYY` Arbitrary integer number. Can have more or less than four digits, but 4 digits is typical.
-/// * `M` Unsigned integer number between 0 and 9. More than one digit is allowed (leading zeros), but untypical.
-/// * `DD` Unsigned integer number between 0 and 29. Can have more or less than two digits (leading zeros), but 2 digits is typical.
-/// * `hh` Hour
-/// * `mm` Minute
-/// * `ss` Second
-///
-/// # Example
-///
-/// ```
-/// use normtime::NormTime;
-///
-/// let d = NormTime::from_ymd_opt( 900, 3, 12).unwrap();
-/// assert_eq!( "0900-03-12".parse::<NormTime>(), Ok( d ) );
-///
-/// let d = NormTime::from_ymd_opt( 12345, 6, 7 ).unwrap();
-/// assert_eq!( "+12345-6-7".parse::<NormTime>(), Ok( d ) );
-///
-/// let d = NormTime::from_ymd_opt( 12345, 6, 7 ).unwrap().and_hms( 8, 9, 10 );
-/// assert_eq!( "+12345-6-7N8:9:10".parse::<NormTime>(), Ok( d ) );
-///
-/// assert!( "foo".parse::<NormTime>().is_err() );
-/// ```
-impl FromStr for NormTime {
-	type Err = TimeError;
+/// Returns `value` if it is within 0 and `max`. Otherwise returns an error naming `field`.
+fn check_range( field: &'static str, value: i64, max: i64 ) -> Result<i64, TimeError> {
+	if !( 0..=max ).contains( &value ) {
+		return Err( TimeError::OutOfRange { field, value, max } );
+	}
 
-	fn from_str( s: &str ) -> Result<Self, Self::Err> {
-		let elems: Vec<&str> = s.split( 'N' ).collect();
-		if elems.is_empty() || elems.len() > 2 {
-			return Err( TimeError::ParseError( s.to_string() ) )
-		}
+	Ok( value )
+}
 
-		let elems_date: Vec<&str> = elems[0].split( '-' ).collect();
-		if elems_date.len() != 3 {
-			return Err( TimeError::ParseError( s.to_string() ) )
+/// Parses the normtime `s` according to `options`.
+fn parse_normtime( s: &str, options: &ParseOptions ) -> Result<NormTime, TimeError> {
+	let check = |field, value, max| if options.strict { check_range( field, value, max ) } else { Ok( value ) };
+	let error = || TimeError::ParseError( s.to_string() );
+	let overflow = || TimeError::Overflow( s.to_string() );
+
+	// Before the norm era, the normyear is written without sign.
+	let ( text, before_era ) = match ( s.strip_suffix( " BNE" ), s.strip_suffix( " NE" ) ) {
+		( Some( x ), _ ) if options.allow_era => ( x, true ),
+		( _, Some( x ) ) if options.allow_era => ( x, false ),
+		_ => ( s, false ),
+	};
+
+	// The weekday is checked after the date is known.
+	let weekday = options.weekday_names.as_ref()
+		.and_then( |names| text.split_once( ' ' ).and_then( |( x, y )| Some( ( names.parse( x )?, y ) ) ) );
+	let text = weekday.map_or( text, |( _, x )| x );
+
+	let separators: &[char] = if options.alternative_separators { &[ 'N', 'T', ' ' ] } else { &[ 'N' ] };
+	let ( date, clock ) = match text.split_once( separators ) {
+		Some( ( x, y ) ) => ( x, Some( y ) ),
+		None if options.allow_missing_clock => ( text, None ),
+		None => return Err( error() ),
+	};
+
+	// The normyear may have a leading sign, which must not be confused with the separator.
+	let ( sign, unsigned ) = match date.strip_prefix( '-' ) {
+		Some( x ) => ( -1, x ),
+		None => ( 1, date.strip_prefix( '+' ).unwrap_or( date ) ),
+	};
+	if !unsigned.starts_with( |c: char| c.is_ascii_digit() ) || ( before_era && unsigned.len() != date.len() ) {
+		return Err( error() )
+	}
+	let sign = if before_era { -1 } else { sign };
+
+	let month_name = |x| options.month_names.as_ref().and_then( |names| names.parse( x ) );
+
+	let mut elems_date = unsigned.split( '-' );
+	let seconds = match ( elems_date.next(), elems_date.next(), elems_date.next(), elems_date.next() ) {
+		( Some( year ), Some( month ), Some( day ), None ) if !month.starts_with( 'W' ) || month_name( month ).is_some() => {
+			let month = match month_name( month ) {
+				Some( x ) => i64::from( x ),
+				None => month.parse::<i64>()?,
+			};
+			sum_fields( &[
+				( sign * year.parse::<i64>()?, DUR_NORMYEAR ),
+				( check( "normmonth", month, 9 )?, DUR_NORMMONTH ),
+				( check( "normday", day.parse::<i64>()?, 29 )?, DUR_NORMDAY ),
+			] )
+		},
+		( Some( year ), Some( week ), Some( day ), None ) if options.allow_ordinal_week => {
+			let week = week.strip_prefix( 'W' ).ok_or_else( error )?;
+			sum_fields( &[
+				( sign * year.parse::<i64>()?, DUR_NORMYEAR ),
+				( check( "normweek", week.parse::<i64>()?, DUR_NORMYEAR / DUR_NORMWEEK - 1 )?, DUR_NORMWEEK ),
+				( check( "normday", day.parse::<i64>()?, DUR_NORMWEEK / DUR_NORMDAY - 1 )?, DUR_NORMDAY ),
+			] )
+		},
+		( Some( year ), Some( day ), None, None ) if options.allow_ordinal_week => {
+			sum_fields( &[
+				( sign * year.parse::<i64>()?, DUR_NORMYEAR ),
+				( check( "normday", day.parse::<i64>()?, DUR_NORMYEAR / DUR_NORMDAY - 1 )?, DUR_NORMDAY ),
+			] )
+		},
+		_ => return Err( error() ),
+	};
+
+	if let Some( ( weekday, _ ) ) = weekday {
+		if i128::from( weekday ) != seconds.rem_euclid( DUR_NORMWEEK.into() ) / i128::from( DUR_NORMDAY ) {
+			return Err( error() )
 		}
+	}
+
+	let Some( clock ) = clock else {
+		return i64::try_from( seconds ).map( NormTime ).map_err( |_| overflow() );
+	};
+
+	let mut elems_time = clock.split( ':' );
+	let ( Some( hour ), Some( minute ), Some( second ), None ) = ( elems_time.next(), elems_time.next(), elems_time.next(), elems_time.next() ) else {
+		return Err( error() )
+	};
+
+	let hour = check( "hour", hour.parse::<i64>()?, ( DUR_NORMDAY - 1 ) / DUR_HOUR )?;
+	let minute = check( "minute", minute.parse::<i64>()?, 59 )?;
+	let second = check( "second", second.parse::<i64>()?, 59 )?;
+	let subday = i64::try_from( sum_fields( &[ ( hour, DUR_HOUR ), ( minute, DUR_MINUTE ), ( second, 1 ) ] ) ).map_err( |_| overflow() )?;
+	let subday = check( "second of normday", subday, DUR_NORMDAY - 1 )?;
+
+	// The range of `NormTime` is checked only once for the total, since the seconds of the earliest normyears alone exceed an `i64`.
+	i64::try_from( seconds + i128::from( subday ) ).map( NormTime ).map_err( |_| overflow() )
+}
+
+/// Returns the sum of the `( count, seconds )` pairs in `parts`. The products of two `i64` and the sum of a few of them always fit into an `i128`.
+fn sum_fields( parts: &[( i64, i64 )] ) -> i128 {
+	parts.iter()
+		.map( |( count, secs )| i128::from( *count ) * i128::from( *secs ) )
+		.sum()
+}
 
-		let mut seconds = elems_date[0].parse::<i64>()? * DUR_NORMYEAR;
-		seconds += elems_date[1].parse::<i64>()? * DUR_NORMMONTH;
-		seconds += elems_date[2].parse::<i64>()? * DUR_NORMDAY;
+/// Returns the number written by the ASCII digits `bytes[start..end]`. Returns `None` if there are no digits, if any byte is no digit or on overflow.
+const fn parse_digits_const( bytes: &[u8], start: usize, end: usize ) -> Option<i64> {
+	if start >= end || end > bytes.len() {
+		return None;
+	}
 
-		let Some( elems_t ) = elems.get( 1 ) else {
-			return Ok( NormTime( seconds ) );
+	let mut res: i64 = 0;
+	let mut i = start;
+	while i < end {
+		if !bytes[i].is_ascii_digit() {
+			return None;
+		}
+		res = match res.checked_mul( 10 ) {
+			Some( x ) => match x.checked_add( ( bytes[i] - b'0' ) as i64 ) {
+				Some( y ) => y,
+				None => return None,
+			},
+			None => return None,
 		};
+		i += 1;
+	}
+
+	Some( res )
+}
+
+/// The const parser behind [`NormTime::parse_const`]. Only plain loops and matches are available in const contexts, so the fields are located by their fixed offsets after the normyear.
+const fn parse_normtime_const( s: &str ) -> Option<NormTime> {
+	let bytes = s.as_bytes();
+	let len = bytes.len();
+
+	let negative = len > 0 && bytes[0] == b'-';
+	let start = if negative { 1 } else { 0 };
+
+	let mut year_end = start;
+	while year_end < len && bytes[year_end] != b'-' {
+		year_end += 1;
+	}
+
+	// The date part is `YYYY-MM-DD` with at least four digits for the normyear.
+	if year_end - start < 4 || year_end + 6 > len || bytes[year_end + 3] != b'-' {
+		return None;
+	}
+	let ( Some( year ), Some( month ), Some( day ) ) = (
+		parse_digits_const( bytes, start, year_end ),
+		parse_digits_const( bytes, year_end + 1, year_end + 3 ),
+		parse_digits_const( bytes, year_end + 4, year_end + 6 ),
+	) else {
+		return None;
+	};
+	if month > 9 || day > 29 {
+		return None;
+	}
 
-		let elems_time: Vec<&str> = elems_t.split( ':' ).collect();
-		if elems_time.len() != 3 {
-			return Err( TimeError::ParseError( s.to_string() ) )
+	let date_end = year_end + 6;
+	let subday = if date_end == len {
+		0
+	} else {
+		if date_end + 9 != len || bytes[date_end] != b'N' || bytes[date_end + 3] != b':' || bytes[date_end + 6] != b':' {
+			return None;
+		}
+		let ( Some( hour ), Some( minute ), Some( second ) ) = (
+			parse_digits_const( bytes, date_end + 1, date_end + 3 ),
+			parse_digits_const( bytes, date_end + 4, date_end + 6 ),
+			parse_digits_const( bytes, date_end + 7, date_end + 9 ),
+		) else {
+			return None;
+		};
+		if minute > 59 || second > 59 {
+			return None;
 		}
 
-		seconds += elems_time[0].parse::<i64>()? * 3600;
-		seconds += elems_time[1].parse::<i64>()? * 60;
-		seconds += elems_time[2].parse::<i64>()?;
+		hour * DUR_HOUR + minute * DUR_MINUTE + second
+	};
+	if subday >= DUR_NORMDAY {
+		return None;
+	}
 
-		Ok( NormTime( seconds ) )
+	// Like `parse_normtime`, the total is calculated as `i128`, since the seconds of the earliest normyears alone exceed an `i64`.
+	let year = if negative { -( year as i128 ) } else { year as i128 };
+	let secs = year * DUR_NORMYEAR as i128 + ( month * DUR_NORMMONTH + day * DUR_NORMDAY + subday ) as i128;
+	if secs < i64::MIN as i128 || secs > i64::MAX as i128 {
+		return None;
+	}
+
+	Some( NormTime( secs as i64 ) )
+}
+
+/// Parsing a `str` into a `NormTime`. The string must be formatted as `YYYY-M-DD` or `YYYY-M-DDNhh:mm:ss`.
+/// * `YYYY` Arbitrary integer number. Can have more or less than four digits, but 4 digits is typical.
+/// * `M` Unsigned integer number between 0 and 9. More than one digit is allowed (leading zeros), but untypical.
+/// * `DD` Unsigned integer number between 0 and 29. Can have more or less than two digits (leading zeros), but 2 digits is typical.
+/// * `hh` Hour
+/// * `mm` Minute
+/// * `ss` Second
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormTime;
+///
+/// let d = NormTime::from_ymd_opt( 900, 3, 12).unwrap();
+/// assert_eq!( "0900-03-12".parse::<NormTime>(), Ok( d ) );
+///
+/// let d = NormTime::from_ymd_opt( 12345, 6, 7 ).unwrap();
+/// assert_eq!( "+12345-6-7".parse::<NormTime>(), Ok( d ) );
+///
+/// let d = NormTime::from_ymd_opt( 12345, 6, 7 ).unwrap().and_hms( 8, 9, 10 );
+/// assert_eq!( "+12345-6-7N8:9:10".parse::<NormTime>(), Ok( d ) );
+///
+/// assert!( "foo".parse::<NormTime>().is_err() );
+/// ```
+impl FromStr for NormTime {
+	type Err = TimeError;
+
+	/// Parses a normtime. Fields exceeding their range are rolled into the next greater unit, e.g. `0001-00-30` is equal to `0001-01-00`. Use [`NormTime::parse_strict`] to reject those.
+	fn from_str( s: &str ) -> Result<Self, Self::Err> {
+		parse_normtime( s, &ParseOptions::new() )
 	}
 }
 
@@ -385,6 +1851,300 @@ mod normtime_serde {
 }
 
 
+// `BigInt` stores the number of seconds since 0000-00-00N00:00:00, `Timestamp` stores the equivalent standard time. Writing a `Timestamp` is supported by all backends serializing into raw bytes (PostgreSQL, MySQL).
+#[cfg( feature = "diesel" )]
+mod normtime_diesel {
+	use super::{NormTime, NORMTIME_OFFSET};
+
+	use chrono::{DateTime, NaiveDateTime};
+	use diesel::backend::Backend;
+	use diesel::deserialize::{self, FromSql};
+	use diesel::query_builder::bind_collector::RawBytesBindCollector;
+	use diesel::serialize::{self, Output, ToSql};
+	use diesel::sql_types::{BigInt, Timestamp};
+
+	impl<DB> ToSql<BigInt, DB> for NormTime
+	where
+		DB: Backend,
+		i64: ToSql<BigInt, DB>,
+	{
+		fn to_sql<'b>( &'b self, out: &mut Output<'b, '_, DB> ) -> serialize::Result {
+			self.0.to_sql( out )
+		}
+	}
+
+	impl<DB> FromSql<BigInt, DB> for NormTime
+	where
+		DB: Backend,
+		i64: FromSql<BigInt, DB>,
+	{
+		fn from_sql( bytes: DB::RawValue<'_> ) -> deserialize::Result<Self> {
+			Ok( Self( i64::from_sql( bytes )? ) )
+		}
+	}
+
+	impl<DB> ToSql<Timestamp, DB> for NormTime
+	where
+		for<'c> DB: Backend<BindCollector<'c> = RawBytesBindCollector<DB>>,
+		NaiveDateTime: ToSql<Timestamp, DB>,
+	{
+		fn to_sql<'b>( &'b self, out: &mut Output<'b, '_, DB> ) -> serialize::Result {
+			let dtime = self.0.checked_add( NORMTIME_OFFSET )
+				.and_then( |x| DateTime::from_timestamp( x, 0 ) )
+				.ok_or_else( || format!( "NormTime out of range of Timestamp: {}", self ) )?
+				.naive_utc();
+			dtime.to_sql( &mut out.reborrow() )
+		}
+	}
+
+	impl<DB> FromSql<Timestamp, DB> for NormTime
+	where
+		DB: Backend,
+		NaiveDateTime: FromSql<Timestamp, DB>,
+	{
+		fn from_sql( bytes: DB::RawValue<'_> ) -> deserialize::Result<Self> {
+			Ok( Self::from( NaiveDateTime::from_sql( bytes )? ) )
+		}
+	}
+}
+
+
+// `NormTime` is written as the number of seconds since 0000-00-00N00:00:00. Reading accepts this integer as well as text formatted like `Display` (or holding the integer).
+#[cfg( feature = "rusqlite" )]
+mod normtime_rusqlite {
+	use super::NormTime;
+
+	use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+	impl ToSql for NormTime {
+		fn to_sql( &self ) -> rusqlite::Result<ToSqlOutput<'_>> {
+			Ok( ToSqlOutput::from( self.0 ) )
+		}
+	}
+
+	impl FromSql for NormTime {
+		fn column_result( value: ValueRef<'_> ) -> FromSqlResult<Self> {
+			match value {
+				ValueRef::Integer( secs ) => Ok( Self( secs ) ),
+				ValueRef::Text( _ ) => {
+					let text = value.as_str()?;
+
+					if let Ok( secs ) = text.parse::<i64>() {
+						return Ok( Self( secs ) );
+					}
+
+					text.parse().map_err( FromSqlError::other )
+				},
+				_ => Err( FromSqlError::InvalidType ),
+			}
+		}
+	}
+}
+
+
+// `NormTime` is stored as `BigInt` as the number of seconds since 0000-00-00N00:00:00.
+#[cfg( feature = "sea-orm" )]
+mod normtime_sea_orm {
+	use super::NormTime;
+
+	use sea_orm::sea_query::{ArrayType, ColumnType, Nullable, Value, ValueType, ValueTypeErr};
+	use sea_orm::{ColIdx, QueryResult, TryGetError, TryGetable};
+
+	impl From<NormTime> for Value {
+		fn from( item: NormTime ) -> Self {
+			Value::BigInt( Some( item.0 ) )
+		}
+	}
+
+	impl TryGetable for NormTime {
+		fn try_get_by<I: ColIdx>( res: &QueryResult, index: I ) -> Result<Self, TryGetError> {
+			i64::try_get_by( res, index ).map( Self )
+		}
+	}
+
+	impl ValueType for NormTime {
+		fn try_from( v: Value ) -> Result<Self, ValueTypeErr> {
+			<i64 as ValueType>::try_from( v ).map( Self )
+		}
+
+		fn type_name() -> String {
+			"NormTime".to_string()
+		}
+
+		fn array_type() -> ArrayType {
+			ArrayType::BigInt
+		}
+
+		fn column_type() -> ColumnType {
+			ColumnType::BigInteger
+		}
+	}
+
+	impl Nullable for NormTime {
+		fn null() -> Value {
+			Value::BigInt( None )
+		}
+	}
+}
+
+
+// `NormTime` converts to `bson::DateTime` via its Unix timestamp. Since `bson::DateTime` has millisecond precision, instants outside its range are clamped.
+#[cfg( feature = "bson" )]
+mod normtime_bson {
+	use super::NormTime;
+
+	use crate::NORMTIME_OFFSET;
+
+	impl From<NormTime> for bson::DateTime {
+		fn from( item: NormTime ) -> Self {
+			bson::DateTime::from_millis( item.timestamp().saturating_mul( 1000 ) )
+		}
+	}
+
+	impl From<bson::DateTime> for NormTime {
+		fn from( item: bson::DateTime ) -> Self {
+			Self( item.timestamp_millis().div_euclid( 1000 ) - NORMTIME_OFFSET )
+		}
+	}
+
+	impl From<NormTime> for bson::Bson {
+		fn from( item: NormTime ) -> Self {
+			bson::Bson::DateTime( item.into() )
+		}
+	}
+}
+
+
+// `INT8` stores the number of seconds since 0000-00-00N00:00:00, `TIMESTAMP` and `TIMESTAMPTZ` store the equivalent standard time (UTC).
+#[cfg( feature = "postgres" )]
+mod normtime_postgres {
+	use super::NormTime;
+
+	use std::error::Error;
+
+	use bytes::BytesMut;
+	use chrono::{DateTime, NaiveDateTime, Utc};
+	use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+	impl ToSql for NormTime {
+		fn to_sql( &self, ty: &Type, out: &mut BytesMut ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+			match *ty {
+				Type::TIMESTAMP => NaiveDateTime::from( *self ).to_sql( ty, out ),
+				Type::TIMESTAMPTZ => NaiveDateTime::from( *self ).and_utc().to_sql( ty, out ),
+				_ => self.0.to_sql( ty, out ),
+			}
+		}
+
+		accepts!( INT8, TIMESTAMP, TIMESTAMPTZ );
+
+		to_sql_checked!();
+	}
+
+	impl<'a> FromSql<'a> for NormTime {
+		fn from_sql( ty: &Type, raw: &'a [u8] ) -> Result<Self, Box<dyn Error + Sync + Send>> {
+			match *ty {
+				Type::TIMESTAMP => Ok( Self::from( NaiveDateTime::from_sql( ty, raw )? ) ),
+				Type::TIMESTAMPTZ => Ok( Self::from( DateTime::<Utc>::from_sql( ty, raw )?.naive_utc() ) ),
+				_ => Ok( Self( i64::from_sql( ty, raw )? ) ),
+			}
+		}
+
+		accepts!( INT8, TIMESTAMP, TIMESTAMPTZ );
+	}
+}
+
+
+// `NormTime` is a `#[repr( transparent )]` wrapper of `i64`, for which every bit pattern is valid.
+#[cfg( feature = "bytemuck" )]
+mod normtime_bytemuck {
+	use super::NormTime;
+
+	unsafe impl bytemuck::Zeroable for NormTime {}
+
+	unsafe impl bytemuck::Pod for NormTime {}
+
+	unsafe impl bytemuck::TransparentWrapper<i64> for NormTime {}
+}
+
+
+// Generated `NormTime`s are restricted to those, whose Unix timestamp can be represented by an `i64`.
+#[cfg( feature = "arbitrary" )]
+mod normtime_arbitrary {
+	use super::NormTime;
+
+	use arbitrary::{Arbitrary, Unstructured};
+
+	use crate::NORMTIME_OFFSET;
+
+	impl<'a> Arbitrary<'a> for NormTime {
+		fn arbitrary( u: &mut Unstructured<'a> ) -> arbitrary::Result<Self> {
+			Ok( Self( u.int_in_range( i64::MIN..=i64::MAX - NORMTIME_OFFSET )? ) )
+		}
+
+		fn size_hint( depth: usize ) -> ( usize, Option<usize> ) {
+			i64::size_hint( depth )
+		}
+	}
+}
+
+
+// Generated `NormTime`s are restricted to those, whose Unix timestamp can be represented by an `i64`. Shrinking approaches 0000-00-00N00:00:00.
+#[cfg( feature = "quickcheck" )]
+mod normtime_quickcheck {
+	use super::NormTime;
+
+	use quickcheck::{Arbitrary, Gen};
+
+	use crate::NORMTIME_OFFSET;
+
+	impl Arbitrary for NormTime {
+		fn arbitrary( g: &mut Gen ) -> Self {
+			Self( i64::arbitrary( g ).min( i64::MAX - NORMTIME_OFFSET ) )
+		}
+
+		fn shrink( &self ) -> Box<dyn Iterator<Item = Self>> {
+			Box::new( self.0.shrink().map( Self ) )
+		}
+	}
+}
+
+
+// JavaScript dates are milliseconds since the Unix epoch. Converting them into `NormTime` drops the subsecond part.
+#[cfg( feature = "wasm" )]
+mod normtime_wasm {
+	use super::NormTime;
+
+	use js_sys::wasm_bindgen::JsValue;
+
+	use crate::NORMTIME_OFFSET;
+
+	impl NormTime {
+		/// Returns `self` as a JavaScript `Date`.
+		///
+		/// This method is only available, if the **`wasm`** feature has been enabled.
+		pub fn to_js_date( &self ) -> js_sys::Date {
+			js_sys::Date::new( &JsValue::from_f64( self.timestamp() as f64 * 1000.0 ) )
+		}
+	}
+
+	impl From<js_sys::Date> for NormTime {
+		fn from( item: js_sys::Date ) -> Self {
+			Self( ( item.get_time() / 1000.0 ).floor() as i64 - NORMTIME_OFFSET )
+		}
+	}
+
+	impl From<NormTime> for js_sys::Date {
+		fn from( item: NormTime ) -> Self {
+			item.to_js_date()
+		}
+	}
+}
+
+
+#[cfg( feature = "uniffi" )]
+uniffi::custom_newtype!( NormTime, i64 );
+
+
 
 
 //=============================================================================
@@ -395,10 +2155,13 @@ mod normtime_serde {
 mod tests {
 	use super::*;
 
+	#[cfg( feature = "chrono" )] use chrono::TimeDelta;
+
 	#[cfg( feature = "serde" )]
 	use serde_test::{Token, assert_tokens};
 
 	#[test]
+	#[cfg( feature = "chrono" )]
 	fn create_normtime() {
 		// Unix-time zero.
 		let time_unix_zero = DateTime::from_timestamp( 0, 0 ).unwrap().naive_utc();
@@ -411,6 +2174,312 @@ mod tests {
 	}
 
 	#[test]
+	fn from_ymd_hms() {
+		assert_eq!( NormTime::from_ymd_hms_opt( -1, 9, 29, 27, 46, 39 ), Some( NormTime( -1 ) ) );
+		assert_eq!( NormTime::from_ymd_hms_opt( 0, 0, 0, 0, 0, 0 ), Some( NormTime( 0 ) ) );
+		assert_eq!( NormTime::from_ymd_hms_opt( 0, 10, 0, 0, 0, 0 ), None );
+		assert_eq!( NormTime::from_ymd_hms_opt( 0, 0, 0, 28, 0, 0 ), None );
+		assert_eq!( NormTime::from_ymd_hms_opt( 0, 0, 0, 0, 0, 60 ), None );
+		assert_eq!( NormTime::from_ymd_hms_opt( 0, 0, 0, u32::MAX, 0, 0 ), None );
+		assert_eq!( NormTime( i64::MAX ).and_hms_opt( 0, 0, 1 ), None );
+		assert_eq!( NormTime( i64::MAX - 1 ).and_hms_opt( 0, 0, 1 ), Some( NormTime( i64::MAX ) ) );
+	}
+
+	#[test]
+	fn from_week_date() {
+		assert_eq!( NormTime::from_week_date( -1, 29, 9 ), Some( NormTime( -DUR_NORMDAY ) ) );
+		assert_eq!( NormTime::from_week_date( 0, 0, 0 ), Some( NormTime( 0 ) ) );
+		assert_eq!( NormTime::from_week_date( 0, 0, 10 ), None );
+		assert_eq!( NormTime::from_week_date( 0, u32::MAX, 0 ), None );
+
+		let t = NormTime::from_ymd_opt( -12, 7, 24 ).unwrap();
+		assert_eq!( NormTime::parse_format( &t.to_string_format( NormTimeFormat::WeekDate ), NormTimeFormat::WeekDate ), Ok( t ) );
+		assert_eq!( NormTime::from_week_date( -12, 23, 4 ), Some( t ) );
+	}
+
+	#[test]
+	fn from_ordinal_date() {
+		assert_eq!( NormTime::from_ordinal_date( -1, 299 ), Some( NormTime( -DUR_NORMDAY ) ) );
+		assert_eq!( NormTime::from_ordinal_date( 0, 0 ), Some( NormTime( 0 ) ) );
+		assert_eq!( NormTime::from_ordinal_date( 0, u32::MAX ), None );
+
+		let t = NormTime::from_ymd_opt( -12, 7, 24 ).unwrap();
+		assert_eq!( NormTime::parse_format( &t.to_string_format( NormTimeFormat::Ordinal ), NormTimeFormat::Ordinal ), Ok( t ) );
+		assert_eq!( NormTime::from_ordinal_date( -12, 234 ), Some( t ) );
+	}
+
+	#[test]
+	fn normtime_from_timestamp() {
+		assert_eq!( NormTime::from_timestamp( NORMTIME_OFFSET ), NormTime::from_ymd_opt( 0, 0, 0 ) );
+		assert_eq!( NormTime::from_timestamp( NORMTIME_OFFSET + 1 ).unwrap().timestamp(), NORMTIME_OFFSET + 1 );
+		assert!( NormTime::from_timestamp( i64::MIN ).is_none() );
+	}
+
+	#[test]
+	fn normtime_now() {
+		let before = SystemTime::now().duration_since( UNIX_EPOCH ).unwrap().as_secs() as i64;
+		let now = NormTime::now();
+		let after = SystemTime::now().duration_since( UNIX_EPOCH ).unwrap().as_secs() as i64;
+
+		assert!( ( before..=after ).contains( &now.timestamp() ) );
+	}
+
+	#[test]
+	fn parse_negative_normyear() {
+		assert_eq!( "-0005-03-12".parse::<NormTime>().unwrap(), NormTime::from_ymd_opt( -5, 3, 12 ).unwrap() );
+		assert_eq!( "+0005-03-12".parse::<NormTime>().unwrap(), NormTime::from_ymd_opt( 5, 3, 12 ).unwrap() );
+
+		// Around the epoch.
+		assert_eq!( "-0001-09-29N27:46:39".parse::<NormTime>().unwrap(), NormTime( -1 ) );
+		assert_eq!( "0000-00-00N00:00:00".parse::<NormTime>().unwrap(), NormTime( 0 ) );
+		assert_eq!( "-0000-00-00N00:00:00".parse::<NormTime>().unwrap(), NormTime( 0 ) );
+		assert_eq!( "0000-00-00N00:00:01".parse::<NormTime>().unwrap(), NormTime( 1 ) );
+		for secs in [ -DUR_NORMYEAR - 1, -DUR_NORMYEAR, -DUR_NORMDAY, -1, 0, 1, DUR_NORMYEAR ] {
+			assert_eq!( NormTime( secs ).to_string().parse::<NormTime>().unwrap(), NormTime( secs ) );
+		}
+
+		assert!( "--0005-03-12".parse::<NormTime>().is_err() );
+		assert!( "-+0005-03-12".parse::<NormTime>().is_err() );
+		assert!( "-03-12".parse::<NormTime>().is_err() );
+	}
+
+	#[test]
+	fn parse_field_count() {
+		assert!( "0001-02".parse::<NormTime>().is_err() );
+		assert!( "0001-02-03-04".parse::<NormTime>().is_err() );
+		assert!( "0001-02-03N04:05".parse::<NormTime>().is_err() );
+		assert!( "0001-02-03N04:05:06:07".parse::<NormTime>().is_err() );
+	}
+
+	#[test]
+	fn parse_overflow() {
+		let overflow = |x: &str| TimeError::Overflow( x.to_string() );
+		assert_eq!( "99999999999999-0-0".parse::<NormTime>(), Err( overflow( "99999999999999-0-0" ) ) );
+		assert_eq!( "-99999999999999-0-0".parse::<NormTime>(), Err( overflow( "-99999999999999-0-0" ) ) );
+		assert_eq!( "0-99999999999999-0".parse::<NormTime>(), Err( overflow( "0-99999999999999-0" ) ) );
+		assert_eq!( "0-0-0N9999999999999999:0:0".parse::<NormTime>(), Err( overflow( "0-0-0N9999999999999999:0:0" ) ) );
+		assert_eq!( "307445734561-0-0N99999999:0:0".parse::<NormTime>(), Err( overflow( "307445734561-0-0N99999999:0:0" ) ) );
+		assert_eq!( "307445734561-0-0".parse::<NormTime>(), Ok( NormTime( 307_445_734_561 * DUR_NORMYEAR ) ) );
+	}
+
+	#[test]
+	fn parse_extremes() {
+		for time in [ NormTime( i64::MIN ), NormTime( i64::MIN + 1 ), NormTime( i64::MAX ), NormTime( i64::MAX - 1 ) ] {
+			assert_eq!( time.to_string().parse::<NormTime>(), Ok( time ) );
+			assert_eq!( NormTime::parse_strict( &time.to_string() ), Ok( time ) );
+			assert_eq!( parse_normtime_const( &time.to_string() ), Some( time ) );
+		}
+
+		assert_eq!( NormTime( i64::MIN ).to_string(), "-307445734562-01-22N06:43:12" );
+		assert_eq!( "-307445734562-01-22N06:43:11".parse::<NormTime>(), Err( TimeError::Overflow( "-307445734562-01-22N06:43:11".to_string() ) ) );
+		assert_eq!( parse_normtime_const( "-307445734562-01-22N06:43:11" ), None );
+		assert_eq!( parse_normtime_const( "-307445734562-00-00" ), None );
+	}
+
+	#[test]
+	fn string_formats() {
+		let styles = [
+			NormTimeFormat::DateTime,
+			NormTimeFormat::Date,
+			NormTimeFormat::Time,
+			NormTimeFormat::Ordinal,
+			NormTimeFormat::WeekDate,
+			NormTimeFormat::Compact,
+			NormTimeFormat::Sortable,
+		];
+
+		for time in [ NormTime( 0 ), NormTime( -1 ), NormTime::from_ymd_opt( 12345, 9, 29 ).unwrap().and_hms( 27, 46, 39 ), NormTime( i64::MIN / 2 ), NormTime( i64::MAX / 2 ) ] {
+			for style in styles {
+				let text = time.to_string_format( style );
+				let res = NormTime::parse_format( &text, style ).unwrap();
+				assert_eq!( res.to_string_format( style ), text );
+			}
+		}
+
+		assert_eq!( NormTime( -1 ).to_string_format( NormTimeFormat::Compact ), "-00010929N274639" );
+		assert_eq!( NormTime::parse_format( "-00010929N274639", NormTimeFormat::Compact ), Ok( NormTime( -1 ) ) );
+		assert!( NormTime::parse_format( "0045-3-12", NormTimeFormat::Date ).is_err() );
+		assert!( NormTime::parse_format( "0045-300", NormTimeFormat::Ordinal ).is_err() );
+		assert!( NormTime::parse_format( "0045ä0312N080000", NormTimeFormat::Compact ).is_err() );
+
+		assert_eq!( NormTime( -1 ).to_string_format( NormTimeFormat::Sortable ), "0999999999999-09-29T274639" );
+		assert!( NormTime::parse_format( "-999999999999-09-29T274639", NormTimeFormat::Sortable ).is_err() );
+
+		let mut times = [ NormTime( i64::MAX ), NormTime( 0 ), NormTime( -1 ), NormTime( i64::MIN ), NormTime( -DUR_NORMYEAR ), NormTime( 1 ) ];
+		let mut keys = times.map( |x| x.to_string_format( NormTimeFormat::Sortable ) );
+		times.sort();
+		keys.sort();
+		assert_eq!( keys, times.map( |x| x.to_string_format( NormTimeFormat::Sortable ) ) );
+	}
+
+	#[test]
+	fn base36() {
+		for time in [ NormTime( 0 ), NormTime( -1 ), NormTime( i64::MIN ), NormTime( i64::MAX ) ] {
+			assert_eq!( NormTime::from_base36( &time.to_base36() ), Ok( time ) );
+		}
+
+		assert_eq!( NormTime( 0 ).to_base36(), "0" );
+		assert_eq!( NormTime( -36 ).to_base36(), "-10" );
+		assert!( NormTime::from_base36( "" ).is_err() );
+	}
+
+	#[test]
+	fn be_bytes() {
+		let mut times = [ NormTime( i64::MAX ), NormTime( 0 ), NormTime( -1 ), NormTime( i64::MIN ), NormTime( 1 ) ];
+		let mut bytes = times.map( NormTime::to_be_bytes );
+		times.sort();
+		bytes.sort();
+		assert_eq!( bytes.map( NormTime::from_be_bytes ), times );
+		assert_eq!( NormTime( 0 ).to_be_bytes(), [ 0x80, 0, 0, 0, 0, 0, 0, 0 ] );
+	}
+
+	#[test]
+	fn parse_const() {
+		for time in [ NormTime( 0 ), NormTime( -1 ), NormTime::from_ymd_opt( 12345, 9, 29 ).unwrap().and_hms( 27, 46, 39 ), NormTime( i64::MIN / 2 ) ] {
+			assert_eq!( parse_normtime_const( &time.to_string() ), Some( time ) );
+			assert_eq!( parse_normtime_const( &time.to_string_date() ), Some( NormTime( time.0 - time.0.rem_euclid( DUR_NORMDAY ) ) ) );
+		}
+
+		for text in [ "", "-", "045-03-12", "0045-3-12", "0045-03-12N", "0045-03-12T08:00:00", "0045-10-00", "0045-00-30", "0045-00-00N27:46:40", "+0045-03-12", "99999999999999999999-00-00" ] {
+			assert_eq!( parse_normtime_const( text ), None, "{}", text );
+		}
+	}
+
+	#[test]
+	fn parse_strict() {
+		assert_eq!( NormTime::parse_strict( "-0001-09-29N27:46:39" ), Ok( NormTime( -1 ) ) );
+		assert_eq!( NormTime::parse_strict( "0001-02-03" ), "0001-02-03".parse() );
+
+		assert_eq!( NormTime::parse_strict( "0001-10-00" ), Err( TimeError::OutOfRange { field: "normmonth", value: 10, max: 9 } ) );
+		assert_eq!( NormTime::parse_strict( "0001-00-00N28:00:00" ), Err( TimeError::OutOfRange { field: "hour", value: 28, max: 27 } ) );
+		assert_eq!( NormTime::parse_strict( "0001-00-00N00:60:00" ), Err( TimeError::OutOfRange { field: "minute", value: 60, max: 59 } ) );
+		assert_eq!( NormTime::parse_strict( "0001-00-00N00:00:60" ), Err( TimeError::OutOfRange { field: "second", value: 60, max: 59 } ) );
+		assert_eq!(
+			NormTime::parse_strict( "0001-00-00N27:46:40" ),
+			Err( TimeError::OutOfRange { field: "second of normday", value: DUR_NORMDAY, max: DUR_NORMDAY - 1 } )
+		);
+	}
+
+	#[test]
+	fn parse_with_options() {
+		let all = ParseOptions {
+			strict: true,
+			allow_missing_clock: true,
+			allow_era: true,
+			alternative_separators: true,
+			allow_ordinal_week: true,
+			month_names: Some( MonthNames::default() ),
+			weekday_names: Some( WeekdayNames::default() ),
+		};
+
+		assert_eq!( NormTime::parse_with( "Tridi 0045-03-12 08:00:00 NE", &all ), "0045-03-12N08:00:00".parse() );
+		assert!( NormTime::parse_with( "Duodi 0045-03-12 08:00:00 NE", &all ).is_err() );
+
+		assert_eq!( NormTime::parse_with( "0045-quartus-12 08:00:00 NE", &all ), "0045-03-12N08:00:00".parse() );
+		assert_eq!( NormTime::parse_with( "0045-03-12 08:00:00 NE", &all ), "0045-03-12N08:00:00".parse() );
+		assert_eq!( NormTime::parse_with( "0001-09-29 27:46:39 BNE", &all ), Ok( NormTime( -1 ) ) );
+		assert!( NormTime::parse_with( "-0001-09-29 27:46:39 BNE", &all ).is_err() );
+		assert!( NormTime::parse_with( "0045-03-12 08:00:00 NE", &ParseOptions::new() ).is_err() );
+
+		assert_eq!( NormTime::parse_with( "0045-299", &all ), NormTime::from_ymd_opt( 45, 9, 29 ).ok_or( TimeError::ParseError( String::new() ) ) );
+		assert_eq!( NormTime::parse_with( "0045-300", &all ), Err( TimeError::OutOfRange { field: "normday", value: 300, max: 299 } ) );
+		assert_eq!( NormTime::parse_with( "0045-W29-9", &all ), NormTime::from_ymd_opt( 45, 9, 29 ).ok_or( TimeError::ParseError( String::new() ) ) );
+		assert_eq!( NormTime::parse_with( "0045-W30-0", &all ), Err( TimeError::OutOfRange { field: "normweek", value: 30, max: 29 } ) );
+		assert!( NormTime::parse_with( "0045-W3-0", &ParseOptions::new() ).is_err() );
+		assert!( NormTime::parse_with( "0045-123", &ParseOptions::new() ).is_err() );
+	}
+
+	#[test]
+	fn decompose() {
+		for t in [ NormTime( i64::MIN ), NormTime( -1 ), NormTime( 0 ), NormTime( i64::MAX ) ] {
+			assert_eq!( t.date().and_time( t.time_of_day() ), t );
+		}
+	}
+
+	#[test]
+	fn normtime_hash() {
+		use std::collections::HashSet;
+
+		let time = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap();
+		let events: HashSet<NormTime> = [ time, time.and_hms( 0, 0, 0 ), time.and_hms( 1, 0, 0 ) ].into_iter().collect();
+
+		assert_eq!( events.len(), 2 );
+		assert!( events.contains( &NormTime::from_ymd_opt( 45, 3, 12 ).unwrap() ) );
+	}
+
+	#[test]
+	fn abs_diff() {
+		assert_eq!( NormTime( i64::MIN ).abs_diff( NormTime( i64::MAX ) ), NormTimeDelta::MAX );
+		assert_eq!( NormTime( -1 ).abs_diff( NormTime( 1 ) ), NormTimeDelta::new_seconds( 2 ) );
+		assert_eq!( NormTime( 0 ).abs_diff( NormTime( NormTimeDelta::MAX.secs ) ), NormTimeDelta::new_seconds( NormTimeDelta::MAX.secs ) );
+	}
+
+	#[test]
+	fn saturating_delta() {
+		let ( min, max ) = ( NormTime( i64::MIN ), NormTime( i64::MAX ) );
+		let delta = NormTimeDelta::new( 1, 500_000_000 ).unwrap();
+		assert_eq!( max.saturating_add_delta( NormTimeDelta::new_seconds( 1 ) ), max );
+		assert_eq!( min.saturating_add_delta( NormTimeDelta::new_seconds( -1 ) ), min );
+		assert_eq!( min.saturating_sub_delta( NormTimeDelta::new_seconds( 1 ) ), min );
+		assert_eq!( max.saturating_sub_delta( NormTimeDelta::new_seconds( -1 ) ), max );
+		assert_eq!( NormTime( 0 ).saturating_add_delta( delta ), NormTime( 1 ) );
+		assert_eq!( NormTime( 0 ).saturating_sub_delta( delta ), NormTime( -2 ) );
+		assert_eq!( NormTime( i64::MIN + 2 ).saturating_sub_delta( delta ), min );
+	}
+
+	#[test]
+	fn succ_pred() {
+		let t = NormTime( 42 );
+		for unit in Unit::ALL {
+			assert_eq!( t.succ( unit ), t + NormTimeDelta::new_seconds( unit.seconds() ) );
+			assert_eq!( t.succ( unit ).pred( unit ), t );
+		}
+		assert_eq!( NormTime( i64::MIN ).succ( Unit::Second ).pred( Unit::Second ), NormTime( i64::MIN ) );
+	}
+
+	#[test]
+	fn same() {
+		let ( a, b ) = ( NormTime( -1 ), NormTime( 0 ) );
+		for unit in Unit::ALL {
+			assert!( !a.same( unit, b ) );
+			assert!( a.same( unit, a.pred( unit ).succ( unit ) ) );
+		}
+		assert!( NormTime( 0 ).same_year( NormTime( DUR_NORMYEAR - 1 ) ) );
+		assert!( !NormTime( 0 ).same_month( NormTime( DUR_NORMMONTH ) ) );
+		assert!( NormTime( -DUR_NORMWEEK ).same_week( NormTime( -1 ) ) );
+		assert!( NormTime( i64::MIN ).same_day( NormTime( i64::MIN + 1 ) ) );
+	}
+
+	#[test]
+	fn between() {
+		let ( a, b ) = ( NormTime( -10 ), NormTime( 10 ) );
+		assert!( NormTime( 0 ).is_between( a, b ) );
+		assert!( a.is_between( a, a ) );
+		assert!( !NormTime( 11 ).is_between( b, a ) );
+		assert!( NormTime( 10 ).is_within( &( ..=b ) ) );
+		assert!( !NormTime( 10 ).is_within( &( a..a ) ) );
+	}
+
+	#[test]
+	#[should_panic]
+	fn succ_overflow() {
+		NormTime( i64::MAX ).succ( Unit::Second );
+	}
+
+	#[test]
+	fn interpolate() {
+		let ( min, max ) = ( NormTime( i64::MIN ), NormTime( i64::MAX ) );
+		assert_eq!( NormTime::midpoint( min, max ), NormTime( -1 ) );
+		assert_eq!( NormTime::midpoint( max, max ), max );
+		assert_eq!( NormTime::lerp( min, max, 0.0 ), min );
+		assert_eq!( NormTime::lerp( min, max, 1.0 ), max );
+		assert_eq!( NormTime::lerp( min, max, 2.0 ), max );
+		assert_eq!( NormTime::lerp( max, min, 2.0 ), min );
+		assert_eq!( NormTime::lerp( NormTime( 0 ), NormTime( 10 ), f64::NAN ), NormTime( 0 ) );
+	}
+
+	#[test]
+	#[cfg( feature = "chrono" )]
 	fn naive_date_to_normtime() {
 		assert_eq!(
 			NormTime::from( NaiveDate::from_ymd_opt( 2068, 1, 1 ).unwrap() ),
@@ -418,6 +2487,173 @@ mod tests {
 		);
 	}
 
+	#[test]
+	#[cfg( feature = "chrono" )]
+	fn compare_naive_date_time() {
+		let ndt = NaiveDate::from_ymd_opt( 2068, 1, 1 ).unwrap().and_hms_milli_opt( 0, 0, 1, 500 ).unwrap();
+		let ntime = NormTime::from_ymd_opt( 0, 0, 0 ).unwrap().and_hms( 0, 0, 1 );
+
+		assert_eq!( ntime, ndt );
+		assert_eq!( ndt, ntime );
+		assert_eq!( ntime.partial_cmp( &ndt ), Some( Ordering::Equal ) );
+		assert!( ntime + NormTimeDelta::new_seconds( 1 ) > ndt );
+		assert!( ndt > NormTime( -NORMTIME_OFFSET ) );
+		assert!( NormTime( i64::MIN ) < ndt );
+		assert!( ndt < NormTime( i64::MAX ) );
+	}
+
+	#[test]
+	#[cfg( feature = "chrono" )]
+	fn utc_strings_out_of_range() {
+		assert_eq!( NormTime( i64::MAX ).to_rfc3339(), None );
+		assert_eq!( NormTime( i64::MIN ).to_utc_string(), None );
+		assert_eq!( NormTime::from_rfc3339( &NormTime( -1 ).to_rfc3339().unwrap() ), Ok( NormTime( -1 ) ) );
+	}
+
+	#[test]
+	#[cfg( feature = "chrono" )]
+	fn sub_naive_date_time() {
+		let ndt = NaiveDate::from_ymd_opt( 2068, 1, 1 ).unwrap().and_hms_opt( 0, 0, 0 ).unwrap();
+		let ntime = NormTime::from_ymd_opt( 0, 0, 0 ).unwrap().and_hms( 1, 0, 0 );
+
+		assert_eq!( ntime - ndt, NormTimeDelta::new_hours( 1 ) );
+		assert_eq!( ndt - ntime, NormTimeDelta::new_hours( -1 ) );
+		assert_eq!( ntime - ndt, ntime - NormTime::from( ndt ) );
+	}
+
+	#[test]
+	#[cfg( feature = "diesel-sqlite" )]
+	fn test_diesel() {
+		use diesel::{Connection, IntoSql, RunQueryDsl, SqliteConnection};
+		use diesel::dsl::sql;
+		use diesel::sql_types::{BigInt, Timestamp};
+
+		let mut conn = SqliteConnection::establish( ":memory:" ).unwrap();
+
+		for time in [ NormTime( i64::MIN ), NormTime::from_ymd_opt( -12, 3, 4 ).unwrap().and_hms( 5, 6, 7 ), NormTime( i64::MAX ) ] {
+			let res = diesel::select( time.into_sql::<BigInt>() ).get_result::<NormTime>( &mut conn ).unwrap();
+			assert_eq!( res, time );
+		}
+
+		let res = diesel::select( sql::<Timestamp>( "'2068-01-01 00:00:01'" ) ).get_result::<NormTime>( &mut conn ).unwrap();
+		assert_eq!( res, NormTime( 1 ) );
+	}
+
+	#[test]
+	#[cfg( feature = "rusqlite" )]
+	fn test_rusqlite() {
+		let conn = rusqlite::Connection::open_in_memory().unwrap();
+		conn.execute( "CREATE TABLE log (num INTEGER, txt TEXT)", () ).unwrap();
+
+		let ntime = NormTime::from_ymd_opt( -12, 3, 4 ).unwrap().and_hms( 5, 6, 7 );
+		conn.execute( "INSERT INTO log (num, txt) VALUES (?1, ?1)", [ ntime ] ).unwrap();
+		conn.execute( "INSERT INTO log (num, txt) VALUES (?1, '0045-03-12N08:00:00')", [ ntime ] ).unwrap();
+
+		let rows: Vec<(NormTime, NormTime)> = conn.prepare( "SELECT num, txt FROM log" ).unwrap()
+			.query_map( (), |row| Ok( ( row.get( 0 )?, row.get( 1 )? ) ) ).unwrap()
+			.collect::<Result<_, _>>().unwrap();
+
+		assert_eq!( rows[0], ( ntime, ntime ) );
+		assert_eq!( rows[1], ( ntime, NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 ) ) );
+	}
+
+	#[test]
+	#[cfg( feature = "sea-orm" )]
+	fn test_sea_orm_value() {
+		use sea_orm::sea_query::{Value, ValueType};
+
+		let item = NormTime::from_ymd_opt( 1, 2, 3 ).unwrap();
+		assert_eq!( Value::from( item ), Value::BigInt( Some( 36_300_000 ) ) );
+		assert_eq!( <NormTime as ValueType>::try_from( Value::from( item ) ).unwrap(), item );
+		assert!( <NormTime as ValueType>::try_from( Value::String( None ) ).is_err() );
+	}
+
+	#[test]
+	#[cfg( feature = "bson" )]
+	fn test_bson() {
+		let ntime = NormTime::from_ymd_opt( 0, 0, 0 ).unwrap().and_hms( 0, 0, 1 );
+		let bdate = bson::DateTime::from_millis( ( NORMTIME_OFFSET + 1 ) * 1000 );
+
+		assert_eq!( bson::DateTime::from( ntime ), bdate );
+		assert_eq!( NormTime::from( bdate ), ntime );
+		assert_eq!( NormTime::from( bson::DateTime::from_millis( ( NORMTIME_OFFSET + 1 ) * 1000 + 999 ) ), ntime );
+		assert_eq!( bson::Bson::from( ntime ), bson::Bson::DateTime( bdate ) );
+	}
+
+	#[test]
+	#[cfg( feature = "postgres" )]
+	fn test_postgres() {
+		use postgres_types::{FromSql, ToSql, Type};
+
+		let ntime = NormTime::from_ymd_opt( 45, 3, 12 ).unwrap().and_hms( 8, 0, 0 );
+
+		for ty in [ Type::INT8, Type::TIMESTAMP, Type::TIMESTAMPTZ ] {
+			let mut buf = bytes::BytesMut::new();
+			ntime.to_sql_checked( &ty, &mut buf ).unwrap();
+			assert_eq!( NormTime::from_sql( &ty, &buf ).unwrap(), ntime );
+		}
+
+		assert!( !<NormTime as ToSql>::accepts( &Type::TEXT ) );
+	}
+
+	#[test]
+	#[cfg( feature = "rkyv" )]
+	fn test_rkyv() {
+		let ntime = NormTime::from_ymd_opt( -12, 3, 4 ).unwrap().and_hms( 5, 6, 7 );
+
+		let bytes = rkyv::to_bytes::<rkyv::rancor::Error>( &ntime ).unwrap();
+		let archived = rkyv::access::<ArchivedNormTime, rkyv::rancor::Error>( &bytes ).unwrap();
+		assert_eq!( archived, &ntime );
+		assert_eq!( rkyv::deserialize::<NormTime, rkyv::rancor::Error>( archived ).unwrap(), ntime );
+	}
+
+	#[test]
+	#[cfg( feature = "bytemuck" )]
+	fn test_bytemuck() {
+		let times = [ NormTime::from_ymd_opt( 0, 0, 0 ).unwrap().and_hms( 0, 0, 1 ), NormTime::from_ymd_opt( -1, 0, 0 ).unwrap() ];
+
+		assert_eq!( bytemuck::cast_slice::<NormTime, i64>( &times ), &[ 1, -30_000_000 ] );
+		assert_eq!( bytemuck::cast_slice::<u8, NormTime>( bytemuck::cast_slice( &times ) ), &times );
+		assert_eq!( <NormTime as bytemuck::TransparentWrapper<i64>>::wrap( 1 ), times[0] );
+	}
+
+	#[test]
+	#[cfg( feature = "zerocopy" )]
+	fn test_zerocopy() {
+		use zerocopy::{FromBytes, IntoBytes};
+
+		let times = [ NormTime::from_ymd_opt( 0, 0, 0 ).unwrap().and_hms( 0, 0, 1 ), NormTime::from_ymd_opt( -1, 0, 0 ).unwrap() ];
+
+		assert_eq!( times[0].as_bytes(), 1i64.as_bytes() );
+		assert_eq!( <[NormTime]>::ref_from_bytes( times.as_bytes() ).unwrap(), &times );
+	}
+
+	#[test]
+	#[cfg( feature = "arbitrary" )]
+	fn test_arbitrary() {
+		use arbitrary::{Arbitrary, Unstructured};
+
+		let mut u = Unstructured::new( &[ 0xff; 32 ] );
+		let time = NormTime::arbitrary( &mut u ).unwrap();
+		assert!( time.0 <= i64::MAX - NORMTIME_OFFSET );
+
+		assert_eq!( NormTime::arbitrary( &mut Unstructured::new( &[] ) ).unwrap(), NormTime( i64::MIN ) );
+	}
+
+	#[test]
+	#[cfg( feature = "quickcheck" )]
+	fn test_quickcheck() {
+		use quickcheck::{Arbitrary, quickcheck};
+
+		fn timestamp_roundtrip( time: NormTime ) -> bool {
+			NormTime::from_timestamp( time.timestamp() ) == Some( time )
+		}
+
+		quickcheck( timestamp_roundtrip as fn( NormTime ) -> bool );
+
+		assert!( NormTime( 100 ).shrink().all( |x| x.0.abs() < 100 ) );
+	}
+
 	#[test]
 	#[cfg( feature = "serde" )]
 	fn test_serialize_deserilaize() {
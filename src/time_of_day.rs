@@ -0,0 +1,239 @@
+//! Clocks without a normday.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use std::fmt;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+use crate::{NormTimeDelta, TimeError};
+use crate::{DUR_NORMDAY, DUR_HOUR, DUR_MINUTE};
+
+
+
+
+//=============================================================================
+// Time of day
+
+
+/// The clock of a normday without the normday, e.g. `08:00:00`. It counts the seconds since the start of a normday.
+///
+/// Besides the 100 000 seconds of a normday (`00:00:00` to `27:46:39`), the end of the normday `27:46:40` can be represented, e.g. as the closing time of a shop open until the end of the normday.
+///
+/// Adding and subtracting durations wraps around the normday and always results in a clock before `27:46:40`. Fractions of a second are rounded down.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{NormTimeDelta, NormTimeOfDay};
+///
+/// let opening = NormTimeOfDay::from_hms_opt( 8, 0, 0 ).unwrap();
+/// assert_eq!( opening.to_string(), "08:00:00" );
+/// assert_eq!( "08:00:00".parse(), Ok( opening ) );
+///
+/// let closing = opening + NormTimeDelta::new_hours( 22 );
+/// assert_eq!( closing.to_string(), "02:13:20" );
+/// assert_eq!( closing - opening, NormTimeDelta::new_seconds( -20_800 ) );
+///
+/// assert_eq!( "27:46:40".parse(), Ok( NormTimeOfDay::END ) );
+/// assert!( "27:46:41".parse::<NormTimeOfDay>().is_err() );
+/// ```
+#[derive( Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Default )]
+pub struct NormTimeOfDay( pub(super) u32 );
+
+impl NormTimeOfDay {
+	/// The start of a normday, `00:00:00`.
+	pub const MIDNIGHT: Self = Self( 0 );
+
+	/// The end of a normday, `27:46:40`.
+	pub const END: Self = Self( DUR_NORMDAY as u32 );
+
+	/// Create a new `NormTimeOfDay` from `hour`, `min` and `sec`. Returns `None` if `min` or `sec` are greater than 59 or the clock is after the end of the normday `27:46:40`.
+	pub const fn from_hms_opt( hour: u32, min: u32, sec: u32 ) -> Option<Self> {
+		if min > 59 || sec > 59 || hour as i64 > DUR_NORMDAY / DUR_HOUR {
+			return None;
+		}
+
+		Self::from_seconds_opt( hour * DUR_HOUR as u32 + min * DUR_MINUTE as u32 + sec )
+	}
+
+	/// Create a new `NormTimeOfDay` from the seconds `secs` since the start of the normday. Returns `None` if `secs` is greater than 100 000.
+	pub const fn from_seconds_opt( secs: u32 ) -> Option<Self> {
+		if secs as i64 > DUR_NORMDAY {
+			return None;
+		}
+
+		Some( Self( secs ) )
+	}
+
+	/// Returns the seconds since the start of the normday.
+	pub const fn seconds( self ) -> u32 {
+		self.0
+	}
+
+	/// Returns the hour (0 to 27).
+	pub const fn hour( self ) -> u32 {
+		self.0 / DUR_HOUR as u32
+	}
+
+	/// Returns the minute within the hour (0 to 59).
+	pub const fn minute( self ) -> u32 {
+		self.0 % DUR_HOUR as u32 / DUR_MINUTE as u32
+	}
+
+	/// Returns the second within the minute (0 to 59).
+	pub const fn second( self ) -> u32 {
+		self.0 % DUR_MINUTE as u32
+	}
+
+	/// Returns the clock `secs` seconds after `self`, wrapping around the normday.
+	const fn wrapping_add_secs( self, secs: i64 ) -> Self {
+		Self( ( ( self.0 as i64 ).rem_euclid( DUR_NORMDAY ) + secs.rem_euclid( DUR_NORMDAY ) ).rem_euclid( DUR_NORMDAY ) as u32 )
+	}
+}
+
+impl Add<NormTimeDelta> for NormTimeOfDay {
+	type Output = Self;
+
+	fn add( self, other: NormTimeDelta ) -> Self::Output {
+		self.wrapping_add_secs( other.secs )
+	}
+}
+
+impl Sub<NormTimeDelta> for NormTimeOfDay {
+	type Output = Self;
+
+	fn sub( self, other: NormTimeDelta ) -> Self::Output {
+		// Rounding down the negated duration, like `Add` does. `rem_euclid` prevents overflowing when negating.
+		self.wrapping_add_secs( -other.secs.rem_euclid( DUR_NORMDAY ) - i64::from( other.nanos > 0 ) )
+	}
+}
+
+/// The duration between two clocks of the same normday.
+impl Sub for NormTimeOfDay {
+	type Output = NormTimeDelta;
+
+	fn sub( self, other: Self ) -> Self::Output {
+		NormTimeDelta::new_seconds( i64::from( self.0 ) - i64::from( other.0 ) )
+	}
+}
+
+forward_ref_binop!( impl Add, add for NormTimeOfDay, NormTimeDelta );
+forward_ref_binop!( impl Sub, sub for NormTimeOfDay, NormTimeDelta );
+forward_ref_binop!( impl Sub, sub for NormTimeOfDay, NormTimeOfDay );
+
+impl fmt::Debug for NormTimeOfDay {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		fmt::Display::fmt( self, f )
+	}
+}
+
+/// A `NormTimeOfDay` is formatted like the clock part of a `NormTime`. Width, fill and alignment are respected.
+impl fmt::Display for NormTimeOfDay {
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result {
+		f.pad( &format!( "{:0>2}:{:0>2}:{:0>2}", self.hour(), self.minute(), self.second() ) )
+	}
+}
+
+impl FromStr for NormTimeOfDay {
+	type Err = TimeError;
+
+	/// Parses a clock like `08:00:00`. Minutes and seconds have to be within 0 and 59 and the clock must not exceed `27:46:40`.
+	fn from_str( s: &str ) -> Result<Self, Self::Err> {
+		let mut elems = s.split( ':' );
+		let ( Some( hour ), Some( minute ), Some( second ), None ) = ( elems.next(), elems.next(), elems.next(), elems.next() ) else {
+			return Err( TimeError::ParseError( s.to_string() ) );
+		};
+
+		Self::from_hms_opt( hour.parse()?, minute.parse()?, second.parse()? )
+			.ok_or_else( || TimeError::ParseError( s.to_string() ) )
+	}
+}
+
+
+#[cfg( feature = "serde" )]
+mod normtimeofday_serde {
+	use super::NormTimeOfDay;
+
+	use std::fmt;
+
+	impl serde::Serialize for NormTimeOfDay {
+		fn serialize<S>( &self, serializer: S ) -> Result<S::Ok, S::Error>
+		where
+			S: serde::Serializer,
+		{
+			serializer.collect_str( self )
+		}
+	}
+
+	struct NormTimeOfDayVisitor;
+
+	impl<'de> serde::de::Visitor<'de> for NormTimeOfDayVisitor {
+		type Value = NormTimeOfDay;
+
+		fn expecting( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+			formatter.write_str( "a formatted clock string" )
+		}
+
+		fn visit_str<E>( self, value: &str ) -> Result<Self::Value, E>
+		where
+			E: serde::de::Error,
+		{
+			value.parse().map_err( E::custom )
+		}
+	}
+
+	impl<'de> serde::Deserialize<'de> for NormTimeOfDay {
+		fn deserialize<D>( deserializer: D ) -> Result<Self, D::Error>
+		where
+			D: serde::Deserializer<'de>,
+		{
+			deserializer.deserialize_str( NormTimeOfDayVisitor )
+		}
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	#[cfg( feature = "serde" )]
+	use serde_test::{Token, assert_tokens};
+
+	#[test]
+	fn wrap_around() {
+		let t = NormTimeOfDay::from_hms_opt( 0, 0, 10 ).unwrap();
+		assert_eq!( t - NormTimeDelta::new_seconds( 20 ), NormTimeOfDay( 99_990 ) );
+		assert_eq!( t - NormTimeDelta::new( 0, 500_000_000 ).unwrap(), NormTimeOfDay( 9 ) );
+		assert_eq!( t + NormTimeDelta::new( 0, 500_000_000 ).unwrap(), t );
+		assert_eq!( NormTimeOfDay::END + NormTimeDelta::ZERO, NormTimeOfDay::MIDNIGHT );
+		assert_eq!( i128::from( ( t + NormTimeDelta::MAX ).seconds() ), ( 10 + NormTimeDelta::MAX.total_nanos().div_euclid( 1_000_000_000 ) ).rem_euclid( 100_000 ) );
+		assert_eq!( i128::from( ( t - NormTimeDelta::MIN ).seconds() ), ( 10 + ( -NormTimeDelta::MIN.total_nanos() ).div_euclid( 1_000_000_000 ) ).rem_euclid( 100_000 ) );
+	}
+
+	#[test]
+	fn parse_normtimeofday() {
+		assert_eq!( "27:46:39".parse(), Ok( NormTimeOfDay( 99_999 ) ) );
+		assert!( "27:60:00".parse::<NormTimeOfDay>().is_err() );
+		assert!( "28:00:00".parse::<NormTimeOfDay>().is_err() );
+		assert!( "08:00".parse::<NormTimeOfDay>().is_err() );
+	}
+
+	#[test]
+	#[cfg( feature = "serde" )]
+	fn test_serialize_deserialize() {
+		assert_tokens( &NormTimeOfDay::from_hms_opt( 8, 0, 0 ).unwrap(), &[ Token::Str( "08:00:00" ) ] );
+	}
+}
@@ -0,0 +1,167 @@
+//! Scheduling asynchronous work in Normtime using `tokio`.
+//!
+//! `NormTime` is based on the system clock, while tokio uses a monotonic clock. Deadlines are converted into tokio's `Instant` when calling the respective function, so later changes of the system clock do not affect them.
+//!
+//! This module is only available, if the **`tokio`** feature has been enabled.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tokio::time::{Instant, Interval, Sleep};
+
+use crate::{NormTime, NormTimeDelta, NORMTIME_OFFSET};
+
+
+
+
+//=============================================================================
+// Conversion
+
+
+/// Returns `delta` as `Duration`. Negative durations are returned as zero.
+fn to_duration( delta: NormTimeDelta ) -> Duration {
+	if delta < NormTimeDelta::ZERO {
+		return Duration::ZERO;
+	}
+
+	Duration::new( delta.secs as u64, delta.nanos as u32 )
+}
+
+/// Returns the duration from `now` until `time`. `now` is given in nanoseconds since the Unix epoch, so the subseconds of the current time are taken into account and the duration never ends before `time`. Times in the past result in zero.
+fn until( time: NormTime, now: i128 ) -> Duration {
+	let nanos = ( ( i128::from( time.0 ) + i128::from( NORMTIME_OFFSET ) ) * 1_000_000_000 - now ).max( 0 );
+	let secs = u64::try_from( nanos / 1_000_000_000 ).unwrap_or( u64::MAX );
+
+	Duration::new( secs, ( nanos % 1_000_000_000 ) as u32 )
+}
+
+/// Returns the tokio `Instant` representing `time`. Times in the past are returned as the current instant. Times too far in the future to be represented are returned as roughly 30 earth years from now.
+pub fn to_instant( time: NormTime ) -> Instant {
+	let instant = Instant::now();
+	let now = match SystemTime::now().duration_since( UNIX_EPOCH ) {
+		Ok( dur ) => dur.as_nanos() as i128,
+		Err( err ) => -( err.duration().as_nanos() as i128 ),
+	};
+
+	instant.checked_add( until( time, now ) )
+		.unwrap_or_else( || instant + Duration::from_secs( 86_400 * 365 * 30 ) )
+}
+
+
+
+
+//=============================================================================
+// Sleep
+
+
+/// Waits until `deadline` has been reached. See `tokio::time::sleep_until`.
+pub fn sleep_until( deadline: NormTime ) -> Sleep {
+	tokio::time::sleep_until( to_instant( deadline ) )
+}
+
+/// Waits until `delta` has elapsed. Negative durations complete immediately. See `tokio::time::sleep`.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormTimeDelta;
+///
+/// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on( async {
+/// normtime::tokio::sleep( NormTimeDelta::new( 0, 1_000_000 ).unwrap() ).await;
+/// # } );
+/// ```
+pub fn sleep( delta: NormTimeDelta ) -> Sleep {
+	tokio::time::sleep( to_duration( delta ) )
+}
+
+
+
+
+//=============================================================================
+// Interval
+
+
+/// Returns an `Interval` yielding every `period`, with the first tick completing immediately. See `tokio::time::interval`.
+///
+/// **Note:** This function panics if `period` is not positive.
+pub fn interval( period: NormTimeDelta ) -> Interval {
+	tokio::time::interval( to_duration( period ) )
+}
+
+/// Returns an `Interval` yielding every `period`, with the first tick completing at `start`. See `tokio::time::interval_at`.
+///
+/// **Note:** This function panics if `period` is not positive.
+pub fn interval_at( start: NormTime, period: NormTimeDelta ) -> Interval {
+	tokio::time::interval_at( to_instant( start ), to_duration( period ) )
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	fn block_on<F: std::future::Future>( future: F ) -> F::Output {
+		tokio::runtime::Builder::new_current_thread()
+			.enable_time()
+			.start_paused( true )
+			.build()
+			.unwrap()
+			.block_on( future )
+	}
+
+	#[test]
+	fn sleeping() {
+		block_on( async {
+			let start = Instant::now();
+
+			sleep( NormTimeDelta::new_hours( 1 ) ).await;
+			assert_eq!( start.elapsed().as_secs(), 3600 );
+
+			sleep( NormTimeDelta::new_hours( -1 ) ).await;
+			assert_eq!( start.elapsed().as_secs(), 3600 );
+
+			sleep_until( NormTime::now() + NormTimeDelta::new_days( 1 ) ).await;
+			assert!( ( 103_599..=103_601 ).contains( &start.elapsed().as_secs() ) );
+		} );
+	}
+
+	#[test]
+	fn deadline_not_early() {
+		let time = NormTime::from_timestamp( 1_000 ).unwrap();
+
+		assert_eq!( until( time, 999_300_000_000 ), Duration::from_millis( 700 ) );
+		assert_eq!( until( time, 1_000_000_000_000 ), Duration::ZERO );
+		assert_eq!( until( time, 1_000_000_000_001 ), Duration::ZERO );
+		assert_eq!( until( time, -1 ), Duration::new( 1_000, 1 ) );
+		assert_eq!( until( NormTime( i64::MAX ), 0 ).as_secs(), i64::MAX as u64 + NORMTIME_OFFSET as u64 );
+	}
+
+	#[test]
+	fn intervals() {
+		block_on( async {
+			let start = Instant::now();
+
+			let mut ticks = interval( NormTimeDelta::new_days( 1 ) );
+			ticks.tick().await;
+			ticks.tick().await;
+			assert_eq!( start.elapsed().as_secs(), 100_000 );
+
+			let mut ticks = interval_at( NormTime::now() + NormTimeDelta::new_hours( 1 ), NormTimeDelta::new_minutes( 1 ) );
+			ticks.tick().await;
+			ticks.tick().await;
+			assert!( ( 103_659..=103_661 ).contains( &start.elapsed().as_secs() ) );
+		} );
+	}
+}
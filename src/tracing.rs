@@ -0,0 +1,81 @@
+//! Stamping `tracing` logs with Normtime.
+//!
+//! This module is only available, if the **`tracing`** feature has been enabled.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use std::fmt;
+
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::time::FormatTime;
+
+use crate::NormTime;
+
+
+
+
+//=============================================================================
+// Timer
+
+
+/// Formats the timestamps of `tracing_subscriber` log lines as `NormTime`, e.g. `0045-03-12N08:00:00`. If `alternate` is `true`, the alternate format is used, e.g. `0045-03-12 08:00:00 NE`.
+///
+/// # Example
+///
+/// ```
+/// use normtime::tracing::NormTimeTimer;
+///
+/// let subscriber = tracing_subscriber::fmt()
+///     .with_timer( NormTimeTimer::default() )
+///     .finish();
+/// ```
+#[derive( Clone, Copy, PartialEq, Eq, Default, Debug )]
+pub struct NormTimeTimer {
+	/// Use the alternate format (`{:#}`) of `NormTime`.
+	pub alternate: bool,
+}
+
+impl NormTimeTimer {
+	/// Creates a new `NormTimeTimer` using the standard format.
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+impl FormatTime for NormTimeTimer {
+	fn format_time( &self, w: &mut Writer<'_> ) -> fmt::Result {
+		if self.alternate {
+			write!( w, "{:#}", NormTime::now() )
+		} else {
+			write!( w, "{}", NormTime::now() )
+		}
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn format_time() {
+		let mut buf = String::new();
+		NormTimeTimer::new().format_time( &mut Writer::new( &mut buf ) ).unwrap();
+		assert!( buf.parse::<NormTime>().is_ok() );
+
+		let mut buf = String::new();
+		NormTimeTimer { alternate: true }.format_time( &mut Writer::new( &mut buf ) ).unwrap();
+		assert!( buf.ends_with( "NE" ) );
+	}
+}
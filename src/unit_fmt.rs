@@ -0,0 +1,171 @@
+//! String representations of durations shared by `NormTimeDelta` and `NormTimeDeltaWide`. All functions take the duration as number of whole seconds.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+#[cfg( feature = "i18n" )] use fluent_templates::Loader;
+#[cfg( feature = "i18n" )] use unic_langid::LanguageIdentifier;
+
+#[cfg( feature = "i18n" )] use crate::DisplayLocale;
+#[cfg( feature = "tex" )] use crate::LatexSym;
+#[cfg( feature = "tex" )] use crate::TexOptions;
+use crate::{TextOptions, Unit};
+#[cfg( feature = "i18n" )] use crate::LOCALES;
+
+
+
+
+//=============================================================================
+// Seconds
+
+
+/// Returns `secs` as `"1 second"` or `"<secs> seconds"`.
+pub(crate) fn seconds( secs: i128 ) -> String {
+	match secs {
+		1 => format!( "{} second", secs ),
+		_ => format!( "{} seconds", secs ),
+	}
+}
+
+/// Returns `secs` like [`seconds`] translated into the language provided by `locale`.
+#[cfg( feature = "i18n" )]
+pub(crate) fn seconds_locale( secs: i128, locale: &LanguageIdentifier ) -> String {
+	match secs {
+		1 => format!( "{} {}", secs, LOCALES.lookup( locale, "second" ) ),
+		_ => format!( "{} {}", secs, LOCALES.lookup( locale, "seconds" ) ),
+	}
+}
+
+/// Returns `secs` like [`seconds`] as LaTeX code.
+#[cfg( feature = "tex" )]
+pub(crate) fn latex_seconds( secs: i128, options: &TexOptions ) -> String {
+	match secs {
+		1 => format!( "{}~second", options.num( secs ) ),
+		_ => format!( "{}~seconds", options.num( secs ) ),
+	}
+}
+
+/// Returns `secs` like [`seconds_locale`] as LaTeX code.
+#[cfg( all( feature = "i18n", feature = "tex" ) )]
+pub(crate) fn latex_seconds_locale( secs: i128, locale: &LanguageIdentifier, options: &TexOptions ) -> String {
+	match secs {
+		1 => format!( "{}~{}", options.num( secs ), LOCALES.lookup( locale, "second" ) ),
+		_ => format!( "{}~{}", options.num( secs ), LOCALES.lookup( locale, "seconds" ) ),
+	}
+}
+
+/// Returns `secs` as LaTeX quantity in seconds using the LaTeX package `{siunitx}`.
+#[cfg( feature = "tex" )]
+pub(crate) fn latex_sym_seconds( secs: i128, options: &TexOptions ) -> String {
+	options.qty( secs, &Unit::Second.to_latex_sym( options ) )
+}
+
+
+
+
+//=============================================================================
+// Units
+
+
+/// Returns an iterator over the unit representations of `secs` with selectable units rounded to the smallest unit provided. The units are yielded from largest to smallest, regardless of their order in `units`.
+pub(crate) fn as_units( secs: i128, units: &[Unit] ) -> impl Iterator<Item = (i128, Unit)> {
+	let mut selected = [ false; Unit::ALL.len() ];
+	for unit in units {
+		selected[*unit as usize] = true;
+	}
+
+	Unit::ALL.into_iter()
+		.filter( move |x| selected[*x as usize] )
+		.scan( secs, |number, unit| {
+			let val = *number / unit.seconds() as i128;
+			*number -= val * unit.seconds() as i128;
+			Some( ( val, unit ) )
+		} )
+}
+
+/// Returns the name of `unit` in the language provided by `locale`, in singular if `count` is `1` or `-1`.
+#[cfg( feature = "i18n" )]
+fn unit_name_locale( count: i128, unit: Unit, locale: &LanguageIdentifier ) -> String {
+	let name_unit = unit.to_string_locale( locale );
+	if count.abs() == 1 {
+		name_unit[0..name_unit.len()-1].to_string()
+	} else {
+		name_unit
+	}
+}
+
+/// Returns `secs` split into `units`, omitting the units that are zero, e.g. `"900 normdays 1 hour"`.
+pub(crate) fn unit( secs: i128, units: &[Unit], options: &TextOptions ) -> String {
+	as_units( secs, units )
+		.filter( |( k, _ )| *k != 0 )
+		.map( |( k, v )| {
+			let postfix = if k.abs() == 1 {
+				v.as_str_singular()
+			} else {
+				v.as_str()
+			};
+			format!( "{}{}{}", options.format_count( k ), options.unit_separator(), postfix )
+		} )
+		.collect::<Vec<String>>()
+		.join( " " )
+}
+
+/// Returns `secs` split into `units` like [`unit`] as LaTeX code.
+#[cfg( feature = "tex" )]
+pub(crate) fn latex_unit( secs: i128, units: &[Unit], options: &TexOptions ) -> String {
+	as_units( secs, units )
+		.filter( |( k, _ )| *k != 0 )
+		.map( |( k, v )| {
+			let postfix = if k.abs() == 1 {
+				v.as_str_singular()
+			} else {
+				v.as_str()
+			};
+			format!( "{}~{}", options.num( k ), postfix )
+		} )
+		.collect::<Vec<String>>()
+		.join( " " )
+}
+
+/// Returns `secs` split into `units` like [`unit`] translated into the language provided by `locale`.
+#[cfg( feature = "i18n" )]
+pub(crate) fn unit_locale( secs: i128, units: &[Unit], locale: &LanguageIdentifier, options: &TextOptions ) -> String {
+	as_units( secs, units )
+		.filter( |( k, _ )| *k != 0 )
+		.map( |( k, v )| format!( "{}{}{}", options.format_count_locale( k, v, locale ), options.unit_separator(), unit_name_locale( k, v, locale ) ) )
+		.collect::<Vec<String>>()
+		.join( " " )
+}
+
+/// Returns `secs` split into `units` like [`unit_locale`] as LaTeX code.
+#[cfg( all( feature = "i18n", feature = "tex" ) )]
+pub(crate) fn latex_unit_locale( secs: i128, units: &[Unit], locale: &LanguageIdentifier, options: &TexOptions ) -> String {
+	as_units( secs, units )
+		.filter( |( k, _ )| *k != 0 )
+		.map( |( k, v )| format!( "{}~{}", options.num( k ), unit_name_locale( k, v, locale ) ) )
+		.collect::<Vec<String>>()
+		.join( " " )
+}
+
+/// Returns `secs` split into `units` like [`unit`] with the units expressed as symbols, e.g. `"900 d 1 h"`.
+pub(crate) fn sym_unit( secs: i128, units: &[Unit], options: &TextOptions ) -> String {
+	as_units( secs, units )
+		.filter( |( k, _ )| *k != 0 )
+		.map( |( k, v )| format!( "{}{}{}", options.format_count( k ), options.unit_separator(), v.as_sym() ) )
+		.collect::<Vec<String>>()
+		.join( " " )
+}
+
+/// Returns `secs` split into `units` as LaTeX quantities using the LaTeX package `{siunitx}`.
+#[cfg( feature = "tex" )]
+pub(crate) fn latex_sym_unit( secs: i128, units: &[Unit], options: &TexOptions ) -> String {
+	as_units( secs, units )
+		.filter( |( k, _ )| *k != 0 )
+		.map( |( k, v )| options.qty( k, &v.to_latex_sym( options ) ) )
+		.collect::<Vec<String>>()
+		.join( "\\," )
+}
@@ -0,0 +1,675 @@
+//! Providing a time duration with a range large enough for geological or astronomical time spans.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, Sub, Mul, Div};
+
+#[cfg( feature = "i18n" )] use unic_langid::LanguageIdentifier;
+
+#[cfg( feature = "i18n" )] use crate::DisplayLocale;
+#[cfg( feature = "tex" )] use crate::{Latex, LatexSym};
+#[cfg( all( feature = "i18n", feature = "tex" ) )] use crate::LatexLocale;
+#[cfg( feature = "tex" )] use crate::TexOptions;
+use crate::TextOptions;
+use crate::unit_fmt;
+use crate::{ConversionError, NormTimeDelta, Unit};
+use crate::{DUR_NORMYEAR, DUR_NORMMONTH, DUR_NORMWEEK, DUR_NORMDAY, DUR_HOUR, DUR_MINUTE};
+
+
+
+
+//=============================================================================
+// Duration
+
+
+/// Time duration with second precision and a range of `i128` seconds.
+///
+/// `NormTimeDelta` is restricted to `i64::MAX` *milliseconds* (roughly 292 million earth years). `NormTimeDeltaWide` covers spans far beyond that and provides the same string representations as `NormTimeDelta`.
+///
+/// # Example
+///
+/// ```
+/// use normtime::{NormTimeDelta, NormTimeDeltaWide};
+///
+/// let age = NormTimeDeltaWide::new_years( 13_800_000_000 );
+/// assert_eq!( age.years(), 13_800_000_000 );
+/// assert!( NormTimeDelta::try_from( age ).is_err() );
+///
+/// let delta = NormTimeDeltaWide::from( NormTimeDelta::new_days( 3 ) );
+/// assert_eq!( NormTimeDelta::try_from( delta ).unwrap(), NormTimeDelta::new_days( 3 ) );
+/// ```
+#[derive( Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug )]
+pub struct NormTimeDeltaWide {
+	secs: i128,
+}
+
+impl NormTimeDeltaWide {
+	/// Creates a new `NormTimeDeltaWide` that has a duration of zero seconds.
+	pub const ZERO: Self = Self { secs: 0 };
+
+	/// The minimum possible `NormTimeDeltaWide`.
+	pub const MIN: Self = Self { secs: i128::MIN };
+
+	/// The maximum possible `NormTimeDeltaWide`.
+	pub const MAX: Self = Self { secs: i128::MAX };
+
+	/// Creates a new `NormTimeDeltaWide` that has a duration of `secs`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDeltaWide;
+	///
+	/// assert_eq!( NormTimeDeltaWide::new_seconds( 0 ), NormTimeDeltaWide::ZERO );
+	/// ```
+	pub const fn new_seconds( secs: i128 ) -> Self {
+		Self { secs }
+	}
+
+	/// Creates a new `NormTimeDeltaWide` that has a duration of `minutes`.
+	///
+	/// **Note:** If the duration cannot be expressed in `i128` seconds, this method will panic.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDeltaWide;
+	///
+	/// assert_eq!( NormTimeDeltaWide::new_minutes( 1 ), NormTimeDeltaWide::new_seconds( 60 ) );
+	/// ```
+	pub const fn new_minutes( minutes: i128 ) -> Self {
+		Self::new_unit( minutes, DUR_MINUTE )
+	}
+
+	/// Creates a new `NormTimeDeltaWide` that has a duration of `hours`.
+	///
+	/// **Note:** If the duration cannot be expressed in `i128` seconds, this method will panic.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDeltaWide;
+	///
+	/// assert_eq!( NormTimeDeltaWide::new_hours( 1 ), NormTimeDeltaWide::new_seconds( 3600 ) );
+	/// ```
+	pub const fn new_hours( hours: i128 ) -> Self {
+		Self::new_unit( hours, DUR_HOUR )
+	}
+
+	/// Creates a new `NormTimeDeltaWide` that has a duration of `days` normdays.
+	///
+	/// **Note:** If the duration cannot be expressed in `i128` seconds, this method will panic.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDeltaWide;
+	///
+	/// assert_eq!( NormTimeDeltaWide::new_days( 1 ), NormTimeDeltaWide::new_seconds( 100_000 ) );
+	/// ```
+	pub const fn new_days( days: i128 ) -> Self {
+		Self::new_unit( days, DUR_NORMDAY )
+	}
+
+	/// Creates a new `NormTimeDeltaWide` that has a duration of `years` normyears.
+	///
+	/// **Note:** If the duration cannot be expressed in `i128` seconds, this method will panic.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDeltaWide;
+	///
+	/// assert_eq!( NormTimeDeltaWide::new_years( 1 ), NormTimeDeltaWide::new_seconds( 30_000_000 ) );
+	/// ```
+	pub const fn new_years( years: i128 ) -> Self {
+		Self::new_unit( years, DUR_NORMYEAR )
+	}
+
+	/// Creates a new `NormTimeDeltaWide` of `number` times a unit of `unit_secs` seconds.
+	const fn new_unit( number: i128, unit_secs: i64 ) -> Self {
+		match number.checked_mul( unit_secs as i128 ) {
+			Some( secs ) => Self { secs },
+			None => panic!( "NormTimeDeltaWide is out of bounds" ),
+		}
+	}
+
+	/// Computes the absolute value of `self`.
+	///
+	/// **Note:** `NormTimeDeltaWide::MIN.abs()` will panic.
+	pub const fn abs( self ) -> Self {
+		Self { secs: self.secs.abs() }
+	}
+
+	/// Returns `true` if `self` has a duration of 0 seconds.
+	pub const fn is_zero( &self ) -> bool {
+		self.secs == 0
+	}
+
+	/// Returns the duration of `self` in seconds.
+	pub const fn seconds( &self ) -> i128 {
+		self.secs
+	}
+
+	/// Returns the duration of `self` in minutes.
+	pub const fn minutes( &self ) -> i128 {
+		self.secs / DUR_MINUTE as i128
+	}
+
+	/// Returns the duration of `self` in hours.
+	pub const fn hours( &self ) -> i128 {
+		self.secs / DUR_HOUR as i128
+	}
+
+	/// Returns the duration of `self` in normdays.
+	pub const fn days( &self ) -> i128 {
+		self.secs / DUR_NORMDAY as i128
+	}
+
+	/// Returns the duration of `self` in normweeks.
+	pub const fn weeks( &self ) -> i128 {
+		self.secs / DUR_NORMWEEK as i128
+	}
+
+	/// Returns the duration of `self` in normmonths.
+	pub const fn months( &self ) -> i128 {
+		self.secs / DUR_NORMMONTH as i128
+	}
+
+	/// Returns the duration of `self` in normyears.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDeltaWide;
+	///
+	/// assert_eq!( NormTimeDeltaWide::new_seconds( 89_000_000 ).years(), 2 );
+	/// ```
+	pub const fn years( &self ) -> i128 {
+		self.secs / DUR_NORMYEAR as i128
+	}
+
+	/// Returns the duration as string with symbol as unit.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::NormTimeDeltaWide;
+	///
+	/// assert_eq!( NormTimeDeltaWide::new_seconds( 10 ).to_string_sym(), "10 s" );
+	/// ```
+	pub fn to_string_sym( &self ) -> String {
+		format!( r"{} s", self.secs )
+	}
+
+	/// Returns a string representation of `self` with selectable units rounded to the smallest unit provided. Selected units, that are too large (would be 0) are omitted.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTimeDeltaWide, Unit};
+	///
+	/// let delta = NormTimeDeltaWide::new_years( 4_500_000_000_000 ) + NormTimeDeltaWide::new_days( 1 );
+	/// assert_eq!( delta.to_string_unit( &[ Unit::Year, Unit::Day ] ), "4500000000000 normyears 1 normday" );
+	/// ```
+	pub fn to_string_unit( &self, units: &[Unit] ) -> String {
+		self.to_string_unit_with( units, &TextOptions::new() )
+	}
+
+	/// Returns a string representation of `self` like [`to_string_unit`](Self::to_string_unit) formatted according to `options`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{Notation, NormTimeDeltaWide, TextOptions, Unit};
+	///
+	/// let options = TextOptions {
+	///     notation: Notation::SiPrefix,
+	///     ..TextOptions::new()
+	/// };
+	///
+	/// let delta = NormTimeDeltaWide::new_years( 4_500_000_000_000 ) + NormTimeDeltaWide::new_days( 1 );
+	/// assert_eq!( delta.to_string_unit_with( &[ Unit::Year, Unit::Day ], &options ), "4.5T normyears 1 normday" );
+	/// ```
+	pub fn to_string_unit_with( &self, units: &[Unit], options: &TextOptions ) -> String {
+		unit_fmt::unit( self.secs, units, options )
+	}
+
+	/// Returns a string representation of `self` with selectable units rounded to the smallest unit provided. Selected units, that are too large (would be 0) are omitted. This string is intended to be used by LaTeX.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTimeDeltaWide, Unit};
+	///
+	/// let delta = NormTimeDeltaWide::new_years( 4_500_000_000_000 ) + NormTimeDeltaWide::new_days( 1 );
+	/// assert_eq!( delta.to_latex_unit( &[ Unit::Year, Unit::Day ] ), "4500000000000~normyears 1~normday" );
+	/// ```
+	#[cfg( feature = "tex" )]
+	pub fn to_latex_unit( &self, units: &[Unit] ) -> String {
+		self.to_latex_unit_with( units, &TexOptions::new() )
+	}
+
+	/// Returns a string representation of `self` like [`to_latex_unit`](Self::to_latex_unit) formatted according to `options`.
+	///
+	/// This method is only available when the **tex** feature has been activated.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTimeDeltaWide, TexOptions, Unit};
+	///
+	/// let options = TexOptions::new().siunitx( "group-digits", "none" );
+	/// let delta = NormTimeDeltaWide::new_days( 1_500 );
+	/// assert_eq!( delta.to_latex_unit_with( &[ Unit::Day ], &options ), r"\num[group-digits=none]{1500}~normdays" );
+	/// ```
+	#[cfg( feature = "tex" )]
+	pub fn to_latex_unit_with( &self, units: &[Unit], options: &TexOptions ) -> String {
+		unit_fmt::latex_unit( self.secs, units, options )
+	}
+
+	/// Returns a string representation of `self` with selectable units rounded to the smallest unit provided. Selected units, that are too large (would be 0) are omitted. The string is using the language that is provided by `locale`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use unic_langid::LanguageIdentifier;
+	/// use unic_langid::langid;
+	/// use normtime::{NormTimeDeltaWide, Unit};
+	///
+	/// const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+	///
+	/// let delta = NormTimeDeltaWide::new_years( 4_500_000_000_000 ) + NormTimeDeltaWide::new_days( 1 );
+	/// assert_eq!(
+	///     delta.to_string_unit_locale( &[ Unit::Year, Unit::Day ], &GERMAN ),
+	///     "4500000000000 Normjahre 1 Normtag"
+	/// );
+	/// ```
+	#[cfg( feature = "i18n" )]
+	pub fn to_string_unit_locale( &self, units: &[Unit], locale: &LanguageIdentifier ) -> String {
+		self.to_string_unit_locale_with( units, locale, &TextOptions::new() )
+	}
+
+	/// Returns a string representation of `self` like [`to_string_unit_locale`](Self::to_string_unit_locale) formatted according to `options`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use unic_langid::langid;
+	/// use normtime::{NormTimeDeltaWide, TextOptions, Unit};
+	///
+	/// let options = TextOptions {
+	///     spell_out: true,
+	///     ..TextOptions::new()
+	/// };
+	///
+	/// let delta = NormTimeDeltaWide::new_days( 3 ) + NormTimeDeltaWide::new_hours( 1 );
+	/// assert_eq!( delta.to_string_unit_locale_with( &[ Unit::Day, Unit::Hour ], &langid!( "en-US" ), &options ), "three normdays one hour" );
+	/// assert_eq!( delta.to_string_unit_locale_with( &[ Unit::Day, Unit::Hour ], &langid!( "de-DE" ), &options ), "drei Normtage eine Stunde" );
+	///
+	/// let delta = NormTimeDeltaWide::new_days( 13 );
+	/// assert_eq!( delta.to_string_unit_locale_with( &[ Unit::Day ], &langid!( "en-US" ), &options ), "13 normdays" );
+	/// ```
+	#[cfg( feature = "i18n" )]
+	pub fn to_string_unit_locale_with( &self, units: &[Unit], locale: &LanguageIdentifier, options: &TextOptions ) -> String {
+		unit_fmt::unit_locale( self.secs, units, locale, options )
+	}
+
+	/// Returns a string representation of `self` with selectable units rounded to the smallest unit provided. Selected units, that are too large (would be 0) are omitted. This string is intended to be used by LaTeX. The string is translated into the language that is provided by `locale`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use unic_langid::LanguageIdentifier;
+	/// use unic_langid::langid;
+	/// use normtime::{NormTimeDeltaWide, Unit};
+	///
+	/// const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+	///
+	/// let delta = NormTimeDeltaWide::new_years( 4_500_000_000_000 ) + NormTimeDeltaWide::new_days( 1 );
+	/// assert_eq!(
+	///     delta.to_latex_unit_locale( &[ Unit::Year, Unit::Day ], &GERMAN ),
+	///     "4500000000000~Normjahre 1~Normtag"
+	/// );
+	/// ```
+	#[cfg( all( feature = "i18n", feature = "tex" ) )]
+	pub fn to_latex_unit_locale( &self, units: &[Unit], locale: &LanguageIdentifier ) -> String {
+		self.to_latex_unit_locale_with( units, locale, &TexOptions::new() )
+	}
+
+	/// Returns a string representation of `self` like [`to_latex_unit_locale`](Self::to_latex_unit_locale) formatted according to `options`.
+	///
+	/// This method is only available when the **i18n** and the **tex** features have been activated.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use unic_langid::langid;
+	/// use normtime::{NormTimeDeltaWide, TexMinus, TexOptions, Unit};
+	///
+	/// let options = TexOptions::new().siunitx( "group-digits", "none" );
+	/// let delta = NormTimeDeltaWide::new_days( 1_500 );
+	/// assert_eq!( delta.to_latex_unit_locale_with( &[ Unit::Day ], &langid!( "de-DE" ), &options ), r"\num[group-digits=none]{1500}~Normtage" );
+	///
+	/// let options = TexOptions::new().minus( TexMinus::Hyphen );
+	/// let delta = NormTimeDeltaWide::new_days( -1 );
+	/// assert_eq!( delta.to_latex_unit_locale_with( &[ Unit::Day ], &langid!( "de-DE" ), &options ), "-1~Normtag" );
+	/// ```
+	#[cfg( all( feature = "i18n", feature = "tex" ) )]
+	pub fn to_latex_unit_locale_with( &self, units: &[Unit], locale: &LanguageIdentifier, options: &TexOptions ) -> String {
+		unit_fmt::latex_unit_locale( self.secs, units, locale, options )
+	}
+
+	/// Returns a string representation of `self` with selectable units rounded to the smallest unit provided. The units are expressed as symbols.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTimeDeltaWide, Unit};
+	///
+	/// let delta = NormTimeDeltaWide::new_years( 4_500_000_000_000 ) + NormTimeDeltaWide::new_days( 1 );
+	/// assert_eq!( delta.to_string_sym_unit( &[ Unit::Year, Unit::Day ] ), "4500000000000 y 1 d" );
+	/// ```
+	pub fn to_string_sym_unit( &self, units: &[Unit] ) -> String {
+		self.to_string_sym_unit_with( units, &TextOptions::new() )
+	}
+
+	/// Returns a string representation of `self` like [`to_string_sym_unit`](Self::to_string_sym_unit) formatted according to `options`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{Notation, NormTimeDeltaWide, TextOptions, Unit};
+	///
+	/// let options = TextOptions {
+	///     notation: Notation::Scientific,
+	///     ..TextOptions::new()
+	/// };
+	///
+	/// let delta = NormTimeDeltaWide::new_years( 4_500_000_000_000 );
+	/// assert_eq!( delta.to_string_sym_unit_with( &[ Unit::Year ], &options ), "4.5e12 y" );
+	/// ```
+	pub fn to_string_sym_unit_with( &self, units: &[Unit], options: &TextOptions ) -> String {
+		unit_fmt::sym_unit( self.secs, units, options )
+	}
+
+	/// Returns a LaTeX-string representation of `self` with selectable units rounded to the smallest unit provided. The units are expressed as symbols using the LaTeX `{siunitx}` package.
+	///
+	/// This method is only available when the **tex** feature has been activated.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTimeDeltaWide, Unit};
+	///
+	/// let delta = NormTimeDeltaWide::new_years( 4_500_000_000_000 ) + NormTimeDeltaWide::new_days( 1 );
+	/// assert_eq!(
+	///     delta.to_latex_sym_unit( &[ Unit::Year, Unit::Day ] ),
+	///     r"\qty{4500000000000}{\normyear}\,\qty{1}{\normday}"
+	/// );
+	/// ```
+	#[cfg( feature = "tex" )]
+	pub fn to_latex_sym_unit( &self, units: &[Unit] ) -> String {
+		self.to_latex_sym_unit_with( units, &TexOptions::new() )
+	}
+
+	/// Returns a LaTeX-string representation of `self` like [`to_latex_sym_unit`](Self::to_latex_sym_unit) formatted according to `options`.
+	///
+	/// This method is only available when the **tex** feature has been activated.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{NormTimeDeltaWide, TexOptions, Unit};
+	///
+	/// let options = TexOptions::new().siunitx( "round-mode", "places" );
+	/// let delta = NormTimeDeltaWide::new_days( 1_500 );
+	/// assert_eq!( delta.to_latex_sym_unit_with( &[ Unit::Day ], &options ), r"\qty[round-mode=places]{1500}{\normday}" );
+	/// ```
+	#[cfg( feature = "tex" )]
+	pub fn to_latex_sym_unit_with( &self, units: &[Unit], options: &TexOptions ) -> String {
+		unit_fmt::latex_sym_unit( self.secs, units, options )
+	}
+
+	/// Adding two `NormTimeDeltaWide`s. If an overflow occurs, this function returns `None`.
+	#[must_use]
+	pub const fn checked_add( &self, rhs: &Self ) -> Option<Self> {
+		match self.secs.checked_add( rhs.secs ) {
+			Some( secs ) => Some( Self { secs } ),
+			None => None,
+		}
+	}
+
+	/// Subtracting two `NormTimeDeltaWide`s. If an overflow occurs, this function returns `None`.
+	#[must_use]
+	pub const fn checked_sub( &self, rhs: &Self ) -> Option<Self> {
+		match self.secs.checked_sub( rhs.secs ) {
+			Some( secs ) => Some( Self { secs } ),
+			None => None,
+		}
+	}
+
+	/// Multiply a `NormTimeDeltaWide` with a `i64`. If an overflow occurs, this function returns `None`.
+	#[must_use]
+	pub const fn checked_mul( &self, rhs: i64 ) -> Option<Self> {
+		match self.secs.checked_mul( rhs as i128 ) {
+			Some( secs ) => Some( Self { secs } ),
+			None => None,
+		}
+	}
+
+	/// Divide a `NormTimeDeltaWide` with a `i64`. A division by 0 returns `None`. The result is truncated to full seconds.
+	#[must_use]
+	pub const fn checked_div( &self, rhs: i64 ) -> Option<Self> {
+		match self.secs.checked_div( rhs as i128 ) {
+			Some( secs ) => Some( Self { secs } ),
+			None => None,
+		}
+	}
+}
+
+
+impl Add for NormTimeDeltaWide {
+	type Output = Self;
+
+	fn add( self, rhs: Self ) -> Self::Output {
+		self.checked_add( &rhs ).expect( "Overflow in `NormTimeDeltaWide + NormTimeDeltaWide`" )
+	}
+}
+
+
+impl Sub for NormTimeDeltaWide {
+	type Output = Self;
+
+	fn sub( self, rhs: Self ) -> Self {
+		self.checked_sub( &rhs ).expect( "Overflow in `NormTimeDeltaWide - NormTimeDeltaWide`" )
+	}
+}
+
+
+impl Mul<i64> for NormTimeDeltaWide {
+	type Output = Self;
+
+	fn mul( self, rhs: i64 ) -> Self {
+		self.checked_mul( rhs ).expect( "Overflow in `NormTimeDeltaWide * i64`" )
+	}
+}
+
+
+impl Div<i64> for NormTimeDeltaWide {
+	type Output = Self;
+
+	fn div( self, rhs: i64 ) -> Self {
+		self.checked_div( rhs ).expect( "Division by 0" )
+	}
+}
+
+forward_ref_binop!( impl Add, add for NormTimeDeltaWide, NormTimeDeltaWide );
+forward_ref_binop!( impl Sub, sub for NormTimeDeltaWide, NormTimeDeltaWide );
+forward_ref_binop!( impl Mul, mul for NormTimeDeltaWide, i64 );
+forward_ref_binop!( impl Div, div for NormTimeDeltaWide, i64 );
+
+
+impl<'a> Sum<&'a NormTimeDeltaWide> for NormTimeDeltaWide {
+	fn sum<I: Iterator<Item = &'a NormTimeDeltaWide>>( iter: I ) -> Self {
+		iter.fold( NormTimeDeltaWide::ZERO, |acc, x| acc + *x )
+	}
+}
+
+impl Sum<NormTimeDeltaWide> for NormTimeDeltaWide {
+	fn sum<I: Iterator<Item = NormTimeDeltaWide>>( iter: I ) -> Self {
+		iter.fold( NormTimeDeltaWide::ZERO, |acc, x| acc + x )
+	}
+}
+
+/// Every `NormTimeDelta` fits into a `NormTimeDeltaWide`. Subseconds are truncated.
+impl From<NormTimeDelta> for NormTimeDeltaWide {
+	fn from( item: NormTimeDelta ) -> Self {
+		Self::new_seconds( item.seconds() as i128 )
+	}
+}
+
+/// Converting a `NormTimeDeltaWide` into a `NormTimeDelta` fails, if the duration exceeds the range of `NormTimeDelta`.
+impl TryFrom<NormTimeDeltaWide> for NormTimeDelta {
+	type Error = ConversionError;
+
+	fn try_from( item: NormTimeDeltaWide ) -> Result<Self, Self::Error> {
+		i64::try_from( item.secs ).ok()
+			.and_then( |x| NormTimeDelta::new( x, 0 ) )
+			.ok_or_else( || ConversionError::InvalidValue( item.to_string() ) )
+	}
+}
+
+/// Representing the duration in seconds.
+///
+/// # Example
+///
+/// ```
+/// use normtime::NormTimeDeltaWide;
+///
+/// assert_eq!( NormTimeDeltaWide::new_seconds( 1 ).to_string(), "1 second" );
+/// assert_eq!( NormTimeDeltaWide::new_years( 1 ).to_string(), "30000000 seconds" );
+/// ```
+impl fmt::Display for NormTimeDeltaWide {
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result {
+		f.pad( &unit_fmt::seconds( self.secs ) )
+	}
+}
+
+#[cfg( feature = "i18n" )]
+impl DisplayLocale for NormTimeDeltaWide {
+	/// Returns a string providing the duration of `self` in seconds translated to the language provided by `locale`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use unic_langid::LanguageIdentifier;
+	/// use unic_langid::langid;
+	/// use normtime::DisplayLocale;
+	/// use normtime::NormTimeDeltaWide;
+	///
+	/// const GERMAN: LanguageIdentifier = langid!( "de-DE" );
+	///
+	/// assert_eq!( NormTimeDeltaWide::new_seconds( 10 ).to_string_locale( &GERMAN ), "10 Sekunden" );
+	/// ```
+	fn to_string_locale( &self, locale: &LanguageIdentifier ) -> String {
+		unit_fmt::seconds_locale( self.secs, locale )
+	}
+}
+
+#[cfg( feature = "tex" )]
+impl Latex for NormTimeDeltaWide {
+	/// Returning `self` as LaTeX string.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{Latex, TexOptions};
+	/// use normtime::NormTimeDeltaWide;
+	///
+	/// assert_eq!( NormTimeDeltaWide::new_seconds( 100 ).to_latex( &TexOptions::new() ), "100~seconds" );
+	/// ```
+	fn to_latex( &self, options: &TexOptions ) -> String {
+		unit_fmt::latex_seconds( self.secs, options )
+	}
+}
+
+#[cfg( all( feature = "i18n", feature = "tex" ) )]
+impl LatexLocale for NormTimeDeltaWide {
+	/// Returns a string providing the duration of `self` in seconds translated to the language provided by `locale`.
+	fn to_latex_locale( &self, locale: &LanguageIdentifier, options: &TexOptions ) -> String {
+		unit_fmt::latex_seconds_locale( self.secs, locale, options )
+	}
+}
+
+#[cfg( feature = "tex" )]
+impl LatexSym for NormTimeDeltaWide {
+	/// Returns a string representing the duration as latex commands with symbols using the LaTeX package `{siunitx}`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use normtime::{LatexSym, TexOptions};
+	/// use normtime::NormTimeDeltaWide;
+	///
+	/// assert_eq!(
+	///     NormTimeDeltaWide::new_seconds( 10 ).to_latex_sym( &TexOptions::new() ),
+	///     r"\qty{10}{\second}"
+	/// );
+	/// ```
+	fn to_latex_sym( &self, options: &TexOptions ) -> String {
+		unit_fmt::latex_sym_seconds( self.secs, options )
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn calculate_normtimedeltawide() {
+		let delta = NormTimeDeltaWide::new_years( i64::MAX as i128 );
+		assert_eq!( delta + delta - delta, delta );
+		assert_eq!( delta * 2 / 2, delta );
+		assert_eq!( [ delta, delta ].iter().sum::<NormTimeDeltaWide>(), delta * 2 );
+		assert!( NormTimeDeltaWide::MAX.checked_add( &NormTimeDeltaWide::new_seconds( 1 ) ).is_none() );
+		assert!( delta.checked_div( 0 ).is_none() );
+	}
+
+	#[test]
+	fn convert_normtimedelta() {
+		assert_eq!( NormTimeDeltaWide::from( NormTimeDelta::new( -2, 500_000_000 ).unwrap() ), NormTimeDeltaWide::new_seconds( -1 ) );
+		assert_eq!(
+			NormTimeDelta::try_from( NormTimeDeltaWide::new_seconds( -100 ) ).unwrap(),
+			NormTimeDelta::new_seconds( -100 )
+		);
+		assert!( NormTimeDelta::try_from( NormTimeDeltaWide::new_seconds( i64::MAX as i128 ) ).is_err() );
+	}
+
+	#[test]
+	fn same_strings_as_normtimedelta() {
+		let units = [ Unit::Day, Unit::Hour, Unit::Minute ];
+
+		for secs in [ 0, 1, -1, 90_005_000, -90_005_000 ] {
+			let delta = NormTimeDelta::new_seconds( secs );
+			let wide = NormTimeDeltaWide::from( delta );
+			assert_eq!( wide.to_string(), delta.to_string() );
+			assert_eq!( wide.to_string_unit( &units ), delta.to_string_unit( &units ) );
+			assert_eq!( wide.to_string_sym_unit( &units ), delta.to_string_sym_unit( &units ) );
+		}
+	}
+}